@@ -148,17 +148,136 @@ pub fn draw_interactions(
     }
 }
 
+/// Draws the soil nutrient heatmap under everything else.
+pub fn draw_resource_field(
+    state: &simulation::ecosystem::Ecosystem,
+    params: &Params,
+    ui_panel_width: f32,
+) {
+    let field = &state.resource_field;
+    if !params.resource_field.enabled || field.nutrients.is_empty() {
+        return;
+    }
+    let screen_w = screen_width() - ui_panel_width;
+    let scale_x = screen_w / params.box_width;
+    let scale_y = screen_height() / params.box_height;
+    let capacity = params.resource_field.capacity.max(f32::EPSILON);
+
+    for (index, nutrients) in field.nutrients.iter().enumerate() {
+        let level = (nutrients / capacity).clamp(0.0, 1.0);
+        if level <= 0.01 {
+            continue;
+        }
+        let col = (index % field.cols) as f32;
+        let row = (index / field.cols) as f32;
+        draw_rectangle(
+            col * field.cell_size * scale_x,
+            row * field.cell_size * scale_y,
+            field.cell_size * scale_x,
+            field.cell_size * scale_y,
+            Color::from_rgba(60, 110, 40, (level * 90.0) as u8),
+        );
+    }
+}
+
+/// Draws one pheromone channel as translucent cells.
+pub fn draw_pheromones(
+    state: &simulation::ecosystem::Ecosystem,
+    params: &Params,
+    channel: Option<usize>,
+    ui_panel_width: f32,
+) {
+    // Distinct hue per channel
+    const CHANNEL_COLORS: [(u8, u8, u8); 4] = [
+        (230, 80, 200),
+        (240, 200, 40),
+        (60, 200, 230),
+        (250, 120, 60),
+    ];
+
+    let field = &state.pheromones;
+    let Some(channel) = channel else {
+        return;
+    };
+    if !params.pheromones.enabled || channel >= field.channels || field.concentration.is_empty() {
+        return;
+    }
+    let screen_w = screen_width() - ui_panel_width;
+    let scale_x = screen_w / params.box_width;
+    let scale_y = screen_height() / params.box_height;
+    let saturation = params.pheromones.saturation.max(f32::EPSILON);
+    let (r, g, b) = CHANNEL_COLORS[channel % CHANNEL_COLORS.len()];
+
+    for (cell, values) in field.concentration.chunks(field.channels).enumerate() {
+        let value = values[channel].max(0.0);
+        let level = value / (value + saturation);
+        if level <= 0.01 {
+            continue;
+        }
+        let col = (cell % field.cols) as f32;
+        let row = (cell / field.cols) as f32;
+        draw_rectangle(
+            col * field.cell_size * scale_x,
+            row * field.cell_size * scale_y,
+            field.cell_size * scale_x,
+            field.cell_size * scale_y,
+            Color::from_rgba(r, g, b, (level * 140.0) as u8),
+        );
+    }
+}
+
+/// Draws obstacles as filled polygons with an outline.
+pub fn draw_obstacles(
+    state: &simulation::ecosystem::Ecosystem,
+    params: &Params,
+    ui_panel_width: f32,
+) {
+    let screen_w = screen_width() - ui_panel_width;
+    let scale_x = screen_w / params.box_width;
+    let scale_y = screen_height() / params.box_height;
+    let to_screen = |[x, y]: [f32; 2]| Vec2::new(x * scale_x, y * scale_y);
+
+    for obstacle in &state.obstacles {
+        for [a, b, c] in obstacle.triangles() {
+            draw_triangle(
+                to_screen(a),
+                to_screen(b),
+                to_screen(c),
+                Color::from_rgba(90, 90, 100, 255),
+            );
+        }
+        let vertices = obstacle.vertices();
+        let n = vertices.len();
+        for i in 0..n {
+            let start = to_screen(vertices[i]);
+            let end = to_screen(vertices[(i + 1) % n]);
+            draw_line(
+                start.x,
+                start.y,
+                end.x,
+                end.y,
+                1.5,
+                Color::from_rgba(150, 150, 160, 255),
+            );
+        }
+    }
+}
+
 pub fn draw_food(state: &simulation::ecosystem::Ecosystem, params: &Params, ui_panel_width: f32) {
     // draw food
     state.food.iter().for_each(|entity| {
         if entity.energy > 0.0 {
             let screen_pos = entity.pos.to_screen(params, ui_panel_width);
             let scaled_radius = params.body_radius.to_screen(params, ui_panel_width);
+            let [r, g, b] = entity
+                .kind
+                .spec(&params.food_kinds)
+                .map_or([0, 200, 100], |spec| spec.color);
             draw_circle(
                 screen_pos[0],
                 screen_pos[1],
                 scaled_radius,
-                Color::from_rgba(0, 200, 100, 255),
+                Color::from_rgba(r, g, b, 255),
             );
         }
     });
@@ -189,11 +308,17 @@ pub fn draw_organisms(
 ) {
     state.organisms.iter().for_each(|entity| {
         let screen_pos = entity.pos.to_screen(params, ui_panel_width);
-        let screen_radius = params.body_radius.to_screen(params, ui_panel_width);
+        let screen_radius = entity
+            .morphology
+            .body_radius(params)
+            .to_screen(params, ui_panel_width);
         let is_selected = selected_id == Some(entity.id);
 
         // Draw scent radius (faint circle)
-        let scent_radius_screen = params.scent_radius.to_screen(params, ui_panel_width);
+        let scent_radius_screen = entity
+            .morphology
+            .scent_radius(params)
+            .to_screen(params, ui_panel_width);
         draw_circle_lines(
             screen_pos[0],
             screen_pos[1],
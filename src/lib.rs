@@ -27,12 +27,26 @@
 pub mod simulation {
     /// Action execution system for organism behaviors.
     pub mod actions;
+    /// World boundary modes and boundary-aware geometry.
+    pub mod boundary;
     /// Neural network implementation for organism brains.
     pub mod brain;
+    /// Collision response between organisms.
+    pub mod collision;
+    /// Health, armour, melee bites and the kill log.
+    pub mod combat;
+    /// Deterministic resolution of contested food, targets and share receivers.
+    pub mod conflict;
+    /// Evolvable diet and trophic digestion efficiencies.
+    pub mod diet;
     /// DNA utilities for genetic similarity and breeding.
     pub mod dna;
     /// Main ecosystem simulation with parallel updates.
     pub mod ecosystem;
+    /// Global energy accounting per step and per pool.
+    pub mod energy;
+    /// Day/night and seasonal environmental cycles.
+    pub mod environment;
     /// Event logging system for displaying recent events.
     pub mod event_log;
     /// Event system for thread-safe state updates.
@@ -48,14 +62,32 @@ pub mod simulation {
     /// The [`locatable::Locatable`] trait is implemented by all entities that have
     /// a position in 2D space and can be updated over time (Food, Organism, Projectile).
     pub mod locatable;
+    /// Mate choice driven by evolvable signal and DNA preferences.
+    pub mod mate_choice;
+    /// Island model migration between genetic pools.
+    pub mod migration;
+    /// Heritable body and sensor morphology.
+    pub mod morphology;
+    /// Novelty search with behaviour characterisation.
+    pub mod novelty;
+    /// Static polygon obstacles such as walls, rocks and mazes.
+    pub mod obstacle;
     /// Organism behavior, state, and lifecycle.
     pub mod organism;
     /// Simulation parameters.
     pub mod params;
+    /// Multi-channel pheromone field that organisms write and read.
+    pub mod pheromone;
+    /// Inertial movement physics with mass, thrust and drag.
+    pub mod physics;
     /// Attack projectiles fired by organisms.
     pub mod projectile;
     /// Reproduction statistics tracking.
     pub mod reproduction;
+    /// Spatial fertility grid with nutrient regrowth, diffusion and decomposition.
+    pub mod resource_field;
     /// Spatial indexing for efficient neighbor queries.
     pub mod spatial;
+    /// Read-only per-step view of the world for parallel organism updates.
+    pub mod view;
 }
@@ -15,16 +15,12 @@ mod graphics;
 mod ui;
 
 /// Calculates the neural network layer sizes based on simulation parameters.
-fn calculate_layer_sizes(
-    num_vision_directions: usize,
-    signal_size: usize,
-    memory_size: usize,
-) -> Vec<usize> {
+fn calculate_layer_sizes(params: &Params) -> Vec<usize> {
     vec![
-        3 * num_vision_directions + signal_size + memory_size + 7, // input: vision(dist+pool+type) + scent + memory + energy + rotation(sin,cos) + position(sin_x,cos_x,sin_y,cos_y)
-        128,                                                       // hidden layer 1
-        48,                                                        // hidden layer 2
-        signal_size + memory_size + 6, // output: signal + memory + rotation + acceleration + attack + share + asexual_repro + sexual_repro
+        simulation::organism::Perception::from_params(params).total_input_size(params), // input: every sense in params.senses
+        128, // hidden layer 1
+        48,  // hidden layer 2
+        simulation::actions::Actuation::from_params(params).total_output_size(params), // output: every action in params.actions
     ]
 }
 
@@ -33,16 +29,25 @@ fn create_simulation_params() -> Params {
     let num_vision_directions: usize = 17;
     let memory_size: usize = 32;
 
-    let layer_sizes = calculate_layer_sizes(num_vision_directions, signal_size, memory_size);
-
     let vision_radius = 50.0;
     let scent_radius = 40.0;
     let share_radius = 15.0;
     let reproduction_radius = 15.0;
     let dna_breeding_distance = 0.2; // Max DNA distance for breeding (hard cutoff)
     let dna_mutation_rate = 0.1; // Standard deviation of DNA mutation
+    let brain_mutation_rate = 0.1; // Brain weight mutation scale for clones
+
+    // Plants and corpses rot after 20s; a corpse is worth two plants
+    let mut food_kinds = simulation::food::default_food_kinds();
+    for kind in &mut food_kinds {
+        kind.lifetime = 20.0;
+    }
+    food_kinds[1].energy = 2.0;
 
-    Params {
+    let box_width = 800.0;
+    let box_height = 700.0;
+
+    let mut params = Params {
         body_radius: 3.0,
         vision_radius,
         scent_radius,
@@ -50,6 +55,7 @@ fn create_simulation_params() -> Params {
         reproduction_radius,
         dna_breeding_distance,
         dna_mutation_rate,
+        brain_mutation_rate,
         idle_energy_rate: 0.1,
         move_energy_rate: 0.00002,
         move_multiplier: 60.0,
@@ -62,21 +68,21 @@ fn create_simulation_params() -> Params {
         max_organism: 200,
         n_food: 120,
         max_food: 150,
-        box_width: 800.0,
-        box_height: 700.0,
-        layer_sizes,
+        box_width,
+        box_height,
+        boundary_mode: simulation::boundary::BoundaryMode::Torus,
+        boundary_contact_cost: 0.5,
+        layer_sizes: Vec::new(),
         attack_cost_rate: 0.3,
         attack_damage_rate: 4.0,
         attack_cooldown: 0.1,
-        corpse_energy_ratio: 2.0,
         max_energy: 4.0,
-        food_energy: 1.0,
         projectile_speed: vision_radius * 2.0,
         projectile_range: vision_radius,
         projectile_radius: 2.0,
         organism_spawn_rate: 6.0,
         food_spawn_rate: 5.0,
-        food_lifetime: 20.0, // 0 = unlimited
+        food_kinds,
         num_genetic_pools: 3,
         pool_interbreed_prob: 0.001, // 5% chance of inter-pool breeding
         brain_type: simulation::brain::BrainType::MLP,
@@ -87,10 +93,33 @@ fn create_simulation_params() -> Params {
         transformer_ff_dim: 32,
         graveyard_size: 50,
         reproduction_energy_multiplier: 0.9,
+        reproduction_mode: simulation::reproduction::ReproductionMode::Spawning,
+        reproduction_cooldown: 5.0,
+        reproduction_energy_cost: 0.1,
         spawn_from_graveyard: false,
         unbalanced_pool_sampling: false,
         empty_pool_seed_count: 5,
-    }
+        novelty: simulation::novelty::NoveltyParams::default(),
+        mate_choice: simulation::mate_choice::MateChoiceParams::default(),
+        migration: simulation::migration::MigrationParams::default(),
+        morphology: simulation::morphology::MorphologyParams::default(),
+        diet: simulation::diet::DietParams::default(),
+        resource_field: simulation::resource_field::ResourceFieldParams::default(),
+        obstacles: simulation::obstacle::ObstacleParams::default(),
+        environment: simulation::environment::EnvironmentParams::default(),
+        pheromones: simulation::pheromone::PheromoneParams::default(),
+        physics: simulation::physics::PhysicsParams::default(),
+        collision: simulation::collision::CollisionParams::default(),
+        combat: simulation::combat::CombatParams::default(),
+        projectiles: simulation::projectile::ProjectileParams::default(),
+        energy_ledger: simulation::energy::EnergyLedgerParams::default(),
+        conflicts: simulation::conflict::ConflictParams::default(),
+        pool_overrides: Vec::new(),
+        senses: simulation::organism::default_senses(),
+        actions: simulation::actions::default_actions(),
+    };
+    params.layer_sizes = calculate_layer_sizes(&params);
+    params
 }
 
 fn handle_keyboard_shortcuts(ui_state: &mut ui::UIState) {
@@ -123,6 +152,26 @@ fn handle_save_request(eco: &simulation::ecosystem::Ecosystem, ui_state: &mut ui
     }
 }
 
+fn handle_ledger_export_request(
+    eco: &simulation::ecosystem::Ecosystem,
+    ui_state: &mut ui::UIState,
+) {
+    let export_path = format!(
+        "energy_ledger_{}.json",
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    );
+    match eco.energy_ledger.save_to_file(&export_path) {
+        Ok(_) => {
+            ui_state.status_message = Some(format!("✓ Exported energy ledger to {}", export_path));
+            println!("Exported energy ledger to {}", export_path);
+        }
+        Err(e) => {
+            ui_state.status_message = Some(format!("✗ Export failed: {}", e));
+            eprintln!("Failed to export energy ledger: {}", e);
+        }
+    }
+}
+
 fn find_latest_save_file() -> Option<std::path::PathBuf> {
     let entries = std::fs::read_dir(".").ok()?;
 
@@ -155,6 +204,7 @@ fn handle_load_request(eco: &mut simulation::ecosystem::Ecosystem, ui_state: &mu
             // Clear history as it's from a different timeline
             ui_state.organism_count_history.clear();
             ui_state.food_count_history.clear();
+            ui_state.preference_correlation_history.clear();
             ui_state.set_last_update_time(eco.time);
             ui_state.reset_plot_time();
         }
@@ -195,6 +245,12 @@ fn update_and_render(
         handle_save_request(eco, ui_state);
     }
 
+    // Handle energy ledger export request
+    if ui_state.ledger_export_requested {
+        ui_state.ledger_export_requested = false;
+        handle_ledger_export_request(eco, ui_state);
+    }
+
     // Handle load request
     if ui_state.load_requested {
         ui_state.load_requested = false;
@@ -202,7 +258,7 @@ fn update_and_render(
     }
 
     // Update history data
-    ui_state.update_history(eco);
+    ui_state.update_history(eco, params);
     ui_state.update_pool_scores(eco, params);
     ui_state.update_pool_ages(eco, params);
 
@@ -228,6 +284,14 @@ fn update_and_render(
             graphics::get_hovered_organism(eco, params, ui_state.stats_panel_width);
 
         // Draw simulation
+        graphics::draw_resource_field(eco, params, ui_state.stats_panel_width);
+        graphics::draw_pheromones(
+            eco,
+            params,
+            ui_state.pheromone_overlay,
+            ui_state.stats_panel_width,
+        );
+        graphics::draw_obstacles(eco, params, ui_state.stats_panel_width);
         graphics::draw_food(eco, params, ui_state.stats_panel_width);
         graphics::draw_projectiles(eco, params, ui_state.stats_panel_width);
         graphics::draw_interactions(
@@ -364,11 +428,7 @@ async fn main() {
                 let should_start = ui::draw_genesis_screen(&mut params_lock);
                 if should_start {
                     // Recalculate layer sizes based on current parameters
-                    params_lock.layer_sizes = calculate_layer_sizes(
-                        params_lock.num_vision_directions,
-                        params_lock.signal_size,
-                        params_lock.memory_size,
-                    );
+                    params_lock.layer_sizes = calculate_layer_sizes(&params_lock);
                 }
                 should_start
             }; // params_lock dropped here
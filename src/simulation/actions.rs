@@ -1,14 +1,26 @@
 //! Organism action execution system.
 //!
 //! Handles execution of all organism actions including movement, rotation,
-//! attacking, energy sharing, and food consumption.
+//! attacking, biting, energy sharing, food consumption, and pheromone deposition.
+//! Which actions organisms have, and in what order their brain outputs are
+//! laid out, is configured by `Params::actions`; the brain's output layer and
+//! the UI's output labels are both derived from it.
 
 use ndarray::Array1;
+use serde::{Deserialize, Serialize};
 
+use super::boundary;
+use super::dna;
+use super::energy::Flow;
 use super::events::SimulationEvent;
 use super::food::Food;
+use super::novelty;
 use super::organism::Organism;
 use super::params::Params;
+use super::pheromone;
+use super::physics;
+use super::reproduction;
+use super::view::OrganismView;
 
 /// Result of executing actions - a list of events to be processed.
 pub type ActionResult = Vec<SimulationEvent>;
@@ -31,13 +43,23 @@ pub fn execute_movement(
     params: &Params,
     dt: f32,
 ) -> ActionResult {
+    if params.physics.enabled {
+        // Movement output is thrust rather than speed
+        physics::apply_thrust(entity, velocity, params, dt);
+        return vec![];
+    }
+
     let vel_vector = Array1::from_vec(vec![
         velocity * entity.rot.cos(),
         velocity * entity.rot.sin(),
-    ]) * params.move_multiplier;
+    ]) * entity.morphology.move_multiplier(params);
 
+    // Faster bodies cover more ground per unit of velocity and pay for it
     entity.pos += &(&vel_vector * dt);
-    entity.consume_energy(velocity.abs() * dt * params.move_energy_rate);
+    entity.consume_energy(
+        velocity.abs() * entity.morphology.speed_scale * dt * params.move_energy_rate,
+        Flow::Movement,
+    );
 
     vec![]
 }
@@ -60,8 +82,14 @@ pub fn execute_rotation(
     params: &Params,
     dt: f32,
 ) -> ActionResult {
+    if params.physics.enabled {
+        // Rotation output is torque rather than turn rate
+        physics::apply_torque(entity, rotation, params, dt);
+        return vec![];
+    }
+
     entity.rot += rotation * dt * 10.0;
-    entity.consume_energy(rotation.abs() * dt * params.rot_energy_rate);
+    entity.consume_energy(rotation.abs() * dt * params.rot_energy_rate, Flow::Rotation);
 
     vec![]
 }
@@ -86,7 +114,7 @@ pub fn execute_attack(
         && entity.energy > attack_strength * params.attack_cost_rate
         && entity.can_attack()
     {
-        entity.consume_energy(attack_strength * params.attack_cost_rate);
+        entity.consume_energy(attack_strength * params.attack_cost_rate, Flow::Attack);
         entity.reset_attack_cooldown(params.attack_cooldown);
 
         vec![SimulationEvent::ProjectileCreated {
@@ -100,6 +128,79 @@ pub fn execute_attack(
     }
 }
 
+/// Finds the nearest organism within biting reach.
+///
+/// # Returns
+///
+/// Optional ID and position of the nearest organism touching `entity` or
+/// within `combat.bite_range` of touching it.
+fn find_bite_target(
+    entity: &Organism,
+    neighbors: &[(f32, usize)],
+    organisms: &[OrganismView],
+    params: &Params,
+) -> Option<(usize, Array1<f32>)> {
+    let mut nearest_gap = f32::MAX;
+    let mut nearest = None;
+
+    for (_, neighbor_id) in neighbors {
+        let other = &organisms[*neighbor_id];
+        if other.id == entity.id || !other.is_alive() {
+            continue;
+        }
+        let gap = boundary::distance(&entity.pos, &other.pos, params)
+            - entity.morphology.body_radius(params)
+            - other.body_radius;
+        if gap < params.combat.bite_range && gap < nearest_gap {
+            nearest_gap = gap;
+            nearest = Some((other.id, other.pos.clone()));
+        }
+    }
+
+    nearest
+}
+
+/// Executes melee bite action.
+///
+/// # Arguments
+///
+/// * `entity` - The organism biting
+/// * `bite_strength` - Bite strength from brain output
+/// * `neighbors` - List of nearby organism indices
+/// * `organisms` - All organisms in the ecosystem
+/// * `params` - Simulation parameters
+///
+/// # Returns
+///
+/// Vector containing a `Bite` event if something was in reach.
+pub fn execute_bite(
+    entity: &mut Organism,
+    bite_strength: f32,
+    neighbors: &[(f32, usize)],
+    organisms: &[OrganismView],
+    params: &Params,
+) -> ActionResult {
+    let strength = bite_strength.min(1.0);
+    if !params.combat.enabled || strength <= 0.1 || !entity.can_attack() {
+        return vec![];
+    }
+    let Some((target_id, target_pos)) = find_bite_target(entity, neighbors, organisms, params)
+    else {
+        return vec![];
+    };
+
+    // Biting shares the attack cooldown
+    entity.consume_energy(strength * params.combat.bite_cost, Flow::Attack);
+    entity.reset_attack_cooldown(params.attack_cooldown);
+
+    vec![SimulationEvent::Bite {
+        attacker_id: entity.id,
+        target_id,
+        strength,
+        from: boundary::displacement(&target_pos, &entity.pos, params),
+    }]
+}
+
 /// Finds the nearest organism within sharing radius.
 ///
 /// # Arguments
@@ -115,7 +216,7 @@ pub fn execute_attack(
 fn find_share_target(
     entity: &Organism,
     neighbors: &[(f32, usize)],
-    organisms: &[Organism],
+    organisms: &[OrganismView],
     params: &Params,
 ) -> Option<usize> {
     let mut nearest_dist = f32::MAX;
@@ -124,7 +225,7 @@ fn find_share_target(
     for (_, neighbor_id) in neighbors {
         let other = &organisms[*neighbor_id];
         if other.id != entity.id {
-            let dist = (&entity.pos - &other.pos).mapv(f32::abs).sum();
+            let dist = boundary::manhattan_distance(&entity.pos, &other.pos, params);
             if dist < params.share_radius && dist < nearest_dist {
                 nearest_dist = dist;
                 nearest_id = Some(other.id);
@@ -152,7 +253,7 @@ pub fn execute_energy_sharing(
     entity: &Organism,
     share_amount: f32,
     neighbors: &[(f32, usize)],
-    organisms: &[Organism],
+    organisms: &[OrganismView],
     params: &Params,
 ) -> ActionResult {
     if share_amount > 0.1 && entity.energy > 0.2 {
@@ -170,7 +271,7 @@ pub fn execute_energy_sharing(
     }
 }
 
-/// Executes food consumption for all nearby food items.
+/// Claims every nearby food item within reach.
 ///
 /// # Arguments
 ///
@@ -181,7 +282,7 @@ pub fn execute_energy_sharing(
 ///
 /// # Returns
 ///
-/// Vector of `FoodConsumed` events for each food item consumed.
+/// Vector of `FoodConsumed` events for each food item claimed.
 pub fn execute_food_consumption(
     entity: &mut Organism,
     neighbor_foods: &[(f32, usize)],
@@ -189,15 +290,16 @@ pub fn execute_food_consumption(
     params: &Params,
 ) -> ActionResult {
     let mut events = vec![];
+    // Food items are as large as a default body
+    let reach = entity.morphology.body_radius(params) + params.body_radius;
 
     for (_, food_id) in neighbor_foods {
         let food_item = &food_items[*food_id];
-        let org_food_dist = (&entity.pos - &food_item.pos).mapv(f32::abs).sum();
-
-        if org_food_dist < params.body_radius * 2.0 && !food_item.is_consumed() {
-            entity.gain_energy(food_item.energy, params.max_energy);
-            entity.score += 1;
+        let org_food_dist = boundary::manhattan_distance(&entity.pos, &food_item.pos, params);
 
+        // Only claim the food here: several organisms may reach it in the
+        // same step, and who gets how much is settled with the events
+        if org_food_dist < reach && !food_item.is_consumed() {
             events.push(SimulationEvent::FoodConsumed {
                 organism_id: entity.id,
                 food_id: *food_id,
@@ -223,7 +325,7 @@ pub fn execute_food_consumption(
 fn find_reproduction_partner(
     entity: &Organism,
     neighbors: &[(f32, usize)],
-    organisms: &[Organism],
+    organisms: &[OrganismView],
     params: &Params,
 ) -> Option<usize> {
     let mut nearest_dist = f32::MAX;
@@ -231,9 +333,15 @@ fn find_reproduction_partner(
 
     for (_, neighbor_id) in neighbors {
         let other = &organisms[*neighbor_id];
-        // Only allow reproduction within same genetic pool
-        if other.id != entity.id && other.energy > 0.5 && other.pool_id == entity.pool_id {
-            let dist = (&entity.pos - &other.pos).mapv(f32::abs).sum();
+        // Only allow reproduction within same genetic pool, with genetically close
+        // partners that are not on cooldown themselves
+        if other.id != entity.id
+            && other.energy > reproduction::ENERGY_RESERVE
+            && other.pool_id == entity.pool_id
+            && other.can_reproduce
+            && dna::periodic_distance(&entity.dna, &other.dna) < params.dna_breeding_distance
+        {
+            let dist = boundary::manhattan_distance(&entity.pos, &other.pos, params);
             if dist < params.reproduction_radius && dist < nearest_dist {
                 nearest_dist = dist;
                 nearest_id = Some(other.id);
@@ -258,10 +366,14 @@ fn find_reproduction_partner(
 pub fn execute_asexual_reproduction(
     entity: &Organism,
     energy_contribution: f32,
-    _params: &Params,
+    params: &Params,
 ) -> ActionResult {
-    // Must give at least 0.5 energy and have enough energy
-    if energy_contribution >= 0.5 && entity.energy >= energy_contribution + 0.5 {
+    // Must give at least 0.5 energy, be off cooldown and afford contribution plus overhead
+    if energy_contribution >= 0.5
+        && entity.can_reproduce()
+        && entity.energy
+            >= energy_contribution + params.reproduction_energy_cost + reproduction::ENERGY_RESERVE
+    {
         vec![SimulationEvent::AsexualReproduction {
             parent_id: entity.id,
             parent_pos: entity.pos.clone(),
@@ -289,11 +401,15 @@ pub fn execute_sexual_reproduction(
     entity: &Organism,
     energy_contribution: f32,
     neighbors: &[(f32, usize)],
-    organisms: &[Organism],
+    organisms: &[OrganismView],
     params: &Params,
 ) -> ActionResult {
-    // Must have at least minimum energy and want to contribute something
-    if energy_contribution > 0.0 && entity.energy >= energy_contribution + 0.5 {
+    // Must want to contribute something, be off cooldown and afford contribution plus overhead
+    if energy_contribution > 0.0
+        && entity.can_reproduce()
+        && entity.energy
+            >= energy_contribution + params.reproduction_energy_cost + reproduction::ENERGY_RESERVE
+    {
         if let Some(partner_id) = find_reproduction_partner(entity, neighbors, organisms, params) {
             vec![SimulationEvent::SexualReproductionIntent {
                 organism_id: entity.id,
@@ -309,78 +425,635 @@ pub fn execute_sexual_reproduction(
     }
 }
 
-/// Executes all actions extracted from brain output.
+/// Executes pheromone deposition for every channel.
 ///
 /// # Arguments
 ///
-/// * `entity` - The organism executing actions
-/// * `brain_outputs` - Neural network outputs
-/// * `neighbors_orgs` - Nearby organisms for energy sharing
-/// * `neighbor_foods` - Nearby food for consumption
-/// * `organisms` - All organisms (for energy sharing target lookup)
-/// * `food_items` - All food items
+/// * `entity` - The organism depositing pheromone
+/// * `deposit_outputs` - Deposit strength per channel from brain output
 /// * `params` - Simulation parameters
 /// * `dt` - Time delta
 ///
 /// # Returns
 ///
-/// Combined vector of all events from all executed actions.
-pub fn execute_all_actions(
+/// Vector of `PheromoneDeposited` events, one per channel with positive output.
+pub fn execute_pheromone_deposit(
     entity: &mut Organism,
-    brain_outputs: &Array1<f32>,
-    neighbors_orgs: &[(f32, usize)],
-    neighbor_foods: &[(f32, usize)],
-    organisms: &[Organism],
-    food_items: &[Food],
+    deposit_outputs: &[f32],
     params: &Params,
     dt: f32,
 ) -> ActionResult {
-    let offset = params.signal_size + params.memory_size;
-    let rotation = brain_outputs[offset];
-    let velocity = brain_outputs[offset + 1];
-    let attack_strength = brain_outputs[offset + 2];
-    let share_amount = brain_outputs[offset + 3];
-    let _asexual_reproduction_energy = brain_outputs[offset + 4]; // Unused - reproduction disabled
-    let _sexual_reproduction_energy = brain_outputs[offset + 5]; // Unused - reproduction disabled
-
     let mut events = vec![];
+    for (channel, &strength) in deposit_outputs.iter().enumerate() {
+        let amount = strength.clamp(0.0, 1.0) * params.pheromones.deposit_rate * dt;
+        if amount <= 0.0 {
+            continue;
+        }
+        entity.consume_energy(
+            amount * params.pheromones.deposit_energy_cost,
+            Flow::Pheromones,
+        );
+        events.push(SimulationEvent::PheromoneDeposited {
+            pos: entity.pos.clone(),
+            channel,
+            amount,
+        });
+    }
+    events
+}
+
+/// What an action can see of the world around the organism executing it.
+pub struct ActionContext<'a> {
+    /// Nearby organisms as `(distance, index)` into `organisms`.
+    pub neighbors_orgs: &'a [(f32, usize)],
+    /// Nearby food as `(distance, index)` into `food_items`.
+    pub neighbor_foods: &'a [(f32, usize)],
+    /// All organisms (for target lookup).
+    pub organisms: &'a [OrganismView],
+    /// All food items.
+    pub food_items: &'a [Food],
+}
 
-    // Execute all actions
-    events.extend(execute_rotation(entity, rotation, params, dt));
-    events.extend(execute_movement(entity, velocity, params, dt));
-    events.extend(execute_attack(entity, attack_strength, params));
-    events.extend(execute_energy_sharing(
-        entity,
-        share_amount,
-        neighbors_orgs,
-        organisms,
-        params,
-    ));
-    events.extend(execute_food_consumption(
-        entity,
-        neighbor_foods,
-        food_items,
-        params,
-    ));
-
-    // Reproduction actions - DISABLED (organism-initiated reproduction is turned off)
-    // All reproduction now happens automatically via ecosystem.spawn()
-    // If both outputs are high, asexual takes priority
-    // if asexual_reproduction_energy >= 0.5 {
-    //     events.extend(execute_asexual_reproduction(
-    //         entity,
-    //         asexual_reproduction_energy,
-    //         params,
-    //     ));
-    // } else if sexual_reproduction_energy > 0.0 {
-    //     events.extend(execute_sexual_reproduction(
-    //         entity,
-    //         sexual_reproduction_energy,
-    //         neighbors_orgs,
-    //         organisms,
-    //         params,
-    //     ));
-    // }
+/// Trait for things organisms can do with their brain outputs.
+///
+/// Each action reads its own slice of the brain output vector, acts on the
+/// organism directly and returns the events that affect anything else.
+pub trait Action: Sync {
+    /// Executes the action.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The organism acting
+    /// * `outputs` - This action's slice of the brain outputs (`output_size()` entries)
+    /// * `context` - Nearby organisms and food
+    /// * `params` - Simulation parameters
+    /// * `dt` - Time delta
+    ///
+    /// # Returns
+    ///
+    /// Events generated by the action.
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        context: &ActionContext,
+        params: &Params,
+        dt: f32,
+    ) -> ActionResult;
 
-    events
+    /// Returns the number of brain outputs this action reads.
+    fn output_size(&self, params: &Params) -> usize;
+
+    /// Returns a short label for each output, in order.
+    ///
+    /// # Returns
+    ///
+    /// One label per output (`output_size()` entries), used to annotate brain outputs in the UI.
+    fn output_labels(&self, params: &Params) -> Vec<String>;
+
+    /// Returns a human-readable name for this action.
+    fn name(&self) -> &str;
+
+    /// Returns the motor slot of the novelty behaviour descriptor (see
+    /// [`novelty::MOTOR_SLOTS`]) this action's first output is recorded in.
+    fn behaviour_slot(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Broadcasts a signal that others can smell.
+pub struct Signal;
+
+impl Action for Signal {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        _context: &ActionContext,
+        _params: &Params,
+        _dt: f32,
+    ) -> ActionResult {
+        entity.signal = Array1::from_vec(outputs.to_vec());
+        vec![]
+    }
+
+    fn output_size(&self, params: &Params) -> usize {
+        params.signal_size
+    }
+
+    fn output_labels(&self, params: &Params) -> Vec<String> {
+        (0..params.signal_size)
+            .map(|i| format!("Signal {i}"))
+            .collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "Signal"
+    }
+}
+
+/// Writes memory cells that are read back through proprioception.
+pub struct Memory;
+
+impl Action for Memory {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        _context: &ActionContext,
+        _params: &Params,
+        _dt: f32,
+    ) -> ActionResult {
+        entity.memory = Array1::from_vec(outputs.to_vec());
+        vec![]
+    }
+
+    fn output_size(&self, params: &Params) -> usize {
+        params.memory_size
+    }
+
+    fn output_labels(&self, params: &Params) -> Vec<String> {
+        (0..params.memory_size)
+            .map(|i| format!("Mem {i}"))
+            .collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "Memory"
+    }
+}
+
+/// Turns the organism (see [`execute_rotation`]).
+pub struct Rotate;
+
+impl Action for Rotate {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        _context: &ActionContext,
+        params: &Params,
+        dt: f32,
+    ) -> ActionResult {
+        execute_rotation(entity, outputs[0], params, dt)
+    }
+
+    fn output_size(&self, _params: &Params) -> usize {
+        1
+    }
+
+    fn output_labels(&self, _params: &Params) -> Vec<String> {
+        vec!["Rotation".to_string()]
+    }
+
+    fn name(&self) -> &'static str {
+        "Rotate"
+    }
+
+    fn behaviour_slot(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+/// Moves the organism along its heading (see [`execute_movement`]).
+pub struct Move;
+
+impl Action for Move {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        _context: &ActionContext,
+        params: &Params,
+        dt: f32,
+    ) -> ActionResult {
+        execute_movement(entity, outputs[0], params, dt)
+    }
+
+    fn output_size(&self, _params: &Params) -> usize {
+        1
+    }
+
+    fn output_labels(&self, _params: &Params) -> Vec<String> {
+        vec!["Accel".to_string()]
+    }
+
+    fn name(&self) -> &'static str {
+        "Move"
+    }
+
+    fn behaviour_slot(&self) -> Option<usize> {
+        Some(1)
+    }
+}
+
+/// Shoots projectiles (see [`execute_attack`]).
+pub struct Attack;
+
+impl Action for Attack {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        _context: &ActionContext,
+        params: &Params,
+        _dt: f32,
+    ) -> ActionResult {
+        execute_attack(entity, outputs[0], params)
+    }
+
+    fn output_size(&self, _params: &Params) -> usize {
+        1
+    }
+
+    fn output_labels(&self, _params: &Params) -> Vec<String> {
+        vec!["Attack".to_string()]
+    }
+
+    fn name(&self) -> &'static str {
+        "Attack"
+    }
+
+    fn behaviour_slot(&self) -> Option<usize> {
+        Some(2)
+    }
+}
+
+/// Bites the nearest touching organism; empty unless combat is enabled
+/// (see [`execute_bite`]).
+pub struct Bite;
+
+impl Action for Bite {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        context: &ActionContext,
+        params: &Params,
+        _dt: f32,
+    ) -> ActionResult {
+        execute_bite(
+            entity,
+            outputs[0],
+            context.neighbors_orgs,
+            context.organisms,
+            params,
+        )
+    }
+
+    fn output_size(&self, params: &Params) -> usize {
+        usize::from(params.combat.enabled)
+    }
+
+    fn output_labels(&self, params: &Params) -> Vec<String> {
+        vec!["Bite".to_string(); self.output_size(params)]
+    }
+
+    fn name(&self) -> &'static str {
+        "Bite"
+    }
+}
+
+/// Gives energy to the nearest organism (see [`execute_energy_sharing`]).
+pub struct Share;
+
+impl Action for Share {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        context: &ActionContext,
+        params: &Params,
+        _dt: f32,
+    ) -> ActionResult {
+        execute_energy_sharing(
+            entity,
+            outputs[0],
+            context.neighbors_orgs,
+            context.organisms,
+            params,
+        )
+    }
+
+    fn output_size(&self, _params: &Params) -> usize {
+        1
+    }
+
+    fn output_labels(&self, _params: &Params) -> Vec<String> {
+        vec!["Share".to_string()]
+    }
+
+    fn name(&self) -> &'static str {
+        "Share"
+    }
+
+    fn behaviour_slot(&self) -> Option<usize> {
+        Some(3)
+    }
+}
+
+/// Eats any food within reach; needs no outputs (see [`execute_food_consumption`]).
+pub struct Eat;
+
+impl Action for Eat {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        _outputs: &[f32],
+        context: &ActionContext,
+        params: &Params,
+        _dt: f32,
+    ) -> ActionResult {
+        execute_food_consumption(entity, context.neighbor_foods, context.food_items, params)
+    }
+
+    fn output_size(&self, _params: &Params) -> usize {
+        0
+    }
+
+    fn output_labels(&self, _params: &Params) -> Vec<String> {
+        vec![]
+    }
+
+    fn name(&self) -> &'static str {
+        "Eat"
+    }
+}
+
+/// Organism-initiated reproduction, asexual or sexual.
+///
+/// Only acts when `Params::reproduction_mode` allows organism-initiated
+/// reproduction. If both outputs are high, asexual takes priority.
+pub struct Reproduce;
+
+impl Action for Reproduce {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        context: &ActionContext,
+        params: &Params,
+        _dt: f32,
+    ) -> ActionResult {
+        if !params.reproduction_mode.allows_organism_initiated() {
+            return vec![];
+        }
+        let (asexual_energy, sexual_energy) = (outputs[0], outputs[1]);
+        if asexual_energy >= 0.5 {
+            execute_asexual_reproduction(entity, asexual_energy, params)
+        } else if sexual_energy > 0.0 {
+            execute_sexual_reproduction(
+                entity,
+                sexual_energy,
+                context.neighbors_orgs,
+                context.organisms,
+                params,
+            )
+        } else {
+            vec![]
+        }
+    }
+
+    fn output_size(&self, _params: &Params) -> usize {
+        2
+    }
+
+    fn output_labels(&self, _params: &Params) -> Vec<String> {
+        vec!["Asexual".to_string(), "Sexual".to_string()]
+    }
+
+    fn name(&self) -> &'static str {
+        "Reproduce"
+    }
+}
+
+/// Deposits pheromone; empty unless the pheromone field is enabled
+/// (see [`execute_pheromone_deposit`]).
+pub struct Deposit;
+
+impl Action for Deposit {
+    fn execute(
+        &self,
+        entity: &mut Organism,
+        outputs: &[f32],
+        _context: &ActionContext,
+        params: &Params,
+        dt: f32,
+    ) -> ActionResult {
+        execute_pheromone_deposit(entity, outputs, params, dt)
+    }
+
+    fn output_size(&self, params: &Params) -> usize {
+        pheromone::output_size(params)
+    }
+
+    fn output_labels(&self, params: &Params) -> Vec<String> {
+        (0..self.output_size(params))
+            .map(|channel| format!("Deposit {channel}"))
+            .collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "Deposit"
+    }
+}
+
+/// An action that can be listed in `Params::actions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionKind {
+    /// Signal broadcast (see [`Signal`]).
+    Signal,
+    /// Memory cells (see [`Memory`]).
+    Memory,
+    /// Turning (see [`Rotate`]).
+    Rotate,
+    /// Moving forward or backward (see [`Move`]).
+    Move,
+    /// Shooting projectiles (see [`Attack`]).
+    Attack,
+    /// Melee bites; empty unless combat is enabled (see [`Bite`]).
+    Bite,
+    /// Energy sharing (see [`Share`]).
+    Share,
+    /// Eating food within reach (see [`Eat`]).
+    Eat,
+    /// Asexual and sexual reproduction (see [`Reproduce`]).
+    Reproduce,
+    /// Pheromone deposits; empty unless the pheromone field is enabled (see [`Deposit`]).
+    Deposit,
+}
+
+impl ActionKind {
+    /// Every kind of action.
+    pub const ALL: [Self; 10] = [
+        Self::Signal,
+        Self::Memory,
+        Self::Rotate,
+        Self::Move,
+        Self::Attack,
+        Self::Bite,
+        Self::Share,
+        Self::Eat,
+        Self::Reproduce,
+        Self::Deposit,
+    ];
+
+    /// Builds the action.
+    pub fn build(self) -> Box<dyn Action> {
+        match self {
+            Self::Signal => Box::new(Signal),
+            Self::Memory => Box::new(Memory),
+            Self::Rotate => Box::new(Rotate),
+            Self::Move => Box::new(Move),
+            Self::Attack => Box::new(Attack),
+            Self::Bite => Box::new(Bite),
+            Self::Share => Box::new(Share),
+            Self::Eat => Box::new(Eat),
+            Self::Reproduce => Box::new(Reproduce),
+            Self::Deposit => Box::new(Deposit),
+        }
+    }
+
+    /// Human-readable name, matching [`Action::name`].
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Signal => "Signal",
+            Self::Memory => "Memory",
+            Self::Rotate => "Rotate",
+            Self::Move => "Move",
+            Self::Attack => "Attack",
+            Self::Bite => "Bite",
+            Self::Share => "Share",
+            Self::Eat => "Eat",
+            Self::Reproduce => "Reproduce",
+            Self::Deposit => "Deposit",
+        }
+    }
+}
+
+/// The default action list, in the classic output layout: every action but biting.
+pub fn default_actions() -> Vec<ActionKind> {
+    vec![
+        ActionKind::Signal,
+        ActionKind::Memory,
+        ActionKind::Rotate,
+        ActionKind::Move,
+        ActionKind::Attack,
+        ActionKind::Share,
+        ActionKind::Eat,
+        ActionKind::Reproduce,
+        ActionKind::Deposit,
+    ]
+}
+
+/// Manages multiple actions and splits brain outputs between them.
+///
+/// The actuation system:
+/// 1. Hands each action its slice of the brain outputs, in order
+/// 2. Lets each action act on the organism
+/// 3. Returns all events generated
+pub struct Actuation {
+    /// Ordered list of actions that consume brain outputs
+    actions: Vec<Box<dyn Action>>,
+}
+
+impl Actuation {
+    /// Creates a new actuation system with the given actions, in order.
+    pub fn new(actions: Vec<Box<dyn Action>>) -> Self {
+        Self { actions }
+    }
+
+    /// Creates an actuation system from a list of action kinds, in order.
+    pub fn from_kinds(kinds: &[ActionKind]) -> Self {
+        Self::new(kinds.iter().map(|kind| kind.build()).collect())
+    }
+
+    /// Creates the actuation system configured by `params.actions`.
+    pub fn from_params(params: &Params) -> Self {
+        Self::from_kinds(&params.actions)
+    }
+
+    /// Executes every action with its slice of the brain outputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `brain_outputs` doesn't hold [`Self::total_output_size`]
+    /// values, which means `layer_sizes` is out of date.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The organism executing actions
+    /// * `brain_outputs` - Neural network outputs
+    /// * `context` - Nearby organisms and food
+    /// * `params` - Simulation parameters
+    /// * `dt` - Time delta
+    ///
+    /// # Returns
+    ///
+    /// Combined vector of all events from all executed actions.
+    pub fn execute(
+        &self,
+        entity: &mut Organism,
+        brain_outputs: &Array1<f32>,
+        context: &ActionContext,
+        params: &Params,
+        dt: f32,
+    ) -> ActionResult {
+        assert_eq!(
+            brain_outputs.len(),
+            self.total_output_size(params),
+            "brain outputs don't match the configured actions; check layer_sizes"
+        );
+        let brain_outputs = brain_outputs
+            .as_slice()
+            .expect("brain outputs are contiguous");
+        let mut slices = Vec::with_capacity(self.actions.len());
+        let mut offset = 0;
+        for action in &self.actions {
+            let end = offset + action.output_size(params);
+            slices.push(&brain_outputs[offset..end]);
+            offset = end;
+        }
+
+        // Record behaviour for novelty search
+        let mut motor = [0.0; novelty::MOTOR_SLOTS];
+        for (action, slice) in self.actions.iter().zip(&slices) {
+            if let (Some(slot), Some(&value)) = (action.behaviour_slot(), slice.first()) {
+                motor[slot] = value;
+            }
+        }
+        entity
+            .behaviour
+            .record([entity.pos[0], entity.pos[1]], motor, params, dt);
+
+        let mut events = vec![];
+        for (action, outputs) in self.actions.iter().zip(slices) {
+            events.extend(action.execute(entity, outputs, context, params, dt));
+        }
+        events
+    }
+
+    /// Returns the total number of brain outputs read by all actions.
+    pub fn total_output_size(&self, params: &Params) -> usize {
+        self.actions.iter().map(|a| a.output_size(params)).sum()
+    }
+
+    /// Returns a label for every brain output, in order.
+    pub fn output_labels(&self, params: &Params) -> Vec<String> {
+        self.actions
+            .iter()
+            .flat_map(|a| a.output_labels(params))
+            .collect()
+    }
+
+    /// Returns a reference to the actions in this actuation system.
+    pub fn actions(&self) -> &[Box<dyn Action>] {
+        &self.actions
+    }
+}
+
+impl Default for Actuation {
+    fn default() -> Self {
+        Self::from_kinds(&default_actions())
+    }
 }
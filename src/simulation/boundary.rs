@@ -0,0 +1,162 @@
+//! World boundary modes and boundary-aware geometry.
+//!
+//! The world is a `box_width` × `box_height` rectangle. Depending on the
+//! [`BoundaryMode`] its edges wrap around (a torus), act as hard walls,
+//! reflect bodies back, or absorb them. On a torus every distance and
+//! direction uses the nearest periodic image, so organisms near one edge
+//! sense and interact with organisms just across it.
+
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+
+use super::energy::Flow;
+use super::organism::Organism;
+use super::params::Params;
+use super::projectile::Projectile;
+
+/// What happens at the edges of the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BoundaryMode {
+    /// Edges wrap around to the opposite side.
+    #[default]
+    Torus,
+    /// Bodies are stopped at the edge and pay `boundary_contact_cost` while touching it.
+    Walls,
+    /// Bodies bounce off the edge; organisms turn away from it.
+    Reflective,
+    /// Organisms crossing the edge die and projectiles vanish.
+    Absorbing,
+}
+
+impl BoundaryMode {
+    /// Returns `true` if the world wraps around.
+    pub fn is_torus(self) -> bool {
+        self == Self::Torus
+    }
+}
+
+/// Shortest displacement vector from `from` to `to`.
+///
+/// On a torus this is the displacement to the nearest periodic image of `to`.
+pub fn displacement(from: &Array1<f32>, to: &Array1<f32>, params: &Params) -> Array1<f32> {
+    let mut delta = to - from;
+    if params.boundary_mode.is_torus() {
+        delta[0] = wrap_delta(delta[0], params.box_width);
+        delta[1] = wrap_delta(delta[1], params.box_height);
+    }
+    delta
+}
+
+/// Euclidean distance between two points, respecting the boundary mode.
+pub fn distance(from: &Array1<f32>, to: &Array1<f32>, params: &Params) -> f32 {
+    displacement(from, to, params)
+        .mapv(|x| x.powi(2))
+        .sum()
+        .sqrt()
+}
+
+/// Manhattan distance between two points, respecting the boundary mode.
+pub fn manhattan_distance(from: &Array1<f32>, to: &Array1<f32>, params: &Params) -> f32 {
+    displacement(from, to, params).mapv(f32::abs).sum()
+}
+
+/// Position of `to` as seen from `from`: its nearest periodic image on a torus,
+/// `to` itself otherwise.
+pub fn nearest_image(from: &Array1<f32>, to: &Array1<f32>, params: &Params) -> Array1<f32> {
+    from + &displacement(from, to, params)
+}
+
+/// Keeps a point inside the world (wrapping on a torus, clamping otherwise).
+pub fn confine(pos: &mut Array1<f32>, params: &Params) {
+    if params.boundary_mode.is_torus() {
+        pos[0] = pos[0].rem_euclid(params.box_width);
+        pos[1] = pos[1].rem_euclid(params.box_height);
+    } else {
+        pos[0] = pos[0].clamp(0.0, params.box_width);
+        pos[1] = pos[1].clamp(0.0, params.box_height);
+    }
+}
+
+/// Applies the boundary mode to an organism that may have left the world.
+pub fn apply_to_organism(organism: &mut Organism, params: &Params, dt: f32) {
+    let (w, h) = (params.box_width, params.box_height);
+    let outside_x = organism.pos[0] < 0.0 || organism.pos[0] > w;
+    let outside_y = organism.pos[1] < 0.0 || organism.pos[1] > h;
+
+    match params.boundary_mode {
+        BoundaryMode::Torus => confine(&mut organism.pos, params),
+        BoundaryMode::Walls => {
+            if outside_x || outside_y {
+                // Walls absorb momentum into them
+                if outside_x {
+                    organism.velocity[0] = 0.0;
+                }
+                if outside_y {
+                    organism.velocity[1] = 0.0;
+                }
+                confine(&mut organism.pos, params);
+                organism.consume_energy(params.boundary_contact_cost * dt, Flow::Boundary);
+            }
+        }
+        BoundaryMode::Reflective => {
+            if outside_x {
+                organism.pos[0] = reflect(organism.pos[0], w);
+                organism.rot = std::f32::consts::PI - organism.rot;
+                organism.velocity[0] = -organism.velocity[0];
+            }
+            if outside_y {
+                organism.pos[1] = reflect(organism.pos[1], h);
+                organism.rot = -organism.rot;
+                organism.velocity[1] = -organism.velocity[1];
+            }
+        }
+        BoundaryMode::Absorbing => {
+            if outside_x || outside_y {
+                organism.kill();
+            }
+        }
+    }
+}
+
+/// Applies the boundary mode to a projectile that may have left the world.
+pub fn apply_to_projectile(projectile: &mut Projectile, params: &Params) {
+    let (w, h) = (params.box_width, params.box_height);
+    let outside_x = projectile.pos[0] < 0.0 || projectile.pos[0] > w;
+    let outside_y = projectile.pos[1] < 0.0 || projectile.pos[1] > h;
+
+    match params.boundary_mode {
+        BoundaryMode::Torus => confine(&mut projectile.pos, params),
+        BoundaryMode::Walls | BoundaryMode::Absorbing => {
+            if outside_x || outside_y {
+                projectile.distance_traveled = projectile.max_range;
+            }
+        }
+        BoundaryMode::Reflective => {
+            if outside_x {
+                projectile.pos[0] = reflect(projectile.pos[0], w);
+                projectile.velocity[0] = -projectile.velocity[0];
+            }
+            if outside_y {
+                projectile.pos[1] = reflect(projectile.pos[1], h);
+                projectile.velocity[1] = -projectile.velocity[1];
+            }
+        }
+    }
+}
+
+/// Wraps a coordinate difference into `[-size / 2, size / 2]`.
+pub fn wrap_delta(delta: f32, size: f32) -> f32 {
+    delta - size * (delta / size).round()
+}
+
+/// Mirrors a coordinate that left `[0, size]` back inside.
+fn reflect(value: f32, size: f32) -> f32 {
+    let reflected = if value < 0.0 {
+        -value
+    } else if value > size {
+        2.0 * size - value
+    } else {
+        value
+    };
+    reflected.clamp(0.0, size)
+}
@@ -6,6 +6,9 @@
 use ndarray::Array1;
 use serde::{Deserialize, Serialize};
 
+use super::organism::Organism;
+use super::params::Params;
+
 pub mod mlp;
 pub mod transformer;
 
@@ -21,10 +24,45 @@ pub enum BrainType {
     Transformer,
 }
 
+impl BrainType {
+    /// Every architecture, in display order.
+    pub const ALL: [Self; 2] = [Self::MLP, Self::Transformer];
+
+    /// Human-readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::MLP => "MLP",
+            Self::Transformer => "Transformer",
+        }
+    }
+}
+
+/// Error returned when crossing two brains whose architectures differ.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchitectureMismatch {
+    /// Architecture of the first parent.
+    pub parent1: String,
+    /// Architecture of the second parent.
+    pub parent2: String,
+}
+
+impl std::fmt::Display for ArchitectureMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot cross a {} brain with a {} brain",
+            self.parent1, self.parent2
+        )
+    }
+}
+
+impl std::error::Error for ArchitectureMismatch {}
+
 /// Neural network brain that can use either MLP or Transformer architecture.
 ///
 /// Used as the "brain" that controls organism behavior.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
 pub enum Brain {
     /// Multi-layer perceptron with tanh activation.
     MLP {
@@ -62,6 +100,7 @@ impl Brain {
     /// - `num_heads`: Number of attention heads per block (e.g., 4)
     /// - `head_dim`: Dimension per attention head (e.g., 16)
     /// - `ff_dim`: Feed-forward hidden dimension (e.g., 128)
+    #[allow(clippy::too_many_arguments)]
     pub fn new_transformer(
         input_size: usize,
         output_size: usize,
@@ -85,6 +124,26 @@ impl Brain {
         }
     }
 
+    /// Creates a brain with random weights of `params.brain_type`.
+    ///
+    /// MLPs use every entry of `layer_sizes`; transformers take their input and
+    /// output sizes from it and the rest of their shape from `params`.
+    pub fn random(layer_sizes: &[usize], params: &Params) -> Self {
+        match params.brain_type {
+            BrainType::MLP => Brain::new(layer_sizes, 0.1),
+            BrainType::Transformer => Brain::new_transformer(
+                layer_sizes[0],
+                layer_sizes.last().copied().unwrap_or(40),
+                params.transformer_model_dim,
+                params.transformer_num_blocks,
+                params.transformer_num_heads,
+                params.transformer_head_dim,
+                params.transformer_ff_dim,
+                0.1,
+            ),
+        }
+    }
+
     /// Runs a forward pass through the brain.
     #[inline]
     pub fn think(&self, inputs: &Array1<f32>) -> Array1<f32> {
@@ -116,9 +175,13 @@ impl Brain {
     }
 
     /// Creates a new brain by averaging two parent brains.
-    /// Both parents must be the same architecture type.
-    pub fn crossover(parent1: &Brain, parent2: &Brain) -> Self {
-        match (parent1, parent2) {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArchitectureMismatch`] if the parents' architectures differ.
+    pub fn crossover(parent1: &Brain, parent2: &Brain) -> Result<Self, ArchitectureMismatch> {
+        parent1.check_architecture(parent2)?;
+        let child = match (parent1, parent2) {
             (Brain::MLP { layers: l1 }, Brain::MLP { layers: l2 }) => {
                 let new_layers = l1
                     .iter()
@@ -150,18 +213,24 @@ impl Brain {
                     output_proj: Mlp::crossover(op1, op2),
                 }
             }
-            _ => {
-                // Mismatched types - return clone of parent1
-                parent1.clone()
-            }
-        }
+            _ => unreachable!("architectures were checked"),
+        };
+        Ok(child)
     }
 
     /// Creates a new brain by weighted averaging two parent brains.
-    /// Both parents must be the same architecture type.
     /// weight1 is the weight for parent1, weight2 = 1.0 - weight1 for parent2.
-    pub fn crossover_weighted(parent1: &Brain, parent2: &Brain, weight1: f32) -> Self {
-        match (parent1, parent2) {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArchitectureMismatch`] if the parents' architectures differ.
+    pub fn crossover_weighted(
+        parent1: &Brain,
+        parent2: &Brain,
+        weight1: f32,
+    ) -> Result<Self, ArchitectureMismatch> {
+        parent1.check_architecture(parent2)?;
+        let child = match (parent1, parent2) {
             (Brain::MLP { layers: l1 }, Brain::MLP { layers: l2 }) => {
                 let new_layers = l1
                     .iter()
@@ -195,15 +264,117 @@ impl Brain {
                     output_proj: Mlp::crossover_weighted(op1, op2, weight1),
                 }
             }
-            _ => {
-                // Mismatched types - return clone of parent1
-                parent1.clone()
+            _ => unreachable!("architectures were checked"),
+        };
+        Ok(child)
+    }
+
+    /// Checks whether two brains have the same architecture and layer shapes,
+    /// so that they can be crossed over.
+    pub fn same_architecture(&self, other: &Brain) -> bool {
+        match (self, other) {
+            (Brain::MLP { layers: l1 }, Brain::MLP { layers: l2 }) => {
+                l1.len() == l2.len()
+                    && l1
+                        .iter()
+                        .zip(l2)
+                        .all(|(a, b)| a.weights.dim() == b.weights.dim())
+            }
+            (
+                Brain::Transformer {
+                    input_embed: ie1,
+                    blocks: b1,
+                    output_proj: op1,
+                },
+                Brain::Transformer {
+                    input_embed: ie2,
+                    blocks: b2,
+                    output_proj: op2,
+                },
+            ) => {
+                ie1.weights.dim() == ie2.weights.dim()
+                    && b1.len() == b2.len()
+                    && b1.iter().zip(b2).all(|(a, b)| {
+                        a.heads.len() == b.heads.len()
+                            && a.w_o.dim() == b.w_o.dim()
+                            && a.ff1.weights.dim() == b.ff1.weights.dim()
+                    })
+                    && op1.weights.dim() == op2.weights.dim()
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks that `other` can be crossed with this brain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArchitectureMismatch`] describing both architectures if not.
+    pub fn check_architecture(&self, other: &Brain) -> Result<(), ArchitectureMismatch> {
+        if self.same_architecture(other) {
+            Ok(())
+        } else {
+            Err(ArchitectureMismatch {
+                parent1: self.describe_architecture(),
+                parent2: other.describe_architecture(),
+            })
+        }
+    }
+
+    /// Short description of the architecture, e.g. `MLP [24, 16, 8]` or
+    /// `Transformer [24 -> 16 x2 -> 8]`.
+    pub fn describe_architecture(&self) -> String {
+        match self {
+            Brain::MLP { layers } => {
+                let mut sizes: Vec<usize> = layers.iter().map(|l| l.weights.dim().1).collect();
+                sizes.extend(layers.last().map(|l| l.weights.dim().0));
+                format!("MLP {sizes:?}")
             }
+            Brain::Transformer {
+                input_embed,
+                blocks,
+                output_proj,
+            } => format!(
+                "Transformer [{} -> {} x{} -> {}]",
+                input_embed.weights.dim().1,
+                input_embed.weights.dim().0,
+                blocks.len(),
+                output_proj.weights.dim().0
+            ),
         }
     }
 
-    /// Mutates all parameters in the brain.
+    /// Multiply-adds needed for one call to [`Brain::think`], used to compare
+    /// the compute cost of architectures.
+    pub fn compute_cost(&self) -> usize {
+        match self {
+            Brain::MLP { layers } => layers.iter().map(|l| l.weights.len()).sum(),
+            Brain::Transformer {
+                input_embed,
+                blocks,
+                output_proj,
+            } => {
+                let block_cost: usize = blocks
+                    .iter()
+                    .map(|block| {
+                        let heads: usize = block
+                            .heads
+                            .iter()
+                            .map(|h| h.w_q.len() + h.w_k.len() + h.w_v.len())
+                            .sum();
+                        heads + block.w_o.len() + block.ff1.weights.len() + block.ff2.weights.len()
+                    })
+                    .sum();
+                input_embed.weights.len() + block_cost + output_proj.weights.len()
+            }
+        }
+    }
+
+    /// Mutates all parameters in the brain. A scale of zero leaves it unchanged.
     pub fn mutate(&mut self, mutation_scale: f32) {
+        if mutation_scale <= 0.0 {
+            return;
+        }
         match self {
             Brain::MLP { layers } => {
                 for layer in layers {
@@ -225,9 +396,14 @@ impl Brain {
     }
 
     /// Calculates the Euclidean distance between two brains.
-    /// Only works if both brains are the same architecture type.
-    pub fn distance(brain1: &Brain, brain2: &Brain) -> f32 {
-        match (brain1, brain2) {
+    ///
+    /// Returns `None` if the brains have different architectures, as there is
+    /// no meaningful distance between them.
+    pub fn distance(brain1: &Brain, brain2: &Brain) -> Option<f32> {
+        if !brain1.same_architecture(brain2) {
+            return None;
+        }
+        let distance = match (brain1, brain2) {
             (Brain::MLP { layers: l1 }, Brain::MLP { layers: l2 }) => {
                 let mut sum_sq = 0.0;
                 for (layer1, layer2) in l1.iter().zip(l2) {
@@ -289,11 +465,9 @@ impl Brain {
 
                 sum_sq.sqrt()
             }
-            _ => {
-                // Different architectures - return large distance
-                f32::MAX
-            }
-        }
+            _ => unreachable!("architectures were checked"),
+        };
+        Some(distance)
     }
 
     /// Flattens all weights and biases into a single vector.
@@ -340,3 +514,48 @@ impl Brain {
         }
     }
 }
+
+/// Averages over the living organisms with one brain architecture, for
+/// comparing architectures competing in the same world.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchitectureStats {
+    /// Architecture the averages are over.
+    pub brain_type: BrainType,
+    /// Number of organisms with it.
+    pub count: usize,
+    /// Average fitness.
+    pub avg_fitness: f64,
+    /// Average age.
+    pub avg_age: f32,
+    /// Average multiply-adds per thought.
+    pub avg_compute_cost: f32,
+}
+
+/// Statistics for every architecture present among `organisms`, in
+/// [`BrainType::ALL`] order.
+pub fn architecture_stats(organisms: &[Organism]) -> Vec<ArchitectureStats> {
+    BrainType::ALL
+        .iter()
+        .filter_map(|&brain_type| {
+            let members: Vec<&Organism> = organisms
+                .iter()
+                .filter(|org| org.brain.brain_type() == brain_type)
+                .collect();
+            if members.is_empty() {
+                return None;
+            }
+            let count = members.len();
+            Some(ArchitectureStats {
+                brain_type,
+                count,
+                avg_fitness: members.iter().map(|o| o.fitness()).sum::<f64>() / count as f64,
+                avg_age: members.iter().map(|o| o.age).sum::<f32>() / count as f32,
+                avg_compute_cost: members
+                    .iter()
+                    .map(|o| o.brain.compute_cost() as f32)
+                    .sum::<f32>()
+                    / count as f32,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,155 @@
+//! Collision response between organisms.
+//!
+//! Two organisms touch when their bodies overlap. What happens next depends
+//! on the [`CollisionMode`]: both die, they are pushed apart, they bounce off
+//! each other with momentum, or they pay an energy penalty while overlapping.
+//!
+//! Every organism resolves its own side of each contact from the step's
+//! snapshot of its neighbours, so the result does not depend on the order in
+//! which organisms are updated in parallel.
+
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+
+use super::boundary;
+use super::energy::Flow;
+use super::organism::Organism;
+use super::params::Params;
+use super::physics;
+use super::view::OrganismView;
+
+/// What happens when two organisms touch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CollisionMode {
+    /// Both organisms die (bodies within Manhattan contact distance).
+    #[default]
+    Lethal,
+    /// Overlapping bodies are pushed apart, heavier bodies (see [`physics::mass`]) moving less.
+    Soft,
+    /// Bodies are pushed apart and bounce off each other, exchanging momentum.
+    Elastic,
+    /// Bodies pass through each other but pay `energy_penalty` while overlapping.
+    Penalty,
+}
+
+/// Collision configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollisionParams {
+    /// Response to contact between organisms.
+    pub mode: CollisionMode,
+    /// Fraction of the overlap removed per step (`Soft` and `Elastic`).
+    pub stiffness: f32,
+    /// Fraction of the approach speed returned as bounce (`Elastic`).
+    pub restitution: f32,
+    /// Rate at which bounce momentum decays, per second (`Elastic`, without physics).
+    pub damping: f32,
+    /// Energy lost per second per overlapping body (`Penalty`).
+    pub energy_penalty: f32,
+}
+
+impl Default for CollisionParams {
+    fn default() -> Self {
+        Self {
+            mode: CollisionMode::Lethal,
+            stiffness: 0.5,
+            restitution: 0.8,
+            damping: 2.0,
+            energy_penalty: 0.5,
+        }
+    }
+}
+
+/// Resolves `entity`'s side of every contact with its neighbours.
+///
+/// # Arguments
+///
+/// * `entity` - The organism being updated
+/// * `neighbors` - Nearby organisms as `(distance, index)` into `organisms`
+/// * `organisms` - View of all organisms at the start of the step
+/// * `params` - Simulation parameters
+/// * `dt` - Time delta
+///
+/// # Returns
+///
+/// Number of organisms `entity` touched.
+pub fn resolve(
+    entity: &mut Organism,
+    neighbors: &[(f32, usize)],
+    organisms: &[OrganismView],
+    params: &Params,
+    dt: f32,
+) -> usize {
+    let collision = &params.collision;
+    let own_mass = physics::mass(entity, params);
+    let own_velocity = entity.velocity;
+    let mut push = [0.0, 0.0];
+    let mut impulse = [0.0, 0.0];
+    let mut contacts = 0;
+
+    for (_, neighbor_id) in neighbors {
+        let other = &organisms[*neighbor_id];
+        if other.id == entity.id {
+            continue; // skip self
+        }
+        let contact_distance = entity.morphology.body_radius(params) + other.body_radius;
+
+        if collision.mode == CollisionMode::Lethal {
+            if boundary::manhattan_distance(&entity.pos, &other.pos, params) < contact_distance {
+                contacts += 1;
+                entity.kill(); // collision with another organism
+            }
+            continue;
+        }
+
+        let offset = boundary::displacement(&entity.pos, &other.pos, params);
+        let distance = offset.mapv(|x| x.powi(2)).sum().sqrt();
+        if distance >= contact_distance {
+            continue;
+        }
+        contacts += 1;
+
+        if collision.mode == CollisionMode::Penalty {
+            entity.consume_energy(collision.energy_penalty * dt, Flow::Collision);
+            continue;
+        }
+
+        // Unit normal pointing away from the other body; coincident bodies
+        // split along x, ordered by id so both sides agree
+        let normal = if distance > f32::EPSILON {
+            [-offset[0] / distance, -offset[1] / distance]
+        } else if entity.id < other.id {
+            [-1.0, 0.0]
+        } else {
+            [1.0, 0.0]
+        };
+        let overlap = contact_distance - distance;
+        let other_mass = other.mass;
+        let share = other_mass / (own_mass + other_mass);
+
+        let separation = overlap * share * collision.stiffness.clamp(0.0, 1.0);
+        push[0] += normal[0] * separation;
+        push[1] += normal[1] * separation;
+
+        if collision.mode == CollisionMode::Elastic {
+            // Approach speed is whichever is larger: the relative velocity
+            // along the normal or the rate the bodies interpenetrated, which
+            // is capped at top swimming speed so stacked spawns don't explode
+            let relative = (own_velocity[0] - other.velocity[0]) * normal[0]
+                + (own_velocity[1] - other.velocity[1]) * normal[1];
+            let penetration = if dt > 0.0 {
+                (overlap / dt).min(params.move_multiplier)
+            } else {
+                0.0
+            };
+            let approach = (-relative).max(penetration);
+            let bounce = (1.0 + collision.restitution.max(0.0)) * share * approach;
+            impulse[0] += normal[0] * bounce;
+            impulse[1] += normal[1] * bounce;
+        }
+    }
+
+    entity.pos += &Array1::from_vec(push.to_vec());
+    entity.velocity[0] += impulse[0];
+    entity.velocity[1] += impulse[1];
+    contacts
+}
@@ -0,0 +1,211 @@
+//! Health, armour and melee combat.
+//!
+//! With combat enabled, damage no longer comes straight out of energy:
+//! organisms have a separate `health` pool that projectiles and bites wear
+//! down and that slowly regenerates at an energy cost. The evolvable armour
+//! gene (see [`Morphology::armour`](super::morphology::Morphology::armour))
+//! reduces incoming damage but raises metabolic cost, and the
+//! [`Bite`](super::actions::Bite) action lets organisms steal energy from
+//! whatever they are touching. Every combat kill is recorded in a
+//! [`KillLog`] together with its damage source.
+
+use std::collections::VecDeque;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::energy::Flow;
+use super::organism::Organism;
+use super::params::Params;
+
+/// Combat configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatParams {
+    /// If false, damage is taken from energy, armour has no effect and organisms cannot bite.
+    pub enabled: bool,
+    /// Health of an unharmed organism.
+    pub max_health: f32,
+    /// Health regenerated per second while below `max_health`.
+    pub regen_rate: f32,
+    /// Energy spent per unit of health regenerated.
+    pub regen_energy_cost: f32,
+    /// Fraction of damage stopped by a fully armoured body (armour gene 1).
+    pub armour_strength: f32,
+    /// Energy per second per unit of armour.
+    pub armour_cost: f32,
+    /// Largest change to the armour gene per generation.
+    pub armour_mutation_rate: f32,
+    /// How far beyond touching distance a bite reaches.
+    pub bite_range: f32,
+    /// Health damage of a full-strength bite.
+    pub bite_damage: f32,
+    /// Energy moved from victim to attacker by a full-strength bite.
+    pub bite_energy: f32,
+    /// Energy a full-strength bite costs the attacker.
+    pub bite_cost: f32,
+    /// Number of kills kept in the kill log.
+    pub kill_log_size: usize,
+}
+
+impl Default for CombatParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_health: 1.0,
+            regen_rate: 0.05,
+            regen_energy_cost: 0.5,
+            armour_strength: 0.75,
+            armour_cost: 0.01,
+            armour_mutation_rate: 0.05,
+            bite_range: 2.0,
+            bite_damage: 0.25,
+            bite_energy: 0.2,
+            bite_cost: 0.02,
+            kill_log_size: 100,
+        }
+    }
+}
+
+/// What dealt the damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageSource {
+    /// A projectile.
+    Projectile,
+    /// A melee bite.
+    Bite,
+}
+
+impl DamageSource {
+    /// Human-readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Projectile => "projectile",
+            Self::Bite => "bite",
+        }
+    }
+}
+
+/// A single combat kill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KillRecord {
+    /// Simulation time of the kill.
+    pub time: f32,
+    /// ID of the organism killed.
+    pub victim_id: usize,
+    /// ID of the organism that dealt the final blow.
+    pub killer_id: usize,
+    /// What dealt the final blow.
+    pub source: DamageSource,
+    /// Damage dealt by the final blow, after armour.
+    pub damage: f32,
+}
+
+/// Most recent combat kills, newest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KillLog {
+    records: VecDeque<KillRecord>,
+}
+
+impl KillLog {
+    /// Records a kill, keeping at most `capacity` records.
+    pub fn record(&mut self, record: KillRecord, capacity: usize) {
+        self.records.push_front(record);
+        self.records.truncate(capacity);
+    }
+
+    /// Returns all records, newest first.
+    pub fn records(&self) -> &VecDeque<KillRecord> {
+        &self.records
+    }
+
+    /// Number of kills recorded by `source`.
+    pub fn count(&self, source: DamageSource) -> usize {
+        self.records.iter().filter(|r| r.source == source).count()
+    }
+}
+
+/// Health of an unharmed organism (when loading saves without health).
+pub fn full_health() -> f32 {
+    CombatParams::default().max_health
+}
+
+/// Damage left after `organism`'s armour.
+pub fn armoured_damage(organism: &Organism, damage: f32, params: &Params) -> f32 {
+    let armour = organism.morphology.armour.clamp(0.0, 1.0);
+    damage * (1.0 - armour * params.combat.armour_strength.clamp(0.0, 1.0))
+}
+
+/// Sets `child`'s armour gene by blending two parents' (`alpha` = share of
+/// `parent1`).
+///
+/// Armour is inherited whether or not morphology evolution is enabled, but
+/// only while combat is enabled.
+pub fn inherit_armour(
+    child: &mut Organism,
+    parent1: &Organism,
+    parent2: &Organism,
+    alpha: f32,
+    params: &Params,
+) {
+    if !params.combat.enabled {
+        return;
+    }
+    child.morphology.armour =
+        parent1.morphology.armour * alpha + parent2.morphology.armour * (1.0 - alpha);
+}
+
+/// Mutates an organism's armour gene by an additive step, since it starts out
+/// at zero. Does nothing when combat is disabled.
+pub fn mutate_armour(organism: &mut Organism, params: &Params) {
+    if !params.combat.enabled {
+        return;
+    }
+    let step = rand::rng().random_range(-1.0..=1.0) * params.combat.armour_mutation_rate;
+    organism.morphology.armour = (organism.morphology.armour + step).clamp(0.0, 1.0);
+}
+
+/// Deals `damage` to an organism.
+///
+/// With combat enabled the damage is reduced by armour and taken from health,
+/// and the organism dies when its health runs out; otherwise it is taken from
+/// energy.
+///
+/// # Returns
+///
+/// The damage actually dealt.
+pub fn deal_damage(organism: &mut Organism, damage: f32, params: &Params) -> f32 {
+    if !params.combat.enabled {
+        organism.consume_energy(damage, Flow::Damage);
+        return damage;
+    }
+    let damage = armoured_damage(organism, damage, params);
+    organism.health -= damage;
+    if organism.health <= 0.0 {
+        organism.kill();
+    }
+    damage
+}
+
+/// Regenerates health and pays for armour.
+pub fn maintain(organism: &mut Organism, params: &Params, dt: f32) {
+    let combat = &params.combat;
+    if !combat.enabled {
+        return;
+    }
+    organism.consume_energy(
+        combat.armour_cost * organism.morphology.armour.max(0.0) * dt,
+        Flow::Combat,
+    );
+
+    let missing = (combat.max_health - organism.health).max(0.0);
+    let affordable = if combat.regen_energy_cost > 0.0 {
+        organism.energy.max(0.0) / combat.regen_energy_cost
+    } else {
+        f32::INFINITY
+    };
+    let heal = (combat.regen_rate * dt).min(missing).min(affordable);
+    if heal > 0.0 {
+        organism.health += heal;
+        organism.consume_energy(heal * combat.regen_energy_cost, Flow::Combat);
+    }
+}
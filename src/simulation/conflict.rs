@@ -0,0 +1,149 @@
+//! Deterministic resolution of contested resources.
+//!
+//! Organisms update in parallel, so several of them can claim the same food
+//! item, strike the same target or share with the same receiver in one step.
+//! All claims on one resource are resolved together under a
+//! [`ConflictPolicy`], whatever order their events were emitted in, so the
+//! outcome of a step doesn't depend on how the organisms were scheduled.
+//! Random resolution draws from a generator seeded by the configured seed,
+//! the simulation time and the resource, never from a shared generator.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+/// How claims on a contested resource are settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConflictPolicy {
+    /// Claimants take turns from the nearest to the farthest.
+    #[default]
+    NearestWins,
+    /// The resource is divided equally between claimants.
+    EqualSplit,
+    /// Claimants take turns in a seeded random order.
+    Random,
+}
+
+/// Conflict resolution configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictParams {
+    /// Policy for contested food, targets and share receivers.
+    pub policy: ConflictPolicy,
+    /// Seed for the `Random` policy.
+    pub seed: u64,
+}
+
+impl Default for ConflictParams {
+    fn default() -> Self {
+        Self {
+            policy: ConflictPolicy::NearestWins,
+            seed: 0,
+        }
+    }
+}
+
+/// A resource organisms can contend for in one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    /// A food item, by index.
+    Food(usize),
+    /// An organism being struck, by index.
+    Target(usize),
+    /// An organism being shared with, by index.
+    Receiver(usize),
+}
+
+impl Resource {
+    fn key(self) -> u64 {
+        let (tag, index) = match self {
+            Self::Food(index) => (0, index),
+            Self::Target(index) => (1, index),
+            Self::Receiver(index) => (2, index),
+        };
+        ((index as u64) << 2) | tag
+    }
+}
+
+/// One organism's claim on a contested resource.
+#[derive(Debug, Clone, Copy)]
+pub struct Claim {
+    /// Organism index or id, unique per claimant and used to break ties.
+    pub claimant: usize,
+    /// Distance from the claimant to the resource.
+    pub distance: f32,
+    /// Most the claimant can take.
+    pub demand: f32,
+}
+
+impl ConflictParams {
+    /// Order in which claims on `resource` are served at simulation `time`.
+    ///
+    /// `NearestWins` serves the nearest claimant first, `Random` shuffles
+    /// with a generator seeded for this resource and time, and `EqualSplit`
+    /// serves them by `claimant`. Returns indices into `claims`.
+    pub fn order(&self, claims: &[Claim], time: f32, resource: Resource) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..claims.len()).collect();
+        // Start from a canonical order so the input order never matters
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&claims[a], &claims[b]);
+            a.claimant
+                .cmp(&b.claimant)
+                .then_with(|| a.demand.total_cmp(&b.demand))
+                .then_with(|| a.distance.total_cmp(&b.distance))
+        });
+        match self.policy {
+            ConflictPolicy::NearestWins => {
+                order.sort_by(|&a, &b| claims[a].distance.total_cmp(&claims[b].distance));
+            }
+            ConflictPolicy::Random => order.shuffle(&mut self.rng(time, resource)),
+            ConflictPolicy::EqualSplit => {}
+        }
+        order
+    }
+
+    /// Divides `capacity` between `claims`, indexed like `claims`.
+    ///
+    /// Under `EqualSplit` every claimant gets an equal share, with what the
+    /// smaller claims leave over going to the larger ones. Otherwise claimants
+    /// take what they demand in [`Self::order`] until nothing is left.
+    pub fn allocate(
+        &self,
+        capacity: f32,
+        claims: &[Claim],
+        time: f32,
+        resource: Resource,
+    ) -> Vec<f32> {
+        let mut shares = vec![0.0; claims.len()];
+        let mut remaining = capacity.max(0.0);
+        if self.policy == ConflictPolicy::EqualSplit {
+            let mut order = self.order(claims, time, resource);
+            order.sort_by(|&a, &b| claims[a].demand.total_cmp(&claims[b].demand));
+            for (served, &i) in order.iter().enumerate() {
+                let share = remaining / (claims.len() - served) as f32;
+                shares[i] = claims[i].demand.max(0.0).min(share);
+                remaining -= shares[i];
+            }
+        } else {
+            for i in self.order(claims, time, resource) {
+                shares[i] = claims[i].demand.max(0.0).min(remaining);
+                remaining -= shares[i];
+            }
+        }
+        shares
+    }
+
+    /// Generator for `resource` at `time`, independent of every other one.
+    fn rng(&self, time: f32, resource: Resource) -> StdRng {
+        let seed = mix(mix(mix(self.seed) ^ u64::from(time.to_bits())) ^ resource.key());
+        StdRng::seed_from_u64(seed)
+    }
+}
+
+/// `SplitMix64` finaliser, spreading nearby inputs across the whole range.
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,100 @@
+//! Evolvable diet and trophic digestion efficiencies.
+//!
+//! Each organism carries one diet gene per food kind. Genes are normalized
+//! into shares that sum to 1, and the digestion efficiency of a kind is its
+//! share raised to `tradeoff`, so a pure specialist digests its food
+//! fully. With `tradeoff` >= 1 generalists digest everything poorly, so
+//! herbivore and carnivore niches can evolve.
+
+use ndarray::Array1;
+use ndarray_rand::RandomExt;
+use ndarray_rand::rand_distr::Uniform;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::food::FoodKind;
+use super::organism::Organism;
+use super::params::Params;
+
+/// Diet evolution configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DietParams {
+    /// If false, every organism digests every food kind with full efficiency.
+    pub enabled: bool,
+    /// Maximum per-gene change applied on inheritance.
+    pub mutation_rate: f32,
+    /// Specialization pressure: efficiency = share^tradeoff (1.0 = linear).
+    pub tradeoff: f32,
+}
+
+impl Default for DietParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mutation_rate: 0.05,
+            tradeoff: 1.0,
+        }
+    }
+}
+
+/// Creates random diet genes for `num_kinds` food kinds.
+pub fn random_diet(num_kinds: usize) -> Array1<f32> {
+    Array1::random(num_kinds, Uniform::new(0.0, 1.0))
+}
+
+/// Fraction of energy `organism` extracts from food of `kind`.
+///
+/// Always 1.0 when diet evolution is disabled or the organism's genes do not
+/// match the food table (e.g. loaded from an older save).
+pub fn efficiency(organism: &Organism, kind: FoodKind, params: &Params) -> f32 {
+    if !params.diet.enabled || organism.diet.len() != params.food_kinds.len() {
+        return 1.0;
+    }
+    let total: f32 = organism.diet.iter().map(|g| g.max(0.0)).sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    organism.diet.get(kind.index()).map_or(0.0, |gene| {
+        (gene.max(0.0) / total).powf(params.diet.tradeoff)
+    })
+}
+
+/// Sets `child`'s diet from two parents by crossover, then mutates it.
+///
+/// Does nothing when diet evolution is disabled. Parents whose genes do not
+/// match the food table pass on a fresh random diet instead.
+pub fn inherit(
+    child: &mut Organism,
+    parent1: &Organism,
+    parent2: &Organism,
+    alpha: f32,
+    params: &Params,
+) {
+    if !params.diet.enabled {
+        return;
+    }
+    let kinds = params.food_kinds.len();
+    child.diet = if parent1.diet.len() == kinds && parent2.diet.len() == kinds {
+        &parent1.diet * alpha + &parent2.diet * (1.0 - alpha)
+    } else {
+        random_diet(kinds)
+    };
+    mutate(child, params);
+}
+
+/// Copies a single parent's diet into `child`, then mutates it.
+pub fn inherit_single(child: &mut Organism, parent: &Organism, params: &Params) {
+    inherit(child, parent, parent, 1.0, params);
+}
+
+/// Mutates an organism's diet genes in place, keeping them in [0, 1].
+pub fn mutate(organism: &mut Organism, params: &Params) {
+    if !params.diet.enabled {
+        return;
+    }
+    let mut rng = rand::rng();
+    for gene in &mut organism.diet {
+        let step = rng.random_range(-1.0..=1.0) * params.diet.mutation_rate;
+        *gene = (*gene + step).clamp(0.0, 1.0);
+    }
+}
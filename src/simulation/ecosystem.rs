@@ -2,38 +2,50 @@
 //!
 //! The ecosystem manages all organisms, food, and projectiles. It handles:
 //! - Parallel organism brain updates using rayon
-//! - Spatial queries using k-d trees for efficient neighbor finding
+//! - Spatial queries using a torus-aware cell grid for efficient neighbor finding
 //! - Event-driven state updates for thread safety
 //! - Organism spawning, reproduction, and evolution
 
 use super::actions;
+use super::boundary;
+use super::collision;
+use super::combat::{self, KillLog};
+use super::diet;
+use super::energy::{self, EnergyLedger, Flow};
+use super::environment;
 use super::events;
 use super::evolution::EvolutionEngine;
 use super::food;
+use super::mate_choice;
+use super::migration::{self, MigrationState};
+use super::morphology;
 use super::organism;
 use super::projectile;
-use super::spatial::SpatialIndex;
-pub use super::spatial::SpatialTrees;
+use super::resource_field::ResourceField;
+use super::spatial::{Neighbourhood, SpatialIndex};
+use super::view::{OrganismView, WorldView};
 
-use super::event_log::EventLog;
-use super::geometric_utils::wrap_around_mut;
+use super::event_log::{EventColor, EventLog};
+use super::novelty::NoveltyArchive;
+use super::obstacle::{self, Obstacle};
 use super::params::Params;
+use super::pheromone::PheromoneField;
+use super::physics;
 use super::reproduction::ReproductionStats;
-use ndarray::{Array1, s};
+use ndarray::Array1;
 use ndarray_rand::RandomExt;
 use rand::Rng;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
 use std::time::Instant;
 
 /// Performance timing statistics for profiling the simulation.
 #[derive(Debug, Clone, Default)]
 pub struct TimingStats {
-    /// Time to build spatial index (KD-trees)
+    /// Time to rebuild the spatial index
     pub spatial_index_ms: f32,
-    /// Time to clone ecosystem for parallel access
-    pub ecosystem_clone_ms: f32,
+    /// Time to build the read-only world view for parallel access
+    pub world_view_ms: f32,
     /// Time for parallel organism updates (sensing, thinking, actions)
     pub parallel_update_ms: f32,
     /// Time for projectile updates and collision detection
@@ -64,8 +76,7 @@ pub struct Ecosystem {
     pub generation: u32,
     /// Statistics about reproduction strategy effectiveness.
     pub reproduction_stats: ReproductionStats,
-    /// Evolution engine managing graveyard and organism spawning.
-    #[serde(skip)]
+    /// Evolution engine managing graveyard, novelty archive and organism spawning.
     #[serde(default = "default_evolution_engine")]
     evolution_engine: EvolutionEngine,
     /// Active energy sharing interactions (`giver_id`, `receiver_id`, timestamp) for visualization
@@ -76,6 +87,27 @@ pub struct Ecosystem {
     pub reproduction_intents: Vec<(usize, usize, f32)>,
     /// Event log for displaying recent events in UI
     pub event_log: EventLog,
+    /// Most recent combat kills and what dealt them.
+    #[serde(default)]
+    pub kill_log: KillLog,
+    /// Every energy flow, per step and per pool.
+    #[serde(default)]
+    pub energy_ledger: EnergyLedger,
+    /// Migration timer and per-pool inflow/outflow totals.
+    #[serde(default)]
+    pub migration: MigrationState,
+    /// Fertility and nutrient grid that food grows from (empty when disabled).
+    #[serde(default)]
+    pub resource_field: ResourceField,
+    /// Static obstacles in the world.
+    #[serde(default)]
+    pub obstacles: Vec<Obstacle>,
+    /// Pheromone concentrations deposited by organisms (empty when disabled).
+    #[serde(default)]
+    pub pheromones: PheromoneField,
+    /// Spatial index, rebuilt in place every step.
+    #[serde(skip)]
+    spatial_index: SpatialIndex,
     /// Performance timing statistics
     #[serde(skip)]
     pub timing_stats: TimingStats,
@@ -96,28 +128,50 @@ impl Ecosystem {
         for i in 0..params.n_organism {
             // Distribute organisms evenly across genetic pools
             let pool_id = i % params.num_genetic_pools;
+            let pool_params = params.for_pool(pool_id);
             let entity = organism::Organism::new_random(
                 i,
                 &center,
                 params.signal_size,
                 params.memory_size,
                 params.num_vision_directions,
-                params.vision_radius,
+                pool_params.vision_radius,
                 params.fov,
-                params.layer_sizes.clone(),
+                pool_params.layer_sizes.clone(),
                 pool_id,
-                params,
+                &pool_params,
             );
 
             organisms.push(entity);
         }
 
+        let mut resource_field = if params.resource_field.enabled {
+            ResourceField::new(params)
+        } else {
+            ResourceField::default()
+        };
+
+        let obstacles = if params.obstacles.enabled {
+            params.obstacles.obstacles.clone()
+        } else {
+            Vec::new()
+        };
+
+        let pheromones = if params.pheromones.enabled {
+            PheromoneField::new(params)
+        } else {
+            PheromoneField::default()
+        };
+
         for _i in 0..params.n_food {
-            let food_item = food::Food::new_random(&center, params.food_energy);
-            food.push(food_item);
+            if let Some(food_item) =
+                Self::random_food(&center, &mut resource_field, &obstacles, 1.0, params)
+            {
+                food.push(food_item);
+            }
         }
 
-        Self {
+        let mut ecosystem = Self {
             organisms,
             food,
             projectiles: Vec::new(),
@@ -128,32 +182,55 @@ impl Ecosystem {
             energy_shares: Vec::new(),
             reproduction_intents: Vec::new(),
             event_log: EventLog::default(),
+            kill_log: KillLog::default(),
+            energy_ledger: EnergyLedger::default(),
+            migration: MigrationState::default(),
+            resource_field,
+            obstacles,
+            pheromones,
+            spatial_index: SpatialIndex::default(),
             timing_stats: TimingStats::default(),
-        }
+        };
+        ecosystem.energy_ledger = EnergyLedger::new(energy::stock(&ecosystem));
+        ecosystem
     }
 
     /// Advances the simulation by one timestep with parallel organism updates.
     pub fn step(&mut self, params: &Params, dt: f32) {
         let step_start = Instant::now();
 
-        // Build spatial index for efficient neighbor queries
+        // Rebuild the spatial index for efficient neighbor queries
         let spatial_start = Instant::now();
-        let spatial_index = SpatialIndex::build(self).expect("Failed to build spatial index");
+        let mut spatial_index = std::mem::take(&mut self.spatial_index);
+        spatial_index.rebuild(self, params);
         self.timing_stats.spatial_index_ms = spatial_start.elapsed().as_secs_f32() * 1000.0;
 
         self.time += dt;
 
-        // Create perception system for generating brain inputs
-        let perception = organism::Perception::default();
+        // Daily and seasonal conditions for this step
+        let conditions = environment::conditions(self.time, params);
 
-        // Get reference to KD-trees for perception system
-        let spatial_trees = spatial_index.as_trees();
+        // Resolve each pool's parameter overrides once
+        let pool_params = params.per_pool();
 
-        // Create Arc wrapper for shared read-only access
-        // This does ONE clone before parallelization (unavoidable for now due to perception API)
-        let clone_start = Instant::now();
-        let ecosystem_snapshot = Arc::new(self.clone());
-        self.timing_stats.ecosystem_clone_ms = clone_start.elapsed().as_secs_f32() * 1000.0;
+        // Create perception system for generating brain inputs
+        let perception = organism::Perception::from_params(params);
+
+        // Create actuation system for executing brain outputs
+        let actuation = actions::Actuation::from_params(params);
+
+        // Read-only view of the world for the parallel phase: organisms are
+        // summarised, everything else is borrowed, and brains stay in place
+        let view_start = Instant::now();
+        let world = WorldView {
+            time: self.time,
+            organisms: OrganismView::all(&self.organisms, params),
+            food: &self.food,
+            projectiles: &self.projectiles,
+            obstacles: &self.obstacles,
+            pheromones: &self.pheromones,
+        };
+        self.timing_stats.world_view_ms = view_start.elapsed().as_secs_f32() * 1000.0;
 
         // Parallel phase: collect events from each organism without mutex contention
         // Use larger chunks to reduce cache line bouncing and task switching overhead
@@ -164,68 +241,76 @@ impl Ecosystem {
             .par_chunks_mut(chunk_size)
             .flat_map(|chunk| {
                 let mut chunk_events = Vec::new();
+                let mut neighbourhood = Neighbourhood::default();
                 for entity in chunk.iter_mut() {
-                    // wrap around the screen
-                    wrap_around_mut(&mut entity.pos, params.box_width, params.box_height);
-
-                    // Get nearest neighbors for collision and action detection
-                    let neighbors_orgs =
-                        spatial_index.query_organisms(&entity.pos, params.vision_radius);
-                    let neighbor_foods =
-                        spatial_index.query_food(&entity.pos, params.vision_radius);
+                    // Everything below sees the organism's own pool's parameters
+                    let params = pool_params
+                        .get(entity.pool_id)
+                        .map_or(params, AsRef::as_ref);
+
+                    // Wrap, stop, bounce or absorb at the world edge
+                    boundary::apply_to_organism(entity, params, dt);
+
+                    // Gather everything the organism can sense or act on, once
+                    neighbourhood.gather(
+                        &spatial_index,
+                        &entity.pos,
+                        Neighbourhood::sensing_radius(entity, params, conditions.vision_scale),
+                    );
+                    let action_radius = Neighbourhood::action_radius(entity, params);
+                    let neighbors_orgs = neighbourhood.organisms(action_radius);
+                    let neighbor_foods = neighbourhood.food(action_radius);
 
                     // Check for collisions with other organisms
-                    for (_, neighbor_id) in &neighbors_orgs {
-                        let neighbor_org = &ecosystem_snapshot.organisms[*neighbor_id];
-                        if neighbor_org.id == entity.id {
-                            continue; // skip self
-                        }
-                        let org_org_distance =
-                            (&entity.pos - &neighbor_org.pos).mapv(f32::abs).sum();
-                        if org_org_distance < params.body_radius * 2.0 {
-                            entity.kill(); // collision with another organism
-                        }
-                    }
+                    entity.touch.contacts +=
+                        collision::resolve(entity, neighbors_orgs, &world.organisms, params, dt);
 
                     // Generate brain inputs using perception system
-                    // Arc dereference is cheap - just a pointer read
-                    let brain_inputs = perception.perceive(
-                        entity,
-                        &ecosystem_snapshot,
-                        params,
-                        Some(&spatial_trees),
-                    );
+                    let brain_inputs = perception.perceive(entity, &world, params, &neighbourhood);
 
                     // Store brain inputs for visualization
                     entity.last_brain_inputs.clone_from(&brain_inputs);
 
+                    // Everything felt so far has now been perceived
+                    entity.touch.clear();
+
                     // Process brain outputs
                     let brain_outputs = entity.brain.think(&brain_inputs);
 
-                    // Update signal and memory from brain outputs
-                    entity.signal = brain_outputs.slice(s![..params.signal_size]).to_owned();
-                    entity.memory = brain_outputs
-                        .slice(s![
-                            params.signal_size..params.signal_size + params.memory_size
-                        ])
-                        .to_owned();
-
                     // update age, cooldown, and idle energy consumption
                     entity.age_by(dt);
                     entity.update_cooldown(dt);
-                    entity.consume_energy(params.idle_energy_rate * dt);
+                    entity.consume_energy(
+                        (params.idle_energy_rate * conditions.idle_energy_scale
+                            + morphology::metabolic_rate(entity, params))
+                            * dt,
+                        Flow::Metabolism,
+                    );
+
+                    // Heal wounds and carry armour
+                    combat::maintain(entity, params, dt);
 
                     // Execute all organism actions and collect events
-                    let entity_events = actions::execute_all_actions(
-                        entity,
-                        &brain_outputs,
-                        &neighbors_orgs,
-                        &neighbor_foods,
-                        &ecosystem_snapshot.organisms,
-                        &ecosystem_snapshot.food,
-                        params,
-                        dt,
-                    );
+                    let context = actions::ActionContext {
+                        neighbors_orgs,
+                        neighbor_foods,
+                        organisms: &world.organisms,
+                        food_items: world.food,
+                    };
+                    let entity_events =
+                        actuation.execute(entity, &brain_outputs, &context, params, dt);
+
+                    // Carry momentum from thrust and bounces
+                    physics::integrate(entity, params, dt);
+
+                    // Keep the body out of walls and rocks
+                    if obstacle::resolve_collision(
+                        &mut entity.pos,
+                        entity.morphology.body_radius(params),
+                        world.obstacles,
+                    ) {
+                        entity.touch.contacts += 1;
+                    }
                     chunk_events.extend(entity_events);
                 }
                 chunk_events
@@ -233,43 +318,13 @@ impl Ecosystem {
             .collect();
         self.timing_stats.parallel_update_ms = parallel_start.elapsed().as_secs_f32() * 1000.0;
 
-        // Update projectiles and check for collisions using KD tree
+        // Move projectiles, sweeping their paths for hits. Organisms have
+        // moved since the index was built, and a stale grid would miss those
+        // that moved into a path from outside the query radius.
         let projectile_start = Instant::now();
-        let mut projectile_events = Vec::new();
-        for (proj_idx, projectile) in self.projectiles.iter_mut().enumerate() {
-            projectile.update(dt);
-
-            // Use spatial index to find nearby organisms within collision range
-            let collision_radius = params.body_radius + params.projectile_radius;
-            let nearby_organisms = spatial_index.query_organisms(&projectile.pos, collision_radius);
-
-            // Check collision with nearby organisms
-            for (_, org_id) in &nearby_organisms {
-                let organism = &self.organisms[*org_id];
-
-                if organism.id == projectile.owner_id {
-                    continue; // Don't hit self
-                }
-
-                let distance = (&projectile.pos - &organism.pos)
-                    .mapv(|x| x.powi(2))
-                    .sum()
-                    .sqrt();
-
-                if distance < collision_radius {
-                    projectile_events.push(events::SimulationEvent::ProjectileHit {
-                        projectile_idx: proj_idx,
-                        target_id: organism.id,
-                        damage: projectile.damage,
-                        owner_id: projectile.owner_id,
-                    });
-                    break;
-                }
-            }
-        }
-
-        // Remove expired projectiles
-        self.projectiles.retain(|p| !p.is_expired());
+        spatial_index.rebuild_organisms(self, params);
+        let mut projectile_events = projectile::update_all(self, &spatial_index, params, dt);
+        self.spatial_index = spatial_index;
 
         // Generate OrganismDied events for dead organisms
         for organism in &self.organisms {
@@ -295,12 +350,22 @@ impl Ecosystem {
         events::apply_events(self, params, combined_events);
         self.timing_stats.event_application_ms = event_start.elapsed().as_secs_f32() * 1000.0;
 
-        // Record deaths and add to graveyard before removing organisms
+        // Collect the energy each organism spent or received, and whatever
+        // the dead take with them
         let cleanup_start = Instant::now();
+        for organism in &mut self.organisms {
+            if !organism.is_alive() {
+                organism.energy_flows.record(Flow::Death, -organism.energy);
+            }
+            self.energy_ledger.collect(organism);
+        }
+
+        // Record deaths and add to graveyard before removing organisms
         for organism in &self.organisms {
             if !organism.is_alive() {
                 self.evolution_engine
-                    .record_death(organism, &mut self.reproduction_stats);
+                    .record_death(organism, &mut self.reproduction_stats, params);
+                self.resource_field.decompose(&organism.pos, params);
             }
         }
 
@@ -313,9 +378,27 @@ impl Ecosystem {
             food_item.age += dt;
         }
 
-        self.food.retain(|f| f.age < params.food_lifetime);
+        for food_item in &self.food {
+            if food_item.is_expired(&params.food_kinds) {
+                self.energy_ledger
+                    .record_food(Flow::FoodLost, -food_item.energy);
+            }
+        }
+        self.food.retain(|f| !f.is_expired(&params.food_kinds));
+
+        // Regrow and diffuse soil nutrients
+        self.resource_field.update(params, dt);
+
+        // Evaporate and diffuse pheromone trails
+        self.pheromones.update(params, dt);
+
+        // Exchange organisms between connected pools (island model)
+        migration::migrate(self, params, dt);
         self.timing_stats.cleanup_ms = cleanup_start.elapsed().as_secs_f32() * 1000.0;
 
+        // Check the step's flows against the change in stock
+        self.energy_ledger.close_step(energy::stock(self), params);
+
         self.timing_stats.total_ms = step_start.elapsed().as_secs_f32() * 1000.0;
     }
 
@@ -328,9 +411,13 @@ impl Ecosystem {
         let center = Array1::from_vec(vec![params.box_width / 2., params.box_height / 2.]);
 
         // Automatic asexual reproduction from graveyard
-        // This complements organism-initiated reproduction
+        // This complements organism-initiated reproduction (see `Params::reproduction_mode`)
         let current_count = self.organisms.len();
-        let max_allowed = params.max_organism.saturating_sub(current_count);
+        let max_allowed = if params.reproduction_mode.allows_spawning() {
+            params.max_organism.saturating_sub(current_count)
+        } else {
+            0
+        };
 
         let organisms_to_spawn_f = params.organism_spawn_rate * dt;
         let organisms_to_spawn = organisms_to_spawn_f.floor() as usize;
@@ -358,18 +445,34 @@ impl Ecosystem {
             // Spawn organism from graveyard or living organisms
             let new_organism = if params.spawn_from_graveyard {
                 // Evolution-based: spawn from graveyard
-                self.evolution_engine.spawn_organism(
+                let (organism, refusal) = self.evolution_engine.spawn_organism(
                     self.generation,
                     target_pool_id,
                     &center,
                     params,
-                )
+                );
+                if let Some(refusal) = refusal {
+                    self.event_log.log(
+                        self.time,
+                        format!(
+                            "Graveyard organisms {} and {} could not breed: {}",
+                            refusal.parent1_id, refusal.parent2_id, refusal.reason
+                        ),
+                        EventColor::Reproduction,
+                    );
+                }
+                organism
             } else {
                 // Reproduction-based: clone from living organisms
                 self.spawn_from_living(target_pool_id, &center, params)
             };
 
             self.generation += 1;
+            self.energy_ledger.record_pool(
+                new_organism.pool_id,
+                Flow::Spawned,
+                new_organism.energy,
+            );
             self.organisms.push(new_organism);
         }
 
@@ -380,7 +483,8 @@ impl Ecosystem {
         let max_allowed_food = params.max_food.saturating_sub(current_food_count);
         if max_allowed_food > 0 {
             // Calculate spawn amount based on rate per second
-            let food_to_spawn_f = params.food_spawn_rate * dt;
+            let conditions = environment::conditions(self.time, params);
+            let food_to_spawn_f = params.food_spawn_rate * conditions.food_rate_scale * dt;
             let base_spawn = food_to_spawn_f.floor() as usize;
 
             // Fractional part determines probability of spawning one more
@@ -395,8 +499,17 @@ impl Ecosystem {
             let total_food_to_spawn = (base_spawn + extra).min(max_allowed_food);
 
             for _ in 0..total_food_to_spawn {
-                let food_item = food::Food::new_random(&center, params.food_energy);
-                self.food.push(food_item);
+                if let Some(food_item) = Self::random_food(
+                    &center,
+                    &mut self.resource_field,
+                    &self.obstacles,
+                    conditions.food_energy_scale,
+                    params,
+                ) {
+                    self.energy_ledger
+                        .record_food(Flow::FoodGrown, food_item.energy);
+                    self.food.push(food_item);
+                }
             }
         }
     }
@@ -421,6 +534,46 @@ impl Ecosystem {
         Array1::random(2, Uniform::new(0., 1.)) * center * 2.0
     }
 
+    /// Spawns a food item of a kind sampled by spawn weight, or `None` if no kind spawns on its own.
+    ///
+    /// With the resource field enabled, food grows in a cell chosen by its
+    /// nutrients and takes its energy from that cell; `None` if no cell can afford it.
+    /// Food never spawns inside an obstacle; `None` if no free spot is found.
+    /// The kind's energy is multiplied by `energy_scale` (the seasonal modulation).
+    fn random_food(
+        center: &Array1<f32>,
+        resource_field: &mut ResourceField,
+        obstacles: &[Obstacle],
+        energy_scale: f32,
+        params: &Params,
+    ) -> Option<food::Food> {
+        const MAX_PLACEMENT_ATTEMPTS: usize = 10;
+
+        let kind = food::sample_spawn_kind(&params.food_kinds)?;
+        let energy = kind.spec(&params.food_kinds)?.energy * energy_scale;
+        if params.resource_field.enabled && !resource_field.fits(params) {
+            *resource_field = ResourceField::new(params);
+        }
+
+        for _ in 0..MAX_PLACEMENT_ATTEMPTS {
+            let mut food_item = food::Food::new_random(center, kind, energy);
+            if params.resource_field.enabled {
+                food_item.pos = resource_field.take_food_position(energy)?;
+                // Edge cells may extend past the world boundary
+                food_item.pos[0] = food_item.pos[0].min(params.box_width);
+                food_item.pos[1] = food_item.pos[1].min(params.box_height);
+            }
+            if !obstacle::is_blocked(obstacles, &food_item.pos) {
+                return Some(food_item);
+            }
+            // Blocked spot: give the nutrients back and try elsewhere
+            if params.resource_field.enabled {
+                resource_field.deposit(&food_item.pos, energy);
+            }
+        }
+        None
+    }
+
     /// Selects a pool ID weighted by pool size (larger pools more likely).
     fn select_pool_weighted_by_size(&self, params: &Params) -> usize {
         // Count organisms per pool
@@ -458,6 +611,9 @@ impl Ecosystem {
         center: &Array1<f32>,
         params: &Params,
     ) -> organism::Organism {
+        let pool_params = params.for_pool(target_pool_id);
+        let params: &Params = &pool_params;
+
         // Find all organisms in target pool
         let pool_organisms: Vec<&organism::Organism> = self
             .organisms
@@ -485,12 +641,14 @@ impl Ecosystem {
         let parent = pool_organisms[rand::rng().random_range(0..pool_organisms.len())];
         let mut child = parent.clone();
         child.id = self.generation as usize;
-        child.age = 0.0;
-        child.score = 0;
+        child.reset_as_offspring(params);
         child.pos = Self::random_spawn_position(center, params);
 
-        // Apply mutation to brain
-        child.brain.mutate(0.1); // Use moderate mutation rate
+        // Apply mutation to brain, morphology and mate preference
+        child.brain.mutate(params.brain_mutation_rate);
+        morphology::mutate(&mut child, params);
+        diet::mutate(&mut child, params);
+        mate_choice::mutate_preference(&mut child.mate_preference, params);
 
         child
     }
@@ -509,6 +667,8 @@ impl Ecosystem {
 
         // Find empty and non-empty pools
         for pool_id in 0..params.num_genetic_pools {
+            let pool_params = params.for_pool(pool_id);
+            let params: &Params = &pool_params;
             if pool_counts[pool_id] == 0 {
                 // Pool is empty, find a non-empty pool to seed from
                 let non_empty_pools: Vec<usize> = pool_counts
@@ -534,6 +694,8 @@ impl Ecosystem {
                             params,
                         );
                         self.generation += 1;
+                        self.energy_ledger
+                            .record_pool(pool_id, Flow::Spawned, new_organism.energy);
                         self.organisms.push(new_organism);
                     }
                 } else {
@@ -550,19 +712,39 @@ impl Ecosystem {
                         .collect();
 
                     // Create new organisms from cloned source pool to empty pool
+                    let template = organism::Organism::new_random(
+                        self.generation as usize,
+                        &center,
+                        params.signal_size,
+                        params.memory_size,
+                        params.num_vision_directions,
+                        params.vision_radius,
+                        params.fov,
+                        params.layer_sizes.clone(),
+                        pool_id,
+                        params,
+                    );
                     for _ in 0..params.empty_pool_seed_count {
                         if let Some(parent) = source_organisms
                             .get(rand::rng().random_range(0..source_organisms.len()))
                         {
                             let mut child = parent.clone();
+                            if !parent.brain.same_architecture(&template.brain) {
+                                // The pool grows a different kind of brain
+                                child.brain = template.brain.clone();
+                            }
                             child.id = self.generation as usize;
                             child.pool_id = pool_id; // Change to empty pool
-                            child.age = 0.0;
-                            child.score = 0;
+                            child.reset_as_offspring(params);
                             child.pos = Self::random_spawn_position(&center, params);
-                            child.brain.mutate(0.1); // Mutate to create diversity
+                            child.brain.mutate(params.brain_mutation_rate); // Mutate to create diversity
+                            morphology::mutate(&mut child, params);
+                            diet::mutate(&mut child, params);
+                            mate_choice::mutate_preference(&mut child.mate_preference, params);
 
                             self.generation += 1;
+                            self.energy_ledger
+                                .record_pool(pool_id, Flow::Spawned, child.energy);
                             self.organisms.push(child);
                         }
                     }
@@ -578,4 +760,9 @@ impl Ecosystem {
     pub fn graveyard(&self) -> &[organism::Organism] {
         self.evolution_engine.graveyard()
     }
+
+    /// Returns a reference to the novelty archive of past behaviours.
+    pub fn novelty_archive(&self) -> &NoveltyArchive {
+        self.evolution_engine.novelty_archive()
+    }
 }
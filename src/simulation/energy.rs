@@ -0,0 +1,356 @@
+//! Global energy accounting.
+//!
+//! Energy lives in two stocks: organisms (split by genetic pool) and food.
+//! Every change to either stock is recorded under a [`Flow`] — food growing,
+//! organisms burning energy to move, energy passing from food to organisms
+//! or between organisms, offspring being born with multiplied energy, and so
+//! on. The [`EnergyLedger`] tallies these flows per step and since the start
+//! and checks that they add up to the change in the stocks, so any energy
+//! that appears or disappears without being recorded shows up as an
+//! imbalance (and fails the step in strict mode).
+//!
+//! Organisms record their own flows into [`Organism::energy_flows`] while
+//! they are updated in parallel; the ecosystem collects them into the ledger
+//! once the step's events have been applied.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::ecosystem::Ecosystem;
+use super::organism::Organism;
+use super::params::Params;
+
+/// Energy accounting configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnergyLedgerParams {
+    /// If true, a step whose flows don't add up to the change in stock panics.
+    pub strict: bool,
+    /// Largest imbalance tolerated, relative to the total stock (at least 1).
+    pub tolerance: f32,
+}
+
+impl Default for EnergyLedgerParams {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            tolerance: 1e-3,
+        }
+    }
+}
+
+/// A way energy enters, leaves or moves through the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Flow {
+    /// Food growing on its own (food stock).
+    FoodGrown,
+    /// Corpses left by combat deaths (food stock).
+    Carrion,
+    /// Organisms spawned from the graveyard or living organisms.
+    Spawned,
+    /// Energy offspring are born with.
+    Born,
+    /// Food eaten: taken from food, given to the eater.
+    Eaten,
+    /// Part of eaten food not digested (diet efficiency below 1).
+    Digestion,
+    /// Energy given to another organism.
+    Shared,
+    /// Energy stolen by a bite.
+    Stolen,
+    /// Organisms moving between or copied into pools.
+    Migrated,
+    /// Idle and body upkeep.
+    Metabolism,
+    /// Movement and thrust.
+    Movement,
+    /// Turning and torque.
+    Rotation,
+    /// Firing projectiles and biting.
+    Attack,
+    /// Laying pheromones.
+    Pheromones,
+    /// Touching the world edge.
+    Boundary,
+    /// Overlapping other bodies (`Penalty` collisions).
+    Collision,
+    /// Carrying armour and healing.
+    Combat,
+    /// Parents' contribution and overhead for offspring.
+    Reproduction,
+    /// Damage taken out of energy (combat disabled).
+    Damage,
+    /// Energy gained beyond `max_energy`, discarded.
+    Overflow,
+    /// Energy left in organisms when they die.
+    Death,
+    /// Food that expired or was destroyed.
+    FoodLost,
+}
+
+impl Flow {
+    /// Every flow, in display order.
+    pub const ALL: [Self; 22] = [
+        Self::FoodGrown,
+        Self::Carrion,
+        Self::Spawned,
+        Self::Born,
+        Self::Eaten,
+        Self::Digestion,
+        Self::Shared,
+        Self::Stolen,
+        Self::Migrated,
+        Self::Metabolism,
+        Self::Movement,
+        Self::Rotation,
+        Self::Attack,
+        Self::Pheromones,
+        Self::Boundary,
+        Self::Collision,
+        Self::Combat,
+        Self::Reproduction,
+        Self::Damage,
+        Self::Overflow,
+        Self::Death,
+        Self::FoodLost,
+    ];
+
+    /// Number of flows.
+    pub const COUNT: usize = Self::ALL.len();
+
+    /// Human-readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::FoodGrown => "Food Grown",
+            Self::Carrion => "Carrion",
+            Self::Spawned => "Spawned",
+            Self::Born => "Born",
+            Self::Eaten => "Eaten",
+            Self::Digestion => "Digestion",
+            Self::Shared => "Shared",
+            Self::Stolen => "Stolen",
+            Self::Migrated => "Migrated",
+            Self::Metabolism => "Metabolism",
+            Self::Movement => "Movement",
+            Self::Rotation => "Rotation",
+            Self::Attack => "Attack",
+            Self::Pheromones => "Pheromones",
+            Self::Boundary => "Boundary",
+            Self::Collision => "Collision",
+            Self::Combat => "Combat",
+            Self::Reproduction => "Reproduction",
+            Self::Damage => "Damage",
+            Self::Overflow => "Overflow",
+            Self::Death => "Death",
+            Self::FoodLost => "Food Lost",
+        }
+    }
+}
+
+/// Signed change to a stock, per flow.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(from = "BTreeMap<Flow, f32>", into = "BTreeMap<Flow, f32>")]
+pub struct FlowTotals([f32; Flow::COUNT]);
+
+impl Default for FlowTotals {
+    fn default() -> Self {
+        Self([0.0; Flow::COUNT])
+    }
+}
+
+impl FlowTotals {
+    /// Adds `amount` (positive into the stock, negative out of it) to `flow`.
+    pub fn record(&mut self, flow: Flow, amount: f32) {
+        self.0[flow as usize] += amount;
+    }
+
+    /// Total recorded for `flow`.
+    pub fn get(&self, flow: Flow) -> f32 {
+        self.0[flow as usize]
+    }
+
+    /// Net change to the stock over all flows.
+    pub fn net(&self) -> f32 {
+        self.0.iter().sum()
+    }
+
+    /// Adds every flow of `other`.
+    pub fn add(&mut self, other: &Self) {
+        for (total, amount) in self.0.iter_mut().zip(other.0) {
+            *total += amount;
+        }
+    }
+
+    /// Returns the totals and resets them to zero.
+    #[must_use]
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+}
+
+impl From<BTreeMap<Flow, f32>> for FlowTotals {
+    fn from(map: BTreeMap<Flow, f32>) -> Self {
+        let mut totals = Self::default();
+        for (flow, amount) in map {
+            totals.record(flow, amount);
+        }
+        totals
+    }
+}
+
+impl From<FlowTotals> for BTreeMap<Flow, f32> {
+    fn from(totals: FlowTotals) -> Self {
+        Flow::ALL
+            .iter()
+            .map(|&flow| (flow, totals.get(flow)))
+            .collect()
+    }
+}
+
+/// Flows into and out of every stock over some period.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnergyTally {
+    /// Flows of each pool's organisms, indexed by pool id.
+    pub pools: Vec<FlowTotals>,
+    /// Flows of food.
+    pub food: FlowTotals,
+}
+
+impl EnergyTally {
+    /// Flows of `pool_id`'s organisms (zero if nothing was recorded for it).
+    pub fn pool(&self, pool_id: usize) -> FlowTotals {
+        self.pools.get(pool_id).copied().unwrap_or_default()
+    }
+
+    /// Total of `flow` over all stocks.
+    pub fn total(&self, flow: Flow) -> f32 {
+        self.pools.iter().map(|p| p.get(flow)).sum::<f32>() + self.food.get(flow)
+    }
+
+    /// Net change over all stocks.
+    pub fn net(&self) -> f32 {
+        self.pools.iter().map(FlowTotals::net).sum::<f32>() + self.food.net()
+    }
+
+    /// Records a change to `pool_id`'s organisms.
+    pub fn record_pool(&mut self, pool_id: usize, flow: Flow, amount: f32) {
+        self.pool_mut(pool_id).record(flow, amount);
+    }
+
+    /// Adds every flow of `other`.
+    pub fn add(&mut self, other: &Self) {
+        for (pool_id, totals) in other.pools.iter().enumerate() {
+            self.pool_mut(pool_id).add(totals);
+        }
+        self.food.add(&other.food);
+    }
+
+    fn pool_mut(&mut self, pool_id: usize) -> &mut FlowTotals {
+        if self.pools.len() <= pool_id {
+            self.pools.resize(pool_id + 1, FlowTotals::default());
+        }
+        &mut self.pools[pool_id]
+    }
+}
+
+/// Tallies every energy flow per step, per pool and since the start.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnergyLedger {
+    /// Flows recorded since the last step closed.
+    current: EnergyTally,
+    /// Flows of the most recent step.
+    last_step: EnergyTally,
+    /// Flows since the ledger started.
+    total: EnergyTally,
+    /// Total stock when the last step closed (`None` before the first).
+    stock: Option<f32>,
+    /// Change in stock not explained by the last step's flows.
+    imbalance: f32,
+    /// Number of steps closed.
+    steps: u64,
+}
+
+impl EnergyLedger {
+    /// Creates a ledger whose first step starts from `stock`.
+    pub fn new(stock: f32) -> Self {
+        Self {
+            stock: Some(stock),
+            ..Self::default()
+        }
+    }
+
+    /// Records a change to `pool_id`'s organisms.
+    pub fn record_pool(&mut self, pool_id: usize, flow: Flow, amount: f32) {
+        self.current.record_pool(pool_id, flow, amount);
+    }
+
+    /// Records a change to food.
+    pub fn record_food(&mut self, flow: Flow, amount: f32) {
+        self.current.food.record(flow, amount);
+    }
+
+    /// Moves the flows an organism recorded into its pool.
+    pub fn collect(&mut self, organism: &mut Organism) {
+        let flows = organism.energy_flows.take();
+        self.current.pool_mut(organism.pool_id).add(&flows);
+    }
+
+    /// Ends the step, comparing its flows with the change in `stock`.
+    ///
+    /// # Panics
+    ///
+    /// In strict mode, if the imbalance exceeds the tolerance.
+    pub fn close_step(&mut self, stock: f32, params: &Params) {
+        let step = std::mem::take(&mut self.current);
+        self.imbalance = self
+            .stock
+            .map_or(0.0, |previous| stock - previous - step.net());
+        self.stock = Some(stock);
+        self.steps += 1;
+        self.total.add(&step);
+        self.last_step = step;
+
+        let ledger = &params.energy_ledger;
+        let tolerance = ledger.tolerance * stock.abs().max(1.0);
+        assert!(
+            !ledger.strict || self.imbalance.abs() <= tolerance,
+            "energy not conserved in step {}: {:+.6} unaccounted for (tolerance {:.6})",
+            self.steps,
+            self.imbalance,
+            tolerance
+        );
+    }
+
+    /// Flows of the most recent step.
+    pub fn last_step(&self) -> &EnergyTally {
+        &self.last_step
+    }
+
+    /// Flows since the ledger started.
+    pub fn total(&self) -> &EnergyTally {
+        &self.total
+    }
+
+    /// Change in stock the most recent step's flows did not account for.
+    pub fn imbalance(&self) -> f32 {
+        self.imbalance
+    }
+
+    /// Number of steps closed.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Exports the ledger as JSON.
+    pub fn save_to_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Total energy held by organisms and food.
+pub fn stock(state: &Ecosystem) -> f32 {
+    state.organisms.iter().map(|o| o.energy).sum::<f32>()
+        + state.food.iter().map(|f| f.energy).sum::<f32>()
+}
@@ -0,0 +1,166 @@
+//! Day/night and seasonal environmental cycles.
+//!
+//! The environment schedule is a pure function of `Ecosystem::time`. A day
+//! cycle scales how far organisms can see, and a seasonal cycle scales the
+//! food spawn rate, the energy of newly grown food and the idle energy cost.
+//! Each cycle follows a curve that maps its phase to a level in [0, 1]
+//! (1 = noon or midsummer), and every modulated quantity is interpolated
+//! between its low-level scale and 1.0.
+
+use serde::{Deserialize, Serialize};
+
+use super::params::Params;
+
+/// Shape of an environmental cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CycleCurve {
+    /// Smooth sinusoid: level 0 at phase 0, 1 at phase 0.5.
+    Sine,
+    /// Piecewise-linear curve through `[phase, level]` points, wrapping from
+    /// the last point back to the first. Phases are in [0, 1).
+    Piecewise(Vec<[f32; 2]>),
+}
+
+impl CycleCurve {
+    /// Level of the curve at `phase` (in [0, 1)), clamped to [0, 1].
+    pub fn level(&self, phase: f32) -> f32 {
+        match self {
+            Self::Sine => 0.5 - 0.5 * (phase * std::f32::consts::TAU).cos(),
+            Self::Piecewise(points) => piecewise_level(points, phase),
+        }
+        .clamp(0.0, 1.0)
+    }
+}
+
+/// Environment schedule configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentParams {
+    /// If false, the environment is stationary and organisms get no clock sense.
+    pub enabled: bool,
+    /// Length of a day in simulation seconds.
+    pub day_length: f32,
+    /// Daylight over a day.
+    pub day_curve: CycleCurve,
+    /// Vision range multiplier at darkest night (1.0 at noon).
+    pub night_vision_scale: f32,
+    /// Length of a year in simulation seconds.
+    pub year_length: f32,
+    /// Warmth over a year.
+    pub season_curve: CycleCurve,
+    /// Food spawn rate multiplier in deepest winter (1.0 in midsummer).
+    pub winter_food_rate_scale: f32,
+    /// Energy multiplier for food grown in deepest winter (1.0 in midsummer).
+    pub winter_food_energy_scale: f32,
+    /// Idle energy cost multiplier in deepest winter (1.0 in midsummer).
+    pub winter_idle_energy_scale: f32,
+}
+
+impl Default for EnvironmentParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            day_length: 60.0,
+            day_curve: CycleCurve::Sine,
+            night_vision_scale: 0.4,
+            year_length: 600.0,
+            season_curve: CycleCurve::Sine,
+            winter_food_rate_scale: 0.3,
+            winter_food_energy_scale: 0.7,
+            winter_idle_energy_scale: 1.5,
+        }
+    }
+}
+
+/// Environmental conditions at one moment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conditions {
+    /// Position within the day, in [0, 1).
+    pub day_phase: f32,
+    /// Position within the year, in [0, 1).
+    pub season_phase: f32,
+    /// Daylight level, in [0, 1].
+    pub daylight: f32,
+    /// Season warmth level, in [0, 1].
+    pub warmth: f32,
+    /// Multiplier on vision range.
+    pub vision_scale: f32,
+    /// Multiplier on `food_spawn_rate`.
+    pub food_rate_scale: f32,
+    /// Multiplier on the energy of newly spawned food.
+    pub food_energy_scale: f32,
+    /// Multiplier on `idle_energy_rate`.
+    pub idle_energy_scale: f32,
+}
+
+impl Default for Conditions {
+    /// Stationary conditions: full daylight, midsummer, no modulation.
+    fn default() -> Self {
+        Self {
+            day_phase: 0.0,
+            season_phase: 0.0,
+            daylight: 1.0,
+            warmth: 1.0,
+            vision_scale: 1.0,
+            food_rate_scale: 1.0,
+            food_energy_scale: 1.0,
+            idle_energy_scale: 1.0,
+        }
+    }
+}
+
+/// Environmental conditions at simulation `time`.
+pub fn conditions(time: f32, params: &Params) -> Conditions {
+    let env = &params.environment;
+    if !env.enabled {
+        return Conditions::default();
+    }
+
+    let day_phase = phase(time, env.day_length);
+    let season_phase = phase(time, env.year_length);
+    let daylight = env.day_curve.level(day_phase);
+    let warmth = env.season_curve.level(season_phase);
+    let lerp = |low: f32, level: f32| low + (1.0 - low) * level;
+
+    Conditions {
+        day_phase,
+        season_phase,
+        daylight,
+        warmth,
+        vision_scale: lerp(env.night_vision_scale, daylight),
+        food_rate_scale: lerp(env.winter_food_rate_scale, warmth),
+        food_energy_scale: lerp(env.winter_food_energy_scale, warmth),
+        idle_energy_scale: lerp(env.winter_idle_energy_scale, warmth),
+    }
+}
+
+/// Fraction of the current cycle elapsed at `time`; 0 for non-positive periods.
+fn phase(time: f32, period: f32) -> f32 {
+    if period > 0.0 {
+        (time / period).rem_euclid(1.0)
+    } else {
+        0.0
+    }
+}
+
+fn piecewise_level(points: &[[f32; 2]], phase: f32) -> f32 {
+    match points {
+        [] => 1.0,
+        [[_, level]] => *level,
+        _ => {
+            // Find the segment containing `phase`, wrapping past the last point
+            let next = points.iter().position(|p| p[0] > phase).unwrap_or(0);
+            let prev = if next == 0 {
+                points.len() - 1
+            } else {
+                next - 1
+            };
+            let ([p0, l0], [p1, l1]) = (points[prev], points[next]);
+            let span = (p1 - p0).rem_euclid(1.0);
+            if span <= f32::EPSILON {
+                return l0;
+            }
+            let t = (phase - p0).rem_euclid(1.0) / span;
+            l0 + (l1 - l0) * t
+        }
+    }
+}
@@ -27,6 +27,8 @@ pub enum EventColor {
     Death,
     /// Food consumption (yellow)
     Food,
+    /// Migration between genetic pools (purple)
+    Migration,
 }
 
 /// Event log that tracks recent simulation events
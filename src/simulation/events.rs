@@ -1,18 +1,33 @@
 //! Event system for thread-safe simulation state updates.
 //!
 //! Uses an event queue to collect state changes from parallel organism updates,
-//! then applies them serially to avoid race conditions.
-
+//! then applies them grouped by the organism or food item they touch. Groups
+//! are applied in parallel, and claims on the same food item, target or share
+//! receiver are settled deterministically (see [`super::conflict`]).
+
+use super::boundary;
+use super::combat::{self, DamageSource, KillRecord};
+use super::conflict::{Claim, ConflictPolicy, Resource};
+use super::diet;
 use super::ecosystem::Ecosystem;
+use super::energy::Flow;
 use super::event_log::EventColor;
+use super::food::{Food, FoodKind};
+use super::mate_choice;
+use super::morphology;
+use super::organism::Organism;
 use super::params::Params;
 use super::projectile;
+use super::reproduction;
 use ndarray::Array1;
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 
 /// Events that modify simulation state.
 ///
-/// Collected during parallel updates and applied serially to maintain consistency.
+/// Collected during parallel updates and applied afterwards, in an order that
+/// doesn't depend on the order they were collected in.
 #[derive(Debug, Clone)]
 pub enum SimulationEvent {
     /// An organism consumed a food item.
@@ -40,16 +55,27 @@ pub enum SimulationEvent {
         /// Position where the organism died (for corpse placement).
         pos: Array1<f32>,
     },
-    /// A projectile hit an organism.
+    /// A projectile hit an organism (the projectile has already been removed).
     ProjectileHit {
-        /// Index of the projectile in the projectiles vector.
-        projectile_idx: usize,
         /// ID of the organism that was hit.
         target_id: usize,
         /// Amount of damage to deal.
         damage: f32,
         /// ID of the organism that fired the projectile.
         owner_id: usize,
+        /// Direction the projectile came from (opposite to its velocity).
+        from: Array1<f32>,
+    },
+    /// An organism bit another organism.
+    Bite {
+        /// ID of the organism biting.
+        attacker_id: usize,
+        /// ID of the organism bitten.
+        target_id: usize,
+        /// Bite strength in [0, 1], scaling damage and energy taken.
+        strength: f32,
+        /// Direction the bite came from (from the target towards the attacker).
+        from: Array1<f32>,
     },
     /// An organism shared energy with another organism.
     EnergyShared {
@@ -80,6 +106,15 @@ pub enum SimulationEvent {
         /// Position of the organism.
         pos: Array1<f32>,
     },
+    /// An organism released pheromone into the field.
+    PheromoneDeposited {
+        /// Position of the organism.
+        pos: Array1<f32>,
+        /// Pheromone channel.
+        channel: usize,
+        /// Concentration added to the cell.
+        amount: f32,
+    },
 }
 
 /// Queue for collecting simulation events from parallel updates.
@@ -110,7 +145,58 @@ impl EventQueue {
     }
 }
 
+/// A blow struck at an organism during a step.
+#[derive(Debug, Clone)]
+struct Blow {
+    /// ID of the organism striking.
+    attacker_id: usize,
+    /// Index of the attacker, if it is still alive to collect what it steals.
+    attacker: Option<usize>,
+    /// Distance from the attacker to the target.
+    distance: f32,
+    /// Damage dealt, before armour.
+    damage: f32,
+    /// Energy the blow tries to steal (bites only).
+    steal: f32,
+    /// Direction the blow came from.
+    from: Array1<f32>,
+    /// What dealt the blow.
+    source: DamageSource,
+}
+
+/// What became of an organism struck during a step.
+struct Struck {
+    /// Energy stolen from it, by attacker index.
+    stolen: Vec<(usize, f32)>,
+    /// Where it died and who killed it, if the blows were fatal.
+    killed: Option<(Array1<f32>, KillRecord)>,
+}
+
+/// How one food item was shared out: each claimant's index and the energy it
+/// digested, if it got any.
+type Portions = Vec<(usize, Option<f32>)>;
+
+/// A change to one organism decided while resolving the step's events.
+#[derive(Debug, Clone, Copy)]
+enum Effect {
+    /// Energy stolen from another organism.
+    Stole(f32),
+    /// Energy digested from (a share of) a food item.
+    Ate(f32),
+    /// Reached for a food item and got none of it.
+    MissedFood,
+    /// Energy given away.
+    Gave(f32),
+    /// Energy received as a gift.
+    Received(f32),
+}
+
 /// Applies all queued events to the ecosystem state.
+///
+/// Events touching different organisms and food items don't conflict, so
+/// they are resolved in parallel; claims on the same food item, target or
+/// share receiver are settled together under [`Params::conflicts`]. The
+/// result doesn't depend on the order of the queue.
 pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueue) {
     // Remove old interaction visualizations (older than 0.5 seconds)
     const VISUALIZATION_DURATION: f32 = 0.5;
@@ -121,15 +207,24 @@ pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueu
         .reproduction_intents
         .retain(|(_, _, timestamp)| state.time - timestamp < VISUALIZATION_DURATION);
 
-    // Track which food items are contested
-    let mut food_claims: HashMap<usize, Vec<usize>> = HashMap::new();
+    let index: HashMap<usize, usize> = state
+        .organisms
+        .iter()
+        .enumerate()
+        .map(|(i, organism)| (organism.id, i))
+        .collect();
+    let distance = |a: usize, b: usize| {
+        boundary::distance(&state.organisms[a].pos, &state.organisms[b].pos, params)
+    };
+
+    // Group events by the organism or food item they contend for
+    let mut food_claims: BTreeMap<usize, Vec<Claim>> = BTreeMap::new();
+    let mut blows: Vec<Vec<Blow>> = vec![Vec::new(); state.organisms.len()];
+    let mut gifts: BTreeMap<usize, Vec<(usize, f32)>> = BTreeMap::new();
+    let mut new_projectiles: Vec<projectile::Projectile> = Vec::new();
     let mut dead_organisms_natural: Vec<(usize, Array1<f32>)> = Vec::new(); // Natural deaths (no corpse)
-    let mut dead_organisms_combat: Vec<(usize, Array1<f32>)> = Vec::new(); // Combat deaths (spawn corpse)
-    let mut projectiles_to_remove: Vec<usize> = Vec::new();
-    let mut energy_transfers: Vec<(usize, usize, f32)> = Vec::new();
     let mut asexual_reproductions: Vec<(usize, Array1<f32>, f32)> = Vec::new();
-    let mut sexual_reproduction_intents: HashMap<usize, Vec<(usize, f32, Array1<f32>)>> =
-        HashMap::new();
+    let mut sexual_reproduction_intents: HashMap<usize, (usize, f32, Array1<f32>)> = HashMap::new();
 
     for event in queue.drain() {
         match event {
@@ -137,7 +232,18 @@ pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueu
                 organism_id,
                 food_id,
             } => {
-                food_claims.entry(food_id).or_default().push(organism_id);
+                if let Some(&claimant) = index.get(&organism_id) {
+                    let food_item = &state.food[food_id];
+                    food_claims.entry(food_id).or_default().push(Claim {
+                        claimant,
+                        distance: boundary::distance(
+                            &state.organisms[claimant].pos,
+                            &food_item.pos,
+                            params,
+                        ),
+                        demand: food_item.energy,
+                    });
+                }
             }
             SimulationEvent::ProjectileCreated {
                 pos,
@@ -145,55 +251,71 @@ pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueu
                 damage,
                 owner_id,
             } => {
-                let projectile = projectile::Projectile::new(
+                new_projectiles.push(projectile::Projectile::new(
                     pos,
                     rotation,
                     params.projectile_speed,
                     damage,
                     owner_id,
                     params.projectile_range,
-                );
-                state.projectiles.push(projectile);
+                ));
             }
             SimulationEvent::OrganismDied { organism_id, pos } => {
                 // Natural death - no corpse spawned
                 dead_organisms_natural.push((organism_id, pos));
             }
             SimulationEvent::ProjectileHit {
-                projectile_idx,
                 target_id,
                 damage,
                 owner_id,
+                from,
             } => {
-                // Apply damage to target
-                let mut target_killed = false;
-                let mut target_pos = None;
-                if let Some(org) = state.organisms.iter_mut().find(|o| o.id == target_id) {
-                    org.consume_energy(damage);
-                    if !org.is_alive() {
-                        target_killed = true;
-                        target_pos = Some(org.pos.clone());
-                    }
-                }
-                // Award point to attacker if target was killed
-                if target_killed
-                    && let Some(_attacker) = state.organisms.iter_mut().find(|o| o.id == owner_id)
-                {
-                    // _attacker.score += 1;
+                if let Some(&target) = index.get(&target_id) {
+                    let attacker = index.get(&owner_id).copied();
+                    blows[target].push(Blow {
+                        attacker_id: owner_id,
+                        attacker,
+                        distance: attacker.map_or(f32::INFINITY, |a| distance(a, target)),
+                        damage,
+                        steal: 0.0,
+                        from,
+                        source: DamageSource::Projectile,
+                    });
                 }
-                // Create corpse if organism was killed by projectile
-                if let Some(pos) = target_pos {
-                    dead_organisms_combat.push((target_id, pos));
+            }
+            SimulationEvent::Bite {
+                attacker_id,
+                target_id,
+                strength,
+                from,
+            } => {
+                if let Some(&target) = index.get(&target_id) {
+                    let attacker = index.get(&attacker_id).copied();
+                    blows[target].push(Blow {
+                        attacker_id,
+                        attacker,
+                        distance: attacker.map_or(f32::INFINITY, |a| distance(a, target)),
+                        damage: params.combat.bite_damage * strength,
+                        steal: if attacker.is_some() {
+                            params.combat.bite_energy * strength
+                        } else {
+                            0.0
+                        },
+                        from,
+                        source: DamageSource::Bite,
+                    });
                 }
-                // Mark projectile for removal
-                projectiles_to_remove.push(projectile_idx);
             }
             SimulationEvent::EnergyShared {
                 giver_id,
                 receiver_id,
                 amount,
             } => {
-                energy_transfers.push((giver_id, receiver_id, amount));
+                if let (Some(&giver), Some(&receiver)) =
+                    (index.get(&giver_id), index.get(&receiver_id))
+                {
+                    gifts.entry(receiver).or_default().push((giver, amount));
+                }
             }
             SimulationEvent::AsexualReproduction {
                 parent_id,
@@ -208,72 +330,173 @@ pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueu
                 energy_contribution,
                 pos,
             } => {
-                // Store intent - we'll match pairs later
+                // Store intent - we'll match pairs later (one intent per organism per step)
                 sexual_reproduction_intents
-                    .entry(partner_id)
-                    .or_default()
-                    .push((organism_id, energy_contribution, pos));
+                    .insert(organism_id, (partner_id, energy_contribution, pos));
+            }
+            SimulationEvent::PheromoneDeposited {
+                pos,
+                channel,
+                amount,
+            } => {
+                state.pheromones.deposit(&pos, channel, amount);
             }
         }
     }
 
-    // Resolve food consumption - first come first served
-    for (food_id, claimants) in food_claims {
-        if state.food[food_id].is_consumed() {
-            continue;
-        }
-
-        if let Some(&winner_id) = claimants.first() {
-            if let Some(org) = state.organisms.iter_mut().find(|o| o.id == winner_id) {
-                org.gain_energy(state.food[food_id].energy, params.max_energy);
-                org.score += 1;
+    // Projectiles join the world in order of their owners
+    new_projectiles.sort_by_key(|projectile| projectile.owner_id);
+    state.projectiles.extend(new_projectiles);
+
+    // Land the blows struck at each organism, one target per task
+    let time = state.time;
+    let struck: Vec<Struck> = state
+        .organisms
+        .par_iter_mut()
+        .zip(blows.par_iter_mut())
+        .enumerate()
+        .filter(|(_, (_, blows))| !blows.is_empty())
+        .map(|(i, (victim, blows))| strike(victim, i, blows, time, params))
+        .collect();
+
+    // Settle each contested food item, one item per task. Organisms that
+    // died, this step or before, no longer eat.
+    let meals: Vec<(usize, Portions)> = food_claims
+        .into_par_iter()
+        .filter(|(food_id, _)| !state.food[*food_id].is_consumed())
+        .filter_map(|(food_id, mut claims)| {
+            claims.retain(|claim| state.organisms[claim.claimant].is_alive());
+            if claims.is_empty() {
+                return None;
+            }
+            let food_item = &state.food[food_id];
+            let shares =
+                params
+                    .conflicts
+                    .allocate(food_item.energy, &claims, time, Resource::Food(food_id));
+            let portions = claims
+                .iter()
+                .zip(shares)
+                .map(|(claim, share)| {
+                    let organism = &state.organisms[claim.claimant];
+                    let digested = (share > 0.0)
+                        .then(|| share * diet::efficiency(organism, food_item.kind, params));
+                    (claim.claimant, digested)
+                })
+                .collect();
+            Some((food_id, portions))
+        })
+        .collect();
+
+    let mut effects: Vec<(usize, Effect)> = Vec::new();
+    let mut dead_organisms_combat: Vec<(Array1<f32>, KillRecord)> = Vec::new(); // Combat deaths (spawn corpse)
+    for struck in struck {
+        for (attacker, stolen) in struck.stolen {
+            let attacker_organism = &mut state.organisms[attacker];
+            if attacker_organism.is_alive() {
+                effects.push((attacker, Effect::Stole(stolen)));
+            } else {
+                // Killed while biting: what it took dies with it
+                attacker_organism.energy_flows.record(Flow::Stolen, stolen);
+                attacker_organism.energy_flows.record(Flow::Death, -stolen);
             }
-            state.food[food_id].consume();
         }
+        dead_organisms_combat.extend(struck.killed);
     }
-
-    // Remove projectiles that hit targets (in reverse order to maintain indices)
-    projectiles_to_remove.sort_unstable();
-    projectiles_to_remove.reverse();
-    for idx in projectiles_to_remove {
-        if idx < state.projectiles.len() {
-            state.projectiles.remove(idx);
+    for (food_id, portions) in meals {
+        let mut total_digested = 0.0;
+        for (claimant, digested) in portions {
+            match digested {
+                Some(digested) => {
+                    total_digested += digested;
+                    effects.push((claimant, Effect::Ate(digested)));
+                }
+                // Reached for it in vain
+                None => effects.push((claimant, Effect::MissedFood)),
+            }
         }
+        let energy = state.food[food_id].energy;
+        state
+            .energy_ledger
+            .record_food(Flow::Eaten, -total_digested);
+        state
+            .energy_ledger
+            .record_food(Flow::Digestion, total_digested - energy);
+        state.food[food_id].consume();
     }
+    apply_effects(&mut state.organisms, effects, params);
 
     // Create corpses only from combat deaths (organisms killed by projectiles)
     // Natural deaths do not spawn corpses
-    for (organism_id, pos) in dead_organisms_combat {
-        let corpse = super::food::Food {
-            pos,
-            energy: params.corpse_energy_ratio,
-            age: 0.0,
-        };
-        state.food.push(corpse);
+    let carrion_energy = FoodKind::Carrion
+        .spec(&params.food_kinds)
+        .map_or(0.0, |spec| spec.energy);
+    for (pos, record) in dead_organisms_combat {
+        if carrion_energy > 0.0 {
+            state
+                .energy_ledger
+                .record_food(Flow::Carrion, carrion_energy);
+            state.food.push(Food {
+                pos,
+                energy: carrion_energy,
+                age: 0.0,
+                kind: FoodKind::Carrion,
+            });
+        }
 
         // Log combat death
         state.event_log.log(
             state.time,
-            format!("Organism {} killed in combat", organism_id),
+            format!(
+                "Organism {} killed by organism {} ({})",
+                record.victim_id,
+                record.killer_id,
+                record.source.name()
+            ),
             EventColor::Combat,
         );
+        state.kill_log.record(record, params.combat.kill_log_size);
     }
 
-    // Process energy transfers
-    for (giver_id, receiver_id, amount) in energy_transfers {
-        // Find giver and deduct energy
-        let mut energy_to_give = 0.0;
-        if let Some(giver) = state.organisms.iter_mut().find(|o| o.id == giver_id) {
-            // Only share if giver has enough energy
-            energy_to_give = amount.min(giver.energy * 0.5); // Max 50% of current energy
-            giver.consume_energy(energy_to_give);
-        }
-
-        // Find receiver and add energy
-        if energy_to_give > 0.0
-            && let Some(receiver) = state.organisms.iter_mut().find(|o| o.id == receiver_id)
-        {
-            receiver.gain_energy(energy_to_give, params.max_energy);
+    // Settle energy sharing, one receiver per task. Gifts are sized from the
+    // givers' energy before any of them is handed over, at most 50% of it.
+    let transfers: Vec<(usize, Vec<(usize, f32)>)> = gifts
+        .into_par_iter()
+        .map(|(receiver, gifts)| {
+            let organisms = &state.organisms;
+            // The dead neither give nor receive
+            if !organisms[receiver].is_alive() {
+                return (receiver, Vec::new());
+            }
+            let claims: Vec<Claim> = gifts
+                .iter()
+                .filter(|&&(giver, _)| organisms[giver].is_alive())
+                .map(|&(giver, amount)| Claim {
+                    claimant: giver,
+                    distance: boundary::distance(
+                        &organisms[giver].pos,
+                        &organisms[receiver].pos,
+                        params,
+                    ),
+                    demand: amount.min(organisms[giver].energy * 0.5),
+                })
+                .filter(|claim| claim.demand > 0.0)
+                .collect();
+            let room = params.max_energy - organisms[receiver].energy;
+            (
+                receiver,
+                accept_gifts(&claims, room, time, receiver, params),
+            )
+        })
+        .collect();
+
+    let mut effects: Vec<(usize, Effect)> = Vec::new();
+    for (receiver, accepted) in transfers {
+        let receiver_id = state.organisms[receiver].id;
+        for (giver, amount) in accepted {
+            let giver_id = state.organisms[giver].id;
+            effects.push((giver, Effect::Gave(amount)));
+            effects.push((receiver, Effect::Received(amount)));
             // Add to visualization with timestamp
             state
                 .energy_shares
@@ -284,25 +507,43 @@ pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueu
                 state.time,
                 format!(
                     "Organism {} shared {:.1} energy with organism {}",
-                    giver_id, energy_to_give, receiver_id
+                    giver_id, amount, receiver_id
                 ),
                 EventColor::Sharing,
             );
         }
     }
+    apply_effects(&mut state.organisms, effects, params);
 
     // Process asexual reproductions
+    let world_center = Array1::from_vec(vec![params.box_width / 2., params.box_height / 2.]);
     for (parent_id, parent_pos, energy_contribution) in asexual_reproductions {
-        if let Some(parent) = state.organisms.iter_mut().find(|o| o.id == parent_id)
-            && parent.energy >= energy_contribution + 0.5
+        // Enforce population cap
+        if state.organisms.len() >= params.max_organism {
+            break;
+        }
+
+        if let Some(parent) = index.get(&parent_id).map(|&i| &mut state.organisms[i])
+            && parent.can_reproduce()
+            && parent.energy
+                >= energy_contribution
+                    + params.reproduction_energy_cost
+                    + reproduction::ENERGY_RESERVE
         {
-            // Deduct energy from parent
-            parent.consume_energy(energy_contribution);
+            let pool_params = params.for_pool(parent.pool_id);
+            let params: &Params = &pool_params;
+
+            // Deduct contribution plus overhead from parent
+            parent.consume_energy(
+                energy_contribution + params.reproduction_energy_cost,
+                Flow::Reproduction,
+            );
+            parent.reset_reproduction_cooldown(params.reproduction_cooldown);
 
             // Create offspring using parent's brain with mutation
             let mut offspring = super::organism::Organism::new_random(
                 state.generation as usize,
-                &parent_pos,
+                &world_center,
                 params.signal_size,
                 params.memory_size,
                 params.num_vision_directions,
@@ -312,21 +553,30 @@ pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueu
                 parent.pool_id,
                 params,
             );
+            offspring.pos = offspring_position(&parent_pos, params);
 
             // Clone and mutate parent brain
             offspring.brain = parent.brain.clone();
-            offspring.brain.mutate(0.05); // Small mutation for asexual reproduction
+            offspring.brain.mutate(params.brain_mutation_rate);
 
             // Set offspring properties - offspring gets multiplied energy
             offspring.energy = energy_contribution * params.reproduction_energy_multiplier;
             offspring.birth_generation = state.generation;
             offspring.reproduction_method = 1; // asexual
             offspring.parent_avg_score = parent.score as f64;
+            offspring.reset_reproduction_cooldown(params.reproduction_cooldown);
             offspring.dna.clone_from(&parent.dna);
             super::dna::mutate(&mut offspring.dna, params.dna_mutation_rate);
+            offspring.mate_preference =
+                mate_choice::clone_preference(&parent.mate_preference, params);
+            morphology::inherit_single(&mut offspring, parent, params);
+            diet::inherit_single(&mut offspring, parent, params);
 
             state.generation += 1;
             let offspring_id = offspring.id;
+            state
+                .energy_ledger
+                .record_pool(offspring.pool_id, Flow::Born, offspring.energy);
             state.organisms.push(offspring);
 
             // Log asexual reproduction
@@ -341,107 +591,313 @@ pub fn apply_events(state: &mut Ecosystem, params: &Params, mut queue: EventQueu
         }
     }
 
-    // Process sexual reproductions - match organisms that both want to reproduce with each other
+    // Process sexual reproductions - only organisms whose intents point at each other consent.
+    // Visit ids in sorted order so matching does not depend on event order.
+    let mut intent_ids: Vec<usize> = sexual_reproduction_intents.keys().copied().collect();
+    intent_ids.sort_unstable();
     let mut sexual_reproductions: Vec<(usize, usize, f32, f32, Array1<f32>)> = Vec::new();
-    for (partner_id, intents) in &sexual_reproduction_intents {
-        // Check if the partner also wants to reproduce with any of these organisms
-        if let Some(partner_intents) = sexual_reproduction_intents.get(partner_id) {
-            for (organism_id, org_energy, org_pos) in intents {
-                // Check if partner wants to reproduce with this organism
-                if let Some((_, partner_energy, _)) =
-                    partner_intents.iter().find(|(id, _, _)| id == organism_id)
-                {
-                    // Both organisms want to reproduce with each other
-                    // Use the position of the first organism
-                    sexual_reproductions.push((
-                        *organism_id,
-                        *partner_id,
-                        *org_energy,
-                        *partner_energy,
-                        org_pos.clone(),
-                    ));
-                    // Add to visualization with timestamp
-                    state
-                        .reproduction_intents
-                        .push((*organism_id, *partner_id, state.time));
-                    break; // Only one match per organism
-                }
-            }
+    for organism_id in intent_ids {
+        let (partner_id, org_energy, org_pos) = &sexual_reproduction_intents[&organism_id];
+        // Each mutual pair is handled once, from its lower id
+        if organism_id > *partner_id {
+            continue;
+        }
+        if let Some((partner_target, partner_energy, _)) =
+            sexual_reproduction_intents.get(partner_id)
+            && *partner_target == organism_id
+        {
+            sexual_reproductions.push((
+                organism_id,
+                *partner_id,
+                *org_energy,
+                *partner_energy,
+                org_pos.clone(),
+            ));
+            // Add to visualization with timestamp
+            state
+                .reproduction_intents
+                .push((organism_id, *partner_id, state.time));
         }
     }
 
     // Execute sexual reproductions
     for (parent1_id, parent2_id, energy1, energy2, pos) in sexual_reproductions {
-        // Find both parents
-        let parent1_idx = state.organisms.iter().position(|o| o.id == parent1_id);
-        let parent2_idx = state.organisms.iter().position(|o| o.id == parent2_id);
+        // Enforce population cap
+        if state.organisms.len() >= params.max_organism {
+            break;
+        }
 
-        if let (Some(p1_idx), Some(p2_idx)) = (parent1_idx, parent2_idx) {
-            // Check both parents have enough energy
-            if state.organisms[p1_idx].energy >= energy1 + 0.5
-                && state.organisms[p2_idx].energy >= energy2 + 0.5
+        // Find both parents
+        if let (Some(&p1_idx), Some(&p2_idx)) = (index.get(&parent1_id), index.get(&parent2_id)) {
+            let cost = params.reproduction_energy_cost;
+            let reserve = reproduction::ENERGY_RESERVE;
+            // Check both parents are off cooldown and can afford contribution plus overhead
+            if !(state.organisms[p1_idx].can_reproduce()
+                && state.organisms[p2_idx].can_reproduce()
+                && state.organisms[p1_idx].energy >= energy1 + cost + reserve
+                && state.organisms[p2_idx].energy >= energy2 + cost + reserve)
             {
-                // Clone parents for genetic material
-                let parent1 = state.organisms[p1_idx].clone();
-                let parent2 = state.organisms[p2_idx].clone();
-
-                // Deduct energy from both parents
-                state.organisms[p1_idx].consume_energy(energy1);
-                state.organisms[p2_idx].consume_energy(energy2);
-
-                // Calculate weighted average ratio based on energy contribution
-                let total_energy = energy1 + energy2;
-                let weight1 = energy1 / total_energy;
-
-                // Create offspring
-                let mut offspring = super::organism::Organism::new_random(
-                    state.generation as usize,
-                    &pos,
-                    params.signal_size,
-                    params.memory_size,
-                    params.num_vision_directions,
-                    params.vision_radius,
-                    params.fov,
-                    params.layer_sizes.clone(),
-                    parent1.pool_id, // Inherit pool from first parent
-                    params,
-                );
+                continue;
+            }
 
-                // Perform weighted crossover based on energy contributions
-                offspring.brain = super::brain::Brain::crossover_weighted(
-                    &parent1.brain,
-                    &parent2.brain,
-                    weight1,
-                );
+            // Each parent must accept the other's display and DNA
+            if !(mate_choice::accepts(&state.organisms[p1_idx], &state.organisms[p2_idx], params)
+                && mate_choice::accepts(&state.organisms[p2_idx], &state.organisms[p1_idx], params))
+            {
+                continue;
+            }
 
-                // Set offspring properties - offspring gets multiplied energy
-                offspring.energy = total_energy * params.reproduction_energy_multiplier;
-                offspring.birth_generation = state.generation;
-                offspring.reproduction_method = if parent1.pool_id == parent2.pool_id {
-                    2 // same-pool sexual
-                } else {
-                    3 // inter-pool sexual
-                };
-                offspring.parent_avg_score = (parent1.score + parent2.score) as f64 / 2.0;
-
-                // DNA crossover
-                offspring.dna = super::dna::crossover(&parent1.dna, &parent2.dna, weight1);
-                super::dna::mutate(&mut offspring.dna, params.dna_mutation_rate);
-
-                state.generation += 1;
-                let offspring_id = offspring.id;
-                state.organisms.push(offspring);
-
-                // Log sexual reproduction
+            // Brains with different architectures can't be crossed
+            if let Err(mismatch) = state.organisms[p1_idx]
+                .brain
+                .check_architecture(&state.organisms[p2_idx].brain)
+            {
                 state.event_log.log(
                     state.time,
                     format!(
-                        "Organisms {} and {} reproduced sexually (offspring: {})",
-                        parent1_id, parent2_id, offspring_id
+                        "Organisms {} and {} could not reproduce: {}",
+                        parent1_id, parent2_id, mismatch
                     ),
                     EventColor::Reproduction,
                 );
+                continue;
+            }
+
+            // Clone parents for genetic material
+            let parent1 = state.organisms[p1_idx].clone();
+            let parent2 = state.organisms[p2_idx].clone();
+            let pool_params = params.for_pool(parent1.pool_id);
+            let params: &Params = &pool_params;
+
+            // Deduct energy from both parents and start their cooldowns
+            for (idx, energy) in [(p1_idx, energy1), (p2_idx, energy2)] {
+                state.organisms[idx].consume_energy(energy + cost, Flow::Reproduction);
+                state.organisms[idx].reset_reproduction_cooldown(params.reproduction_cooldown);
             }
+
+            // Calculate weighted average ratio based on energy contribution
+            let total_energy = energy1 + energy2;
+            let weight1 = energy1 / total_energy;
+
+            // Create offspring
+            let mut offspring = super::organism::Organism::new_random(
+                state.generation as usize,
+                &world_center,
+                params.signal_size,
+                params.memory_size,
+                params.num_vision_directions,
+                params.vision_radius,
+                params.fov,
+                params.layer_sizes.clone(),
+                parent1.pool_id, // Inherit pool from first parent
+                params,
+            );
+            offspring.pos = offspring_position(&pos, params);
+
+            // Perform weighted crossover based on energy contributions
+            offspring.brain =
+                super::brain::Brain::crossover_weighted(&parent1.brain, &parent2.brain, weight1)
+                    .expect("architectures were checked");
+
+            // Set offspring properties - offspring gets multiplied energy
+            offspring.energy = total_energy * params.reproduction_energy_multiplier;
+            offspring.birth_generation = state.generation;
+            offspring.reproduction_method = if parent1.pool_id == parent2.pool_id {
+                2 // same-pool sexual
+            } else {
+                3 // inter-pool sexual
+            };
+            offspring.parent_avg_score = (parent1.score + parent2.score) as f64 / 2.0;
+            offspring.reset_reproduction_cooldown(params.reproduction_cooldown);
+
+            // DNA crossover
+            offspring.dna = super::dna::crossover(&parent1.dna, &parent2.dna, weight1);
+            super::dna::mutate(&mut offspring.dna, params.dna_mutation_rate);
+            offspring.mate_preference = mate_choice::inherit_preference(
+                &parent1.mate_preference,
+                &parent2.mate_preference,
+                weight1,
+                params,
+            );
+            morphology::inherit(&mut offspring, &parent1, &parent2, weight1, params);
+            diet::inherit(&mut offspring, &parent1, &parent2, weight1, params);
+
+            state.generation += 1;
+            let offspring_id = offspring.id;
+            state
+                .energy_ledger
+                .record_pool(offspring.pool_id, Flow::Born, offspring.energy);
+            state.organisms.push(offspring);
+
+            // Log sexual reproduction
+            state.event_log.log(
+                state.time,
+                format!(
+                    "Organisms {} and {} reproduced sexually (offspring: {})",
+                    parent1_id, parent2_id, offspring_id
+                ),
+                EventColor::Reproduction,
+            );
+        }
+    }
+}
+
+/// Lands the blows struck at `victim` (at `index`) in the order the conflict
+/// policy serves them, sharing out its energy between the biters.
+///
+/// Blows landing after a fatal one are lost, and the kill goes to whoever
+/// struck the fatal one.
+fn strike(
+    victim: &mut Organism,
+    index: usize,
+    blows: &mut [Blow],
+    time: f32,
+    params: &Params,
+) -> Struck {
+    // Put identical queues of blows in the same order however they arrived
+    blows.sort_by(|a, b| {
+        a.attacker_id
+            .cmp(&b.attacker_id)
+            .then_with(|| a.source.name().cmp(b.source.name()))
+            .then_with(|| a.damage.total_cmp(&b.damage))
+            .then_with(|| a.steal.total_cmp(&b.steal))
+            .then_with(|| {
+                a.from
+                    .iter()
+                    .zip(&b.from)
+                    .fold(Ordering::Equal, |order, (x, y)| {
+                        order.then_with(|| x.total_cmp(y))
+                    })
+            })
+    });
+    let claims: Vec<Claim> = blows
+        .iter()
+        .map(|blow| Claim {
+            claimant: blow.attacker_id,
+            distance: blow.distance,
+            demand: blow.steal,
+        })
+        .collect();
+    let resource = Resource::Target(index);
+    let stealable = params
+        .conflicts
+        .allocate(victim.energy, &claims, time, resource);
+
+    let mut struck = Struck {
+        stolen: Vec::new(),
+        killed: None,
+    };
+    for i in params.conflicts.order(&claims, time, resource) {
+        if !victim.is_alive() {
+            break;
+        }
+        let blow = &blows[i];
+        let stolen = stealable[i].min(victim.energy.max(0.0));
+        if let Some(attacker) = blow.attacker
+            && stolen > 0.0
+        {
+            victim.consume_energy(stolen, Flow::Stolen);
+            struck.stolen.push((attacker, stolen));
+        }
+        let dealt = combat::deal_damage(victim, blow.damage, params);
+        victim.touch.record_damage(dealt, &blow.from);
+        if !victim.is_alive() {
+            struck.killed = Some((
+                victim.pos.clone(),
+                KillRecord {
+                    time,
+                    victim_id: victim.id,
+                    killer_id: blow.attacker_id,
+                    source: blow.source,
+                    damage: dealt,
+                },
+            ));
+        }
+    }
+    struck
+}
+
+/// Decides which gifts to `receiver` are handed over, and how much of each.
+///
+/// A lone gift is always accepted in full. When several organisms share with
+/// the same receiver its room below the maximum energy is contested: under
+/// `EqualSplit` the room is divided between the givers, otherwise givers hand
+/// over their whole gift in turn until the receiver is full, and the rest keep
+/// their energy. Returns `(giver index, amount)` pairs in the order served.
+fn accept_gifts(
+    claims: &[Claim],
+    room: f32,
+    time: f32,
+    receiver: usize,
+    params: &Params,
+) -> Vec<(usize, f32)> {
+    let resource = Resource::Receiver(receiver);
+    if claims.len() < 2 {
+        return claims.iter().map(|c| (c.claimant, c.demand)).collect();
+    }
+    if params.conflicts.policy == ConflictPolicy::EqualSplit {
+        let shares = params.conflicts.allocate(room, claims, time, resource);
+        return params
+            .conflicts
+            .order(claims, time, resource)
+            .into_iter()
+            .filter(|&i| shares[i] > 0.0)
+            .map(|i| (claims[i].claimant, shares[i]))
+            .collect();
+    }
+    let mut room = room;
+    let mut accepted = Vec::new();
+    for i in params.conflicts.order(claims, time, resource) {
+        if room <= 0.0 {
+            break;
         }
+        accepted.push((claims[i].claimant, claims[i].demand));
+        room -= claims[i].demand;
+    }
+    accepted
+}
+
+/// Applies each organism's effects, one organism per task.
+///
+/// Effects on the same organism are applied in the order given.
+fn apply_effects(organisms: &mut [Organism], effects: Vec<(usize, Effect)>, params: &Params) {
+    if effects.is_empty() {
+        return;
     }
+    let mut by_organism: Vec<Vec<Effect>> = vec![Vec::new(); organisms.len()];
+    for (i, effect) in effects {
+        by_organism[i].push(effect);
+    }
+    organisms
+        .par_iter_mut()
+        .zip(by_organism)
+        .for_each(|(organism, effects)| {
+            for effect in effects {
+                match effect {
+                    Effect::Stole(amount) => {
+                        organism.gain_energy(amount, params.max_energy, Flow::Stolen);
+                    }
+                    Effect::Ate(digested) => {
+                        organism.gain_energy(digested, params.max_energy, Flow::Eaten);
+                        organism.score += 1;
+                        organism.touch.ate_food = true;
+                    }
+                    Effect::MissedFood => organism.touch.lost_food = true,
+                    Effect::Gave(amount) => organism.consume_energy(amount, Flow::Shared),
+                    Effect::Received(amount) => {
+                        organism.gain_energy(amount, params.max_energy, Flow::Shared);
+                        organism.touch.energy_received += amount;
+                    }
+                }
+            }
+        });
+}
+
+/// Places an offspring next to its parent, just outside collision range.
+fn offspring_position(parent_pos: &Array1<f32>, params: &Params) -> Array1<f32> {
+    let angle = rand::random::<f32>() * std::f32::consts::TAU;
+    let distance = params.body_radius * 3.0;
+    let mut pos = parent_pos + &Array1::from_vec(vec![angle.cos(), angle.sin()]) * distance;
+    boundary::confine(&mut pos, params);
+    pos
 }
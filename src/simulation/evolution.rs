@@ -5,21 +5,48 @@
 
 use ndarray::Array1;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-use super::brain::Brain;
+use super::brain::{ArchitectureMismatch, Brain};
+use super::diet;
 use super::dna;
+use super::mate_choice;
+use super::morphology;
+use super::novelty::{NoveltyArchive, SelectionMode};
 use super::organism::Organism;
 use super::params::Params;
 use super::reproduction::ReproductionStats;
 
+/// Graveyard breeding refused because the parents' brains could not be crossed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreedingRefusal {
+    /// Id of the first parent.
+    pub parent1_id: usize,
+    /// Id of the mate it chose.
+    pub parent2_id: usize,
+    /// Why the brains could not be crossed.
+    pub reason: ArchitectureMismatch,
+}
+
 /// Manages the graveyard and organism spawning/evolution.
-#[derive(Debug, Clone)]
+///
+/// Only the novelty archive is persisted; the graveyard is rebuilt from
+/// deaths after a load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvolutionEngine {
     /// Graveyard of deceased organisms for breeding selection.
-    /// Maintained sorted by fitness (highest first).
+    /// Maintained sorted by selection score (highest first).
+    #[serde(skip)]
     graveyard: Vec<Organism>,
+    /// Behaviour vector of each graveyard organism, in graveyard order,
+    /// computed once when it is archived.
+    #[serde(skip)]
+    graveyard_behaviours: Vec<Vec<f32>>,
     /// Maximum size of the graveyard.
     max_graveyard_size: usize,
+    /// Archive of past behaviours used to score novelty.
+    #[serde(default)]
+    novelty_archive: NoveltyArchive,
 }
 
 impl EvolutionEngine {
@@ -27,26 +54,57 @@ impl EvolutionEngine {
     pub fn new(max_graveyard_size: usize) -> Self {
         Self {
             graveyard: Vec::with_capacity(max_graveyard_size),
+            graveyard_behaviours: Vec::with_capacity(max_graveyard_size),
             max_graveyard_size,
+            novelty_archive: NoveltyArchive::default(),
         }
     }
 
     /// Records an organism's death and adds it to the graveyard.
     ///
-    /// Only organisms that lived long enough (age >= 0.5) are added.
-    /// Maintains graveyard sorted by fitness.
-    pub fn record_death(&mut self, organism: &Organism, stats: &mut ReproductionStats) {
+    /// The organism's novelty is scored against the archive and the current
+    /// graveyard before it is stored. Only organisms that lived long enough
+    /// (age >= 0.5) are added. Maintains graveyard sorted by selection score.
+    pub fn record_death(
+        &mut self,
+        organism: &Organism,
+        stats: &mut ReproductionStats,
+        params: &Params,
+    ) {
         stats.record_death(organism);
 
         // Only add organisms that lived long enough
         if organism.age >= 0.5 {
-            self.graveyard.push(organism.clone());
+            let behaviour = organism
+                .behaviour
+                .to_vector(params.novelty.trajectory_samples);
+
+            let mut dead = organism.clone();
+            dead.novelty = self.novelty_archive.novelty(
+                &behaviour,
+                self.graveyard_behaviours.iter().map(Vec::as_slice),
+                params.novelty.k_nearest,
+            );
 
-            // Maintain graveyard size by keeping only the fittest
+            if rand::rng().random::<f32>() < params.novelty.archive_add_prob {
+                self.novelty_archive
+                    .add(behaviour.clone(), params.novelty.archive_size);
+            }
+
+            self.graveyard.push(dead);
+            self.graveyard_behaviours.push(behaviour);
+
+            // Maintain graveyard size by keeping only the best candidates
             if self.graveyard.len() > self.max_graveyard_size {
-                self.graveyard
-                    .sort_by(|a, b| b.fitness().partial_cmp(&a.fitness()).unwrap());
-                self.graveyard.truncate(self.max_graveyard_size);
+                let score = selection_score(&self.graveyard, params);
+                let mut entries: Vec<(Organism, Vec<f32>)> = self
+                    .graveyard
+                    .drain(..)
+                    .zip(self.graveyard_behaviours.drain(..))
+                    .collect();
+                entries.sort_by(|(a, _), (b, _)| score(b).total_cmp(&score(a)));
+                entries.truncate(self.max_graveyard_size);
+                (self.graveyard, self.graveyard_behaviours) = entries.into_iter().unzip();
             }
         }
     }
@@ -62,17 +120,22 @@ impl EvolutionEngine {
     ///
     /// # Returns
     ///
-    /// A new organism with genetics from the graveyard.
+    /// A new organism with genetics from the graveyard, and the refused
+    /// breeding if the chosen parents' brains could not be crossed (the
+    /// organism then keeps its random genome).
     pub fn spawn_organism(
         &self,
         generation: u32,
         target_pool_id: usize,
         center: &Array1<f32>,
         params: &Params,
-    ) -> Organism {
-        // Sort graveyard by fitness
+    ) -> (Organism, Option<BreedingRefusal>) {
+        let pool_params = params.for_pool(target_pool_id);
+        let params: &Params = &pool_params;
+
+        // Sort graveyard by selection score (fitness, novelty or a blend)
         let mut sorted_graveyard = self.graveyard.clone();
-        sorted_graveyard.sort_by(|a, b| b.fitness().partial_cmp(&a.fitness()).unwrap());
+        sort_by_selection_score(&mut sorted_graveyard, params);
 
         // Create base organism
         let mut new_organism = Organism::new_random(
@@ -91,7 +154,7 @@ impl EvolutionEngine {
         new_organism.birth_generation = generation;
 
         // Logarithmic random sampling for mutation scale
-        let mutation_scale = sample_mutation_scale();
+        let mutation_scale = sample_mutation_scale(params.brain_mutation_rate);
 
         // Get organisms in the target pool
         let pool_organisms: Vec<usize> = sorted_graveyard
@@ -102,6 +165,7 @@ impl EvolutionEngine {
             .collect();
 
         // Apply evolution strategy based on pool population
+        let mut refusal = None;
         if pool_organisms.is_empty() && !sorted_graveyard.is_empty() {
             // Seed from other pools
             Self::seed_from_other_pool(
@@ -116,7 +180,7 @@ impl EvolutionEngine {
 
             if reproduction_strategy == 0 {
                 // Sexual reproduction (crossover)
-                Self::sexual_reproduction(
+                refusal = Self::sexual_reproduction(
                     &mut new_organism,
                     &pool_organisms,
                     &sorted_graveyard,
@@ -143,7 +207,7 @@ impl EvolutionEngine {
             );
         }
 
-        new_organism
+        (new_organism, refusal)
     }
 
     /// Seeds a new organism from a different genetic pool.
@@ -156,21 +220,29 @@ impl EvolutionEngine {
         let seed_idx = rand::rng().random_range(0..graveyard.len());
         let seed = &graveyard[seed_idx];
 
-        let mut cloned_brain = seed.brain.clone();
-        cloned_brain.mutate(mutation_scale * 2.0); // Extra mutation for diversity
-        new_organism.brain = cloned_brain;
+        // A pool with a different kind of brain keeps its fresh random one
+        if seed.brain.same_architecture(&new_organism.brain) {
+            let mut cloned_brain = seed.brain.clone();
+            cloned_brain.mutate(mutation_scale * 2.0); // Extra mutation for diversity
+            new_organism.brain = cloned_brain;
+        }
         new_organism.dna.clone_from(&seed.dna);
         dna::mutate(&mut new_organism.dna, params.dna_mutation_rate * 2.0);
+        new_organism.mate_preference = mate_choice::clone_preference(&seed.mate_preference, params);
+        morphology::inherit_single(new_organism, seed, params);
+        diet::inherit_single(new_organism, seed, params);
     }
 
     /// Performs sexual reproduction (crossover between two parents).
+    ///
+    /// Returns the refusal if the chosen parents' brains can't be crossed.
     fn sexual_reproduction(
         new_organism: &mut Organism,
         pool_organisms: &[usize],
         graveyard: &[Organism],
         mutation_scale: f32,
         params: &Params,
-    ) {
+    ) -> Option<BreedingRefusal> {
         // Decide if we allow inter-pool breeding
         let allow_interbreeding = rand::rng().random::<f32>() < params.pool_interbreed_prob;
 
@@ -187,18 +259,29 @@ impl EvolutionEngine {
             let top_count = (candidates.len() as f32 * 0.15).max(2.0) as usize;
             let top_count = top_count.min(candidates.len());
 
-            // Pick two different parents from top 15%
+            // Pick two different parents from top 15%; the second must suit the first's taste
             let parent_1_idx = rand::rng().random_range(0..top_count);
-            let mut parent_2_idx = rand::rng().random_range(0..top_count);
-
-            // Ensure parents are different
-            while parent_2_idx == parent_1_idx && top_count > 1 {
-                parent_2_idx = rand::rng().random_range(0..top_count);
-            }
-
             let parent_1 = &graveyard[candidates[parent_1_idx]];
+            let parent_2_idx = Self::choose_mate(
+                parent_1,
+                parent_1_idx,
+                &candidates[..top_count],
+                graveyard,
+                params,
+            );
             let parent_2 = &graveyard[candidates[parent_2_idx]];
 
+            let crossover_brain = match Brain::crossover(&parent_1.brain, &parent_2.brain) {
+                Ok(brain) => brain,
+                Err(reason) => {
+                    return Some(BreedingRefusal {
+                        parent1_id: parent_1.id,
+                        parent2_id: parent_2.id,
+                        reason,
+                    });
+                }
+            };
+
             // Track parent scores
             let avg_parent_score = (parent_1.score + parent_2.score) as f64 / 2.0;
             new_organism.parent_avg_score = avg_parent_score;
@@ -210,20 +293,65 @@ impl EvolutionEngine {
                 new_organism.reproduction_method = 2; // same-pool sexual
             }
 
-            // Perform crossover
-            let crossover_brain = Brain::crossover(&parent_1.brain, &parent_2.brain);
-            new_organism.brain = crossover_brain;
+            // Use the crossover (parents from a pool with another kind of
+            // brain leave the fresh random one in place)
+            if crossover_brain.same_architecture(&new_organism.brain) {
+                new_organism.brain = crossover_brain;
+            }
 
             // Inherit DNA from parents with crossover and mutation
             let alpha = rand::rng().random::<f32>();
             new_organism.dna = dna::crossover(&parent_1.dna, &parent_2.dna, alpha);
             dna::mutate(&mut new_organism.dna, params.dna_mutation_rate);
+            new_organism.mate_preference = mate_choice::inherit_preference(
+                &parent_1.mate_preference,
+                &parent_2.mate_preference,
+                alpha,
+                params,
+            );
+            morphology::inherit(new_organism, parent_1, parent_2, alpha, params);
+            diet::inherit(new_organism, parent_1, parent_2, alpha, params);
 
             // Extra mutation for inter-pool breeding
             if !is_same_pool && parent_1.pool_id != parent_2.pool_id {
                 new_organism.brain.mutate(mutation_scale * 0.5);
             }
         }
+        None
+    }
+
+    /// Chooses a mate for `parent` among `candidates` (indices into `graveyard`).
+    ///
+    /// Random candidates other than the parent itself are proposed up to
+    /// `max_proposals` times and accepted according to the parent's mate
+    /// preference. If every proposal is rejected, the most acceptable one is
+    /// used so breeding never stalls.
+    ///
+    /// Returns the position of the chosen mate in `candidates`.
+    fn choose_mate(
+        parent: &Organism,
+        parent_idx: usize,
+        candidates: &[usize],
+        graveyard: &[Organism],
+        params: &Params,
+    ) -> usize {
+        let mut best = (parent_idx, f32::NEG_INFINITY);
+        for _ in 0..params.mate_choice.max_proposals.max(1) {
+            let mut idx = rand::rng().random_range(0..candidates.len());
+            while idx == parent_idx && candidates.len() > 1 {
+                idx = rand::rng().random_range(0..candidates.len());
+            }
+
+            let candidate = &graveyard[candidates[idx]];
+            let probability = mate_choice::acceptance_probability(parent, candidate, params);
+            if rand::rng().random::<f32>() < probability {
+                return idx;
+            }
+            if probability > best.1 {
+                best = (idx, probability);
+            }
+        }
+        best.0
     }
 
     /// Performs asexual reproduction (cloning with mutation).
@@ -250,6 +378,10 @@ impl EvolutionEngine {
             let mutation = rand::rng().random_range(-1.0..1.0) * params.dna_mutation_rate;
             new_organism.dna[i] = (new_organism.dna[i] + mutation).clamp(0.0, 1.0);
         }
+        new_organism.mate_preference =
+            mate_choice::clone_preference(&parent.mate_preference, params);
+        morphology::inherit_single(new_organism, parent, params);
+        diet::inherit_single(new_organism, parent, params);
     }
 
     /// Clones a single parent organism.
@@ -267,18 +399,63 @@ impl EvolutionEngine {
         new_organism.brain = cloned_brain;
         new_organism.dna.clone_from(&parent.dna);
         dna::mutate(&mut new_organism.dna, params.dna_mutation_rate);
+        new_organism.mate_preference =
+            mate_choice::clone_preference(&parent.mate_preference, params);
+        morphology::inherit_single(new_organism, parent, params);
+        diet::inherit_single(new_organism, parent, params);
     }
 
     /// Returns a reference to the graveyard.
     pub fn graveyard(&self) -> &[Organism] {
         &self.graveyard
     }
+
+    /// Returns a reference to the novelty archive.
+    pub fn novelty_archive(&self) -> &NoveltyArchive {
+        &self.novelty_archive
+    }
+}
+
+/// Sorts organisms by selection score, highest first.
+///
+/// In blend mode fitness and novelty are each normalized by their maximum
+/// over `organisms` so the weight is independent of their raw scales.
+pub fn sort_by_selection_score(organisms: &mut [Organism], params: &Params) {
+    let score = selection_score(organisms, params);
+    organisms.sort_by(|a, b| score(b).total_cmp(&score(a)));
+}
+
+/// Selection score of an organism relative to `organisms`, as ranked by
+/// [`sort_by_selection_score`].
+fn selection_score(organisms: &[Organism], params: &Params) -> impl Fn(&Organism) -> f64 + use<> {
+    let max_fitness = organisms
+        .iter()
+        .map(Organism::fitness)
+        .fold(f64::EPSILON, f64::max);
+    let max_novelty = organisms
+        .iter()
+        .map(|org| org.novelty as f64)
+        .fold(f64::EPSILON, f64::max);
+    let weight = params.novelty.novelty_weight as f64;
+    let selection_mode = params.novelty.selection_mode;
+
+    move |org: &Organism| match selection_mode {
+        SelectionMode::Fitness => org.fitness(),
+        SelectionMode::Novelty => org.novelty as f64,
+        SelectionMode::Blend => {
+            (1.0 - weight) * org.fitness() / max_fitness + weight * org.novelty as f64 / max_novelty
+        }
+    }
 }
 
-/// Samples a mutation scale using logarithmic random distribution.
-fn sample_mutation_scale() -> f32 {
-    let min = 0.0002f32;
-    let max = 0.2f32;
+/// Samples a mutation scale between `rate / 500` and `2 * rate` using
+/// logarithmic random distribution.
+fn sample_mutation_scale(rate: f32) -> f32 {
+    if rate <= 0.0 {
+        return 0.0;
+    }
+    let min = rate * 0.002;
+    let max = rate * 2.0;
     let log_min = min.ln();
     let log_max = max.ln();
     let log_mutation_scale = rand::rng().random_range(log_min..log_max);
@@ -1,14 +1,123 @@
 //! Food items that organisms can consume for energy.
 //!
-//! Food can be either randomly spawned or created from organism corpses.
+//! Food can be either randomly spawned or created from organism corpses. Each
+//! item has a [`FoodKind`] whose energy, lifetime and appearance come from the
+//! matching [`FoodKindSpec`] in `Params::food_kinds`.
 
 use ndarray::Array1;
 use ndarray_rand::RandomExt;
 use ndarray_rand::rand_distr::Uniform;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use super::locatable::Locatable;
 
+/// The kind of a food item.
+///
+/// Kinds index into `Params::food_kinds`: plants are entry 0, carrion entry 1
+/// and user-defined kinds follow in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FoodKind {
+    /// Spontaneously growing plant matter.
+    #[default]
+    Plant,
+    /// Corpses of organisms killed in combat.
+    Carrion,
+    /// A user-defined kind (0 = first entry after carrion).
+    Custom(usize),
+}
+
+impl FoodKind {
+    /// Position of this kind in `Params::food_kinds`.
+    pub fn index(self) -> usize {
+        match self {
+            Self::Plant => 0,
+            Self::Carrion => 1,
+            Self::Custom(i) => 2 + i,
+        }
+    }
+
+    /// Kind stored at position `index` of `Params::food_kinds`.
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            0 => Self::Plant,
+            1 => Self::Carrion,
+            i => Self::Custom(i - 2),
+        }
+    }
+
+    /// Looks up the spec for this kind, if the table defines it.
+    pub fn spec(self, kinds: &[FoodKindSpec]) -> Option<&FoodKindSpec> {
+        kinds.get(self.index())
+    }
+}
+
+/// Properties shared by every food item of one kind.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FoodKindSpec {
+    /// Display name.
+    pub name: String,
+    /// Energy of a fresh item.
+    pub energy: f32,
+    /// Seconds before an item rots away (0 = unlimited).
+    pub lifetime: f32,
+    /// Relative share of spontaneous food spawns (0 = never spawns on its own).
+    pub spawn_weight: f32,
+    /// Rendering colour (RGB).
+    pub color: [u8; 3],
+    /// Value shown in the vision "type" channel (organisms are 1.0, projectiles -1.0).
+    pub vision_code: f32,
+    /// Scent channel this kind adds to (clamped to the signal size).
+    pub scent_channel: usize,
+}
+
+/// Default food table: plants and carrion.
+pub fn default_food_kinds() -> Vec<FoodKindSpec> {
+    vec![
+        FoodKindSpec {
+            name: "Plant".to_string(),
+            energy: 1.0,
+            lifetime: 0.0,
+            spawn_weight: 1.0,
+            color: [0, 200, 100],
+            vision_code: 0.0,
+            scent_channel: 2,
+        },
+        FoodKindSpec {
+            name: "Carrion".to_string(),
+            energy: 0.8,
+            lifetime: 0.0,
+            spawn_weight: 0.0,
+            color: [160, 70, 50],
+            vision_code: 0.5,
+            scent_channel: 0,
+        },
+    ]
+}
+
+/// Picks the kind of a spontaneously spawned food item, weighted by `spawn_weight`.
+///
+/// Returns `None` if no kind spawns on its own.
+pub fn sample_spawn_kind(kinds: &[FoodKindSpec]) -> Option<FoodKind> {
+    let total: f32 = kinds.iter().map(|k| k.spawn_weight.max(0.0)).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut target = rand::rng().random::<f32>() * total;
+    for (index, kind) in kinds.iter().enumerate() {
+        let weight = kind.spawn_weight.max(0.0);
+        if weight > 0.0 && target < weight {
+            return Some(FoodKind::from_index(index));
+        }
+        target -= weight;
+    }
+    // Rounding fallback: last kind that can spawn
+    kinds
+        .iter()
+        .rposition(|k| k.spawn_weight > 0.0)
+        .map(FoodKind::from_index)
+}
+
 /// A food item that organisms can consume for energy.
 ///
 /// Food items have a position and energy value. When an organism consumes food,
@@ -17,10 +126,13 @@ use super::locatable::Locatable;
 pub struct Food {
     /// Position in 2D space.
     pub pos: Array1<f32>,
-    /// Energy value remaining. Regular food has 1.0, corpses have less based on `corpse_energy_ratio`.
+    /// Energy value remaining, initially the energy of its kind.
     pub energy: f32,
     /// Age of the food item in seconds.
     pub age: f32,
+    /// What kind of food this is.
+    #[serde(default)]
+    pub kind: FoodKind,
 }
 
 impl Food {
@@ -29,19 +141,30 @@ impl Food {
     /// # Arguments
     ///
     /// * `screen_center` - The center point of the simulation area, used to calculate bounds.
+    /// * `kind` - The kind of food.
     /// * `energy` - The energy value for this food item.
     ///
     /// # Returns
     ///
-    /// A new `Food` instance with random position and specified energy.
-    pub fn new_random(screen_center: &Array1<f32>, energy: f32) -> Self {
+    /// A new `Food` instance with random position and specified kind and energy.
+    pub fn new_random(screen_center: &Array1<f32>, kind: FoodKind, energy: f32) -> Self {
         Self {
             pos: Array1::random(2, Uniform::new(0., 1.)) * screen_center * 2.0,
             energy,
             age: 0.0,
+            kind,
         }
     }
 
+    /// Checks if this food item has outlived its kind's lifetime.
+    ///
+    /// Kinds with a lifetime of 0 (or missing from `kinds`) never expire.
+    pub fn is_expired(&self, kinds: &[FoodKindSpec]) -> bool {
+        self.kind
+            .spec(kinds)
+            .is_some_and(|spec| spec.lifetime > 0.0 && self.age >= spec.lifetime)
+    }
+
     /// Checks if this food item has been fully consumed.
     ///
     /// # Returns
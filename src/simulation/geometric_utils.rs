@@ -28,6 +28,40 @@ pub fn line_circle_distance(
     Euclidean.distance(&p, &line)
 }
 
+/// Calculates where a line segment first enters a circle.
+///
+/// # Arguments
+///
+/// * `line_start` - Starting point of the line segment
+/// * `line_end` - Ending point of the line segment
+/// * `circle_center` - Center point of the circle
+/// * `radius` - Radius of the circle
+///
+/// # Returns
+///
+/// Distance along the segment from `line_start` to the first point inside the
+/// circle (0 if it starts inside), or `None` if the segment misses the circle.
+pub fn segment_circle_entry(
+    line_start: &Array1<f32>,
+    line_end: &Array1<f32>,
+    circle_center: &Array1<f32>,
+    radius: f32,
+) -> Option<f32> {
+    if line_circle_distance(line_start, line_end, circle_center) >= radius {
+        return None;
+    }
+    let direction = line_end - line_start;
+    let length = direction.mapv(|x| x.powi(2)).sum().sqrt();
+    if length <= f32::EPSILON {
+        return Some(0.0);
+    }
+    let to_center = circle_center - line_start;
+    let along = to_center.dot(&direction) / length;
+    let off_axis_sq = to_center.mapv(|x| x.powi(2)).sum() - along * along;
+    let half_chord = (radius * radius - off_axis_sq).max(0.0).sqrt();
+    Some((along - half_chord).clamp(0.0, length))
+}
+
 /// Wraps a position vector around the simulation box boundaries (toroidal topology).
 ///
 /// # Arguments
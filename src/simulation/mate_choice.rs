@@ -0,0 +1,216 @@
+//! Mate choice driven by evolvable preferences.
+//!
+//! Each organism carries a mate-preference vector: a preferred signal
+//! (matching the displayed `signal` channels) followed by a preferred DNA
+//! point. A candidate partner is accepted with probability
+//! `exp(-selectivity * mismatch²)`, so preferences and the displays they
+//! favour can co-evolve and drive sexual selection.
+
+use ndarray::{Array1, s};
+use ndarray_rand::RandomExt;
+use ndarray_rand::rand_distr::Uniform;
+use serde::{Deserialize, Serialize};
+
+use super::dna;
+use super::organism::Organism;
+use super::params::Params;
+
+/// Number of DNA dimensions at the end of a preference vector.
+const DNA_DIMS: usize = 2;
+
+/// Largest periodic distance in DNA space, used to normalize DNA mismatch.
+const MAX_DNA_DISTANCE: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// Mate choice configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MateChoiceParams {
+    /// If false, every partner that passes the pool and DNA checks is accepted.
+    pub enabled: bool,
+    /// How sharply acceptance falls off with mismatch (0.0 = indiscriminate).
+    pub selectivity: f32,
+    /// Weight of DNA mismatch relative to signal mismatch (0.0 = signal only, 1.0 = DNA only).
+    pub dna_weight: f32,
+    /// Maximum per-component change applied to an inherited preference.
+    pub preference_mutation_rate: f32,
+    /// Number of candidates a graveyard parent may reject before settling for
+    /// the most acceptable one it has seen.
+    pub max_proposals: usize,
+}
+
+impl Default for MateChoiceParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            selectivity: 4.0,
+            dna_weight: 0.5,
+            preference_mutation_rate: 0.05,
+            max_proposals: 10,
+        }
+    }
+}
+
+/// Creates a random preference vector for `signal_size` signal channels.
+///
+/// Signal preferences are drawn from the brain output range [-1, 1], DNA
+/// preferences from [0, 1].
+pub fn random_preference(signal_size: usize) -> Array1<f32> {
+    let mut preference = Array1::random(signal_size + DNA_DIMS, Uniform::new(-1.0, 1.0));
+    preference
+        .slice_mut(s![signal_size..])
+        .assign(&Array1::random(DNA_DIMS, Uniform::new(0.0, 1.0)));
+    preference
+}
+
+/// Normalized mismatch between a preference and a candidate's display and DNA.
+///
+/// Returns a value in [0, 1], or `None` if the preference does not fit the
+/// candidate (e.g. a preference loaded from an older save).
+pub fn mismatch(
+    preference: &Array1<f32>,
+    signal: &Array1<f32>,
+    dna: &Array1<f32>,
+    params: &MateChoiceParams,
+) -> Option<f32> {
+    let signal_size = signal.len();
+    if preference.len() != signal_size + DNA_DIMS || dna.len() != DNA_DIMS {
+        return None;
+    }
+
+    // Signal channels span [-1, 1], so the largest possible distance is 2 * sqrt(n)
+    let signal_mismatch = if signal_size == 0 {
+        0.0
+    } else {
+        let preferred = preference.slice(s![..signal_size]);
+        let sq: f32 = preferred
+            .iter()
+            .zip(signal.iter())
+            .map(|(p, x)| (p - x.clamp(-1.0, 1.0)).powi(2))
+            .sum();
+        sq.sqrt() / (2.0 * (signal_size as f32).sqrt())
+    };
+
+    let preferred_dna = preference.slice(s![signal_size..]).to_owned();
+    let dna_mismatch = dna::periodic_distance(&preferred_dna, dna) / MAX_DNA_DISTANCE;
+
+    let weight = params.dna_weight.clamp(0.0, 1.0);
+    Some(
+        ((1.0 - weight) * signal_mismatch.powi(2) + weight * dna_mismatch.powi(2))
+            .sqrt()
+            .min(1.0),
+    )
+}
+
+/// Probability that `chooser` accepts `candidate` as a mate.
+///
+/// Always 1.0 when mate choice is disabled or the chooser has no usable preference.
+pub fn acceptance_probability(chooser: &Organism, candidate: &Organism, params: &Params) -> f32 {
+    if !params.mate_choice.enabled {
+        return 1.0;
+    }
+    mismatch(
+        &chooser.mate_preference,
+        &candidate.signal,
+        &candidate.dna,
+        &params.mate_choice,
+    )
+    .map_or(1.0, |m| (-params.mate_choice.selectivity * m * m).exp())
+}
+
+/// Rolls whether `chooser` accepts `candidate` as a mate.
+pub fn accepts(chooser: &Organism, candidate: &Organism, params: &Params) -> bool {
+    rand::random::<f32>() < acceptance_probability(chooser, candidate, params)
+}
+
+/// Mutates a preference vector in place.
+///
+/// Signal preferences are clamped to [-1, 1]; DNA preferences wrap around
+/// like DNA itself.
+pub fn mutate_preference(preference: &mut Array1<f32>, params: &Params) {
+    let rate = params.mate_choice.preference_mutation_rate;
+    let signal_size = preference.len().saturating_sub(DNA_DIMS);
+    for (i, value) in preference.iter_mut().enumerate() {
+        let mutation = (rand::random::<f32>() * 2.0 - 1.0) * rate;
+        *value = if i < signal_size {
+            (*value + mutation).clamp(-1.0, 1.0)
+        } else {
+            dna::wrap(*value + mutation)
+        };
+    }
+}
+
+/// Creates an offspring preference by crossover of two parents, then mutates it.
+///
+/// `alpha` is the share of `parent1` (see [`dna::crossover`]). If the parents'
+/// preferences are incompatible, the offspring gets a fresh random preference.
+pub fn inherit_preference(
+    parent1: &Array1<f32>,
+    parent2: &Array1<f32>,
+    alpha: f32,
+    params: &Params,
+) -> Array1<f32> {
+    if parent1.len() != params.signal_size + DNA_DIMS || parent1.len() != parent2.len() {
+        return random_preference(params.signal_size);
+    }
+    let mut preference = dna::crossover(parent1, parent2, alpha);
+    mutate_preference(&mut preference, params);
+    preference
+}
+
+/// Copies a single parent's preference with mutation.
+pub fn clone_preference(parent: &Array1<f32>, params: &Params) -> Array1<f32> {
+    inherit_preference(parent, parent, 1.0, params)
+}
+
+/// Correlation between preferred and displayed signals across a population.
+///
+/// Computes the Pearson correlation between each organism's preferred value
+/// and its own displayed value for every signal channel, then averages over
+/// channels. A positive value means organisms that prefer a display tend to
+/// carry it too, the signature of runaway sexual selection. Returns `None`
+/// when there are fewer than two organisms or no channel varies.
+pub fn preference_display_correlation(organisms: &[Organism], signal_size: usize) -> Option<f32> {
+    let usable: Vec<&Organism> = organisms
+        .iter()
+        .filter(|org| {
+            org.signal.len() == signal_size && org.mate_preference.len() == signal_size + DNA_DIMS
+        })
+        .collect();
+    if usable.len() < 2 {
+        return None;
+    }
+
+    let correlations: Vec<f32> = (0..signal_size)
+        .filter_map(|channel| {
+            let preferred: Vec<f32> = usable
+                .iter()
+                .map(|org| org.mate_preference[channel])
+                .collect();
+            let displayed: Vec<f32> = usable.iter().map(|org| org.signal[channel]).collect();
+            pearson(&preferred, &displayed)
+        })
+        .collect();
+
+    if correlations.is_empty() {
+        None
+    } else {
+        Some(correlations.iter().sum::<f32>() / correlations.len() as f32)
+    }
+}
+
+/// Pearson correlation of two equally long samples, or `None` if either is constant.
+fn pearson(xs: &[f32], ys: &[f32]) -> Option<f32> {
+    let n = xs.len() as f32;
+    let mean_x = xs.iter().sum::<f32>() / n;
+    let mean_y = ys.iter().sum::<f32>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in xs.iter().zip(ys) {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        cov += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+    if var_x <= f32::EPSILON || var_y <= f32::EPSILON {
+        return None;
+    }
+    Some(cov / (var_x * var_y).sqrt())
+}
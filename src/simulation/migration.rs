@@ -0,0 +1,291 @@
+//! Island model migration between genetic pools.
+//!
+//! Pools are islands connected by a topology. Every `interval` seconds each
+//! pool sends a fraction of its organisms to every pool it is connected to,
+//! either as copies or by moving them, and the flows are tallied per pool.
+
+use ndarray::Array1;
+use ndarray_rand::RandomExt;
+use ndarray_rand::rand_distr::Uniform;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use super::brain::Brain;
+use super::ecosystem::Ecosystem;
+use super::energy::Flow;
+use super::event_log::EventColor;
+use super::params::Params;
+
+/// How genetic pools are connected for migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MigrationTopology {
+    /// Each pool is connected to the pools before and after it (wrapping).
+    Ring,
+    /// Pool 0 is a hub connected to every other pool; the others are not connected.
+    Star,
+    /// Every pool is connected to every other pool.
+    FullyConnected,
+    /// Connections are read from [`MigrationParams::adjacency`].
+    Custom,
+}
+
+/// Which organisms are chosen to migrate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MigrantPolicy {
+    /// Fittest organisms first.
+    Best,
+    /// Uniformly random organisms.
+    Random,
+    /// Organisms whose behaviour is furthest from the novelty archive first.
+    MostNovel,
+}
+
+/// Whether migrants leave their source pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MigrationKind {
+    /// An unmutated copy joins the destination pool; the original stays.
+    Copy,
+    /// The organism itself changes pool.
+    Move,
+}
+
+/// Island model configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationParams {
+    /// If false, pools only exchange genes through `pool_interbreed_prob` and empty-pool seeding.
+    pub enabled: bool,
+    /// How pools are connected.
+    pub topology: MigrationTopology,
+    /// Directed adjacency matrix for [`MigrationTopology::Custom`]:
+    /// `adjacency[from][to]` allows migration from `from` to `to`.
+    /// Missing entries count as unconnected.
+    pub adjacency: Vec<Vec<bool>>,
+    /// Fraction of a pool's organisms sent along each outgoing connection per round.
+    pub rate: f32,
+    /// Simulation seconds between migration rounds.
+    pub interval: f32,
+    /// How migrants are chosen.
+    pub policy: MigrantPolicy,
+    /// Whether migrants are copied or moved.
+    pub kind: MigrationKind,
+}
+
+impl Default for MigrationParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            topology: MigrationTopology::Ring,
+            adjacency: Vec::new(),
+            rate: 0.05,
+            interval: 20.0,
+            policy: MigrantPolicy::Random,
+            kind: MigrationKind::Move,
+        }
+    }
+}
+
+impl MigrationParams {
+    /// Returns `true` if organisms may migrate from pool `from` to pool `to`.
+    pub fn is_connected(&self, from: usize, to: usize, num_pools: usize) -> bool {
+        if from == to || from >= num_pools || to >= num_pools {
+            return false;
+        }
+        match self.topology {
+            MigrationTopology::Ring => (from + 1) % num_pools == to || (to + 1) % num_pools == from,
+            MigrationTopology::Star => from == 0 || to == 0,
+            MigrationTopology::FullyConnected => true,
+            MigrationTopology::Custom => self
+                .adjacency
+                .get(from)
+                .and_then(|row| row.get(to))
+                .copied()
+                .unwrap_or(false),
+        }
+    }
+
+    /// Returns the pools that pool `from` sends migrants to.
+    pub fn destinations(&self, from: usize, num_pools: usize) -> Vec<usize> {
+        (0..num_pools)
+            .filter(|&to| self.is_connected(from, to, num_pools))
+            .collect()
+    }
+}
+
+/// Migration timer and cumulative per-pool flows.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationState {
+    /// Time accumulated since the last migration round.
+    pub time_since_migration: f32,
+    /// Total migrants received by each pool.
+    pub inflow: Vec<usize>,
+    /// Total migrants sent by each pool.
+    pub outflow: Vec<usize>,
+}
+
+impl MigrationState {
+    /// Total migrants received by `pool_id`.
+    pub fn inflow(&self, pool_id: usize) -> usize {
+        self.inflow.get(pool_id).copied().unwrap_or(0)
+    }
+
+    /// Total migrants sent by `pool_id`.
+    pub fn outflow(&self, pool_id: usize) -> usize {
+        self.outflow.get(pool_id).copied().unwrap_or(0)
+    }
+
+    fn record(&mut self, from: usize, to: usize, count: usize) {
+        let len = from.max(to) + 1;
+        if self.inflow.len() < len {
+            self.inflow.resize(len, 0);
+        }
+        if self.outflow.len() < len {
+            self.outflow.resize(len, 0);
+        }
+        self.outflow[from] += count;
+        self.inflow[to] += count;
+    }
+}
+
+/// Advances the migration timer and runs a migration round when it is due.
+///
+/// Organisms whose brain doesn't fit the destination pool's architecture stay
+/// home, and the refusal is logged.
+pub fn migrate(state: &mut Ecosystem, params: &Params, dt: f32) {
+    let migration = &params.migration;
+    if !migration.enabled || params.num_genetic_pools < 2 {
+        return;
+    }
+
+    state.migration.time_since_migration += dt;
+    if state.migration.time_since_migration < migration.interval {
+        return;
+    }
+    state.migration.time_since_migration = 0.0;
+
+    // Rank each pool's organisms once, before anyone moves
+    let ranked: Vec<Vec<usize>> = (0..params.num_genetic_pools)
+        .map(|pool_id| rank_migrants(state, pool_id, params))
+        .collect();
+
+    for (from, candidates) in ranked.iter().enumerate() {
+        // Each organism migrates along at most one connection per round
+        let mut candidates = candidates.iter().copied();
+        for to in migration.destinations(from, params.num_genetic_pools) {
+            let count = migrant_count(ranked[from].len(), migration.rate);
+            let destination = params.for_pool(to);
+            let template = Brain::random(&destination.layer_sizes, &destination);
+            let mut migrated = 0;
+            let mut refused = 0;
+            for idx in candidates.by_ref().take(count) {
+                // A brain can't think in a pool built for another architecture
+                if !state.organisms[idx].brain.same_architecture(&template) {
+                    refused += 1;
+                    continue;
+                }
+                match migration.kind {
+                    MigrationKind::Move => {
+                        let energy = state.organisms[idx].energy;
+                        state
+                            .energy_ledger
+                            .record_pool(from, Flow::Migrated, -energy);
+                        state.energy_ledger.record_pool(to, Flow::Migrated, energy);
+                        state.organisms[idx].pool_id = to;
+                    }
+                    MigrationKind::Copy => {
+                        if state.organisms.len() >= params.max_organism {
+                            break;
+                        }
+                        let mut copy = state.organisms[idx].clone();
+                        copy.id = state.generation as usize;
+                        copy.pool_id = to;
+                        copy.reset_as_offspring(params);
+                        copy.pos = Array1::random(2, Uniform::new(0., 1.))
+                            * Array1::from_vec(vec![params.box_width, params.box_height]);
+                        state.generation += 1;
+                        state
+                            .energy_ledger
+                            .record_pool(to, Flow::Migrated, copy.energy);
+                        state.organisms.push(copy);
+                    }
+                }
+                migrated += 1;
+            }
+
+            if migrated > 0 {
+                state.migration.record(from, to, migrated);
+                state.event_log.log(
+                    state.time,
+                    format!(
+                        "{} organism(s) migrated from pool {} to pool {}",
+                        migrated, from, to
+                    ),
+                    EventColor::Migration,
+                );
+            }
+            if refused > 0 {
+                state.event_log.log(
+                    state.time,
+                    format!(
+                        "{} organism(s) could not migrate from pool {} to pool {}: it grows {} brains",
+                        refused,
+                        from,
+                        to,
+                        template.describe_architecture()
+                    ),
+                    EventColor::Migration,
+                );
+            }
+        }
+    }
+}
+
+/// Returns indices of the organisms in `pool_id`, in the order they should migrate.
+fn rank_migrants(state: &Ecosystem, pool_id: usize, params: &Params) -> Vec<usize> {
+    let mut indices: Vec<usize> = state
+        .organisms
+        .iter()
+        .enumerate()
+        .filter(|(_, org)| org.pool_id == pool_id)
+        .map(|(idx, _)| idx)
+        .collect();
+
+    match params.migration.policy {
+        MigrantPolicy::Best => {
+            indices.sort_by(|&a, &b| {
+                state.organisms[b]
+                    .fitness()
+                    .total_cmp(&state.organisms[a].fitness())
+            });
+        }
+        MigrantPolicy::Random => indices.shuffle(&mut rand::rng()),
+        MigrantPolicy::MostNovel => {
+            // Living organisms have no novelty yet, so score them against the archive
+            let samples = params.novelty.trajectory_samples;
+            let novelty = |idx: usize| {
+                let behaviour = state.organisms[idx].behaviour.to_vector(samples);
+                state.novelty_archive().novelty(
+                    &behaviour,
+                    std::iter::empty(),
+                    params.novelty.k_nearest,
+                )
+            };
+            let mut scored: Vec<(usize, f32)> = indices.iter().map(|&i| (i, novelty(i))).collect();
+            scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+            indices = scored.into_iter().map(|(idx, _)| idx).collect();
+        }
+    }
+    indices
+}
+
+/// Number of migrants for a pool of `pool_size`, rounding the fractional
+/// part stochastically so small rates still move organisms over time.
+fn migrant_count(pool_size: usize, rate: f32) -> usize {
+    let expected = pool_size as f32 * rate.clamp(0.0, 1.0);
+    let base = expected.floor() as usize;
+    if rand::rng().random::<f32>() < expected.fract() {
+        base + 1
+    } else {
+        base
+    }
+}
@@ -0,0 +1,215 @@
+//! Heritable body and sensor morphology.
+//!
+//! Each organism carries scale factors for its body radius, movement speed
+//! and scent radius (relative to the global [`Params`] values), alongside its
+//! per-ray vision angles and lengths. Morphology is inherited through the same
+//! crossover and mutation steps as the brain and DNA, and every trait adds to
+//! the organism's metabolic cost so bigger, faster or sharper-sensed bodies
+//! are not free. The armour gene rides along in [`Morphology`] but is
+//! inherited and mutated by [`combat`](super::combat), and only evolves while
+//! combat is enabled.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::combat;
+use super::organism::Organism;
+use super::params::Params;
+
+/// Morphology evolution configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MorphologyParams {
+    /// If false, every organism keeps the global body, speed and sensor values.
+    pub enabled: bool,
+    /// Maximum relative change per trait applied on inheritance.
+    pub mutation_rate: f32,
+    /// Smallest allowed trait scale (also bounds vision ray length as a fraction of `vision_radius`).
+    pub min_scale: f32,
+    /// Largest allowed trait scale (also bounds vision ray length as a fraction of `vision_radius`).
+    pub max_scale: f32,
+    /// Energy per second per unit of body area (body scale squared).
+    pub body_cost: f32,
+    /// Energy per second per unit of speed scale.
+    pub speed_cost: f32,
+    /// Energy per second per unit of mean vision ray length (as a fraction of `vision_radius`).
+    pub vision_cost: f32,
+    /// Energy per second per unit of scent scale.
+    pub scent_cost: f32,
+}
+
+impl Default for MorphologyParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mutation_rate: 0.05,
+            min_scale: 0.5,
+            max_scale: 2.0,
+            body_cost: 0.005,
+            speed_cost: 0.005,
+            vision_cost: 0.005,
+            scent_cost: 0.005,
+        }
+    }
+}
+
+/// Scalar morphology traits, as multipliers of the matching [`Params`] values.
+///
+/// Vision ray genes live on [`Organism::vision_angles`] and
+/// [`Organism::vision_lengths`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Morphology {
+    /// Multiplier on `Params::body_radius`.
+    pub body_scale: f32,
+    /// Multiplier on `Params::move_multiplier`.
+    pub speed_scale: f32,
+    /// Multiplier on `Params::scent_radius`.
+    pub scent_scale: f32,
+    /// Armour in [0, 1], reducing damage taken when combat is enabled.
+    #[serde(default)]
+    pub armour: f32,
+}
+
+impl Default for Morphology {
+    fn default() -> Self {
+        Self {
+            body_scale: 1.0,
+            speed_scale: 1.0,
+            scent_scale: 1.0,
+            armour: 0.0,
+        }
+    }
+}
+
+impl Morphology {
+    /// Collision radius of this body.
+    pub fn body_radius(&self, params: &Params) -> f32 {
+        params.body_radius * self.body_scale
+    }
+
+    /// Movement speed multiplier of this body.
+    pub fn move_multiplier(&self, params: &Params) -> f32 {
+        params.move_multiplier * self.speed_scale
+    }
+
+    /// Scent radius of this body.
+    pub fn scent_radius(&self, params: &Params) -> f32 {
+        params.scent_radius * self.scent_scale
+    }
+
+    /// Mutates every body trait by a random relative step, clamped to the allowed scale range.
+    pub fn mutate(&mut self, params: &Params) {
+        let morphology = &params.morphology;
+        for trait_scale in [
+            &mut self.body_scale,
+            &mut self.speed_scale,
+            &mut self.scent_scale,
+        ] {
+            let step = rand::rng().random_range(-1.0..=1.0) * morphology.mutation_rate;
+            *trait_scale =
+                (*trait_scale * (1.0 + step)).clamp(morphology.min_scale, morphology.max_scale);
+        }
+    }
+
+    /// Blends two parents' body traits (`alpha` = share of `parent1`), keeping
+    /// `parent1`'s armour.
+    pub fn crossover(parent1: &Morphology, parent2: &Morphology, alpha: f32) -> Self {
+        let blend = |a: f32, b: f32| a * alpha + b * (1.0 - alpha);
+        Self {
+            body_scale: blend(parent1.body_scale, parent2.body_scale),
+            speed_scale: blend(parent1.speed_scale, parent2.speed_scale),
+            scent_scale: blend(parent1.scent_scale, parent2.scent_scale),
+            armour: parent1.armour,
+        }
+    }
+}
+
+/// Sets `child`'s morphology from two parents by crossover, then mutates it.
+///
+/// Covers the scalar traits and the per-ray vision genes, which are left
+/// alone when morphology evolution is disabled or the parents' ray counts do
+/// not match the current settings. The armour gene is inherited separately
+/// through [`combat::inherit_armour`].
+pub fn inherit(
+    child: &mut Organism,
+    parent1: &Organism,
+    parent2: &Organism,
+    alpha: f32,
+    params: &Params,
+) {
+    if params.morphology.enabled {
+        child.morphology = Morphology::crossover(&parent1.morphology, &parent2.morphology, alpha);
+
+        let rays = params.num_vision_directions;
+        if [parent1, parent2]
+            .iter()
+            .all(|p| p.vision_angles.len() == rays && p.vision_lengths.len() == rays)
+        {
+            child.vision_angles =
+                &parent1.vision_angles * alpha + &parent2.vision_angles * (1.0 - alpha);
+            child.vision_lengths =
+                &parent1.vision_lengths * alpha + &parent2.vision_lengths * (1.0 - alpha);
+        }
+    }
+    combat::inherit_armour(child, parent1, parent2, alpha, params);
+
+    mutate(child, params);
+}
+
+/// Copies a single parent's morphology into `child`, then mutates it.
+pub fn inherit_single(child: &mut Organism, parent: &Organism, params: &Params) {
+    inherit(child, parent, parent, 1.0, params);
+}
+
+/// Mutates an organism's morphology in place (scalar traits and vision rays).
+///
+/// Ray angles drift by up to `mutation_rate * fov` and stay within ±π; ray
+/// lengths drift relatively and stay within the scale range of `vision_radius`.
+/// The armour gene mutates through [`combat::mutate_armour`].
+pub fn mutate(organism: &mut Organism, params: &Params) {
+    combat::mutate_armour(organism, params);
+    if !params.morphology.enabled {
+        return;
+    }
+    let morphology = &params.morphology;
+    organism.morphology.mutate(params);
+
+    let mut rng = rand::rng();
+    for angle in &mut organism.vision_angles {
+        let step = rng.random_range(-1.0..=1.0) * morphology.mutation_rate * params.fov;
+        *angle = (*angle + step).clamp(-std::f32::consts::PI, std::f32::consts::PI);
+    }
+    let (min_length, max_length) = (
+        morphology.min_scale * params.vision_radius,
+        morphology.max_scale * params.vision_radius,
+    );
+    for length in &mut organism.vision_lengths {
+        let step = rng.random_range(-1.0..=1.0) * morphology.mutation_rate;
+        *length = (*length * (1.0 + step)).clamp(min_length, max_length);
+    }
+}
+
+/// Energy per second an organism spends maintaining its morphology.
+///
+/// Zero when morphology evolution is disabled.
+pub fn metabolic_rate(organism: &Organism, params: &Params) -> f32 {
+    let morphology = &params.morphology;
+    if !morphology.enabled {
+        return 0.0;
+    }
+
+    let mean_vision = if organism.vision_lengths.is_empty() {
+        0.0
+    } else {
+        organism.vision_lengths.mean().unwrap_or(0.0) / params.vision_radius
+    };
+
+    morphology.body_cost * organism.morphology.body_scale.powi(2)
+        + morphology.speed_cost * organism.morphology.speed_scale
+        + morphology.vision_cost * mean_vision
+        + morphology.scent_cost * organism.morphology.scent_scale
+}
+
+/// Largest body radius any organism can evolve, for conservative spatial queries.
+pub fn max_body_radius(params: &Params) -> f32 {
+    params.body_radius * params.morphology.max_scale.max(1.0)
+}
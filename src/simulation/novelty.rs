@@ -0,0 +1,210 @@
+//! Novelty search with behaviour characterisation.
+//!
+//! Each organism records a behaviour characterisation (BC) during its life: a
+//! sampled trajectory of normalized positions and a histogram of the actions
+//! its brain chose. Novelty is the mean distance from an organism's BC to its
+//! k nearest neighbours in an archive of past behaviours, which lets selection
+//! reward doing something new rather than only doing something well.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use super::params::Params;
+
+/// Number of bins in the action histogram.
+///
+/// Bins: turn left, turn right, move forward, move backward, attack, share.
+pub const ACTION_BINS: usize = 6;
+
+/// Number of motor outputs recorded each step.
+///
+/// Slots: rotate, move, attack, share.
+pub const MOTOR_SLOTS: usize = 4;
+
+/// Brain output magnitude above which a continuous action counts as "taken".
+const ACTION_THRESHOLD: f32 = 0.1;
+
+/// How parents are ranked when breeding from the graveyard.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SelectionMode {
+    /// Rank by fitness only (age and score).
+    Fitness,
+    /// Rank by novelty only.
+    Novelty,
+    /// Rank by a weighted blend of normalized fitness and normalized novelty.
+    Blend,
+}
+
+/// Novelty search configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoveltyParams {
+    /// Parent selection criterion.
+    pub selection_mode: SelectionMode,
+    /// Weight of novelty in [`SelectionMode::Blend`] (0.0 = fitness only, 1.0 = novelty only).
+    pub novelty_weight: f32,
+    /// Number of nearest neighbours averaged to compute novelty.
+    pub k_nearest: usize,
+    /// Maximum number of behaviours kept in the archive (oldest are evicted first).
+    pub archive_size: usize,
+    /// Probability that a dead organism's behaviour is added to the archive.
+    pub archive_add_prob: f32,
+    /// Number of trajectory samples recorded per organism.
+    pub trajectory_samples: usize,
+    /// Simulation seconds between two trajectory samples.
+    pub sample_interval: f32,
+}
+
+impl Default for NoveltyParams {
+    fn default() -> Self {
+        Self {
+            selection_mode: SelectionMode::Fitness,
+            novelty_weight: 0.5,
+            k_nearest: 15,
+            archive_size: 500,
+            archive_add_prob: 0.1,
+            trajectory_samples: 8,
+            sample_interval: 2.0,
+        }
+    }
+}
+
+/// Behaviour characterisation gathered over an organism's lifetime.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BehaviourCharacterisation {
+    /// Sampled positions normalized to [0, 1] by the world size.
+    pub trajectory: Vec<[f32; 2]>,
+    /// Count of steps each action was taken (see [`ACTION_BINS`]).
+    pub action_counts: [u32; ACTION_BINS],
+    /// Number of steps recorded.
+    pub steps: u32,
+    /// Time accumulated since the last trajectory sample.
+    pub time_since_sample: f32,
+}
+
+impl BehaviourCharacterisation {
+    /// Records one step of behaviour.
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - Current position of the organism
+    /// * `actions` - Brain outputs for rotation, velocity, attack and share
+    /// * `params` - Simulation parameters
+    /// * `dt` - Time delta
+    pub fn record(&mut self, pos: [f32; 2], actions: [f32; MOTOR_SLOTS], params: &Params, dt: f32) {
+        let [rotation, velocity, attack, share] = actions;
+        let taken = [
+            rotation < -ACTION_THRESHOLD,
+            rotation > ACTION_THRESHOLD,
+            velocity > ACTION_THRESHOLD,
+            velocity < -ACTION_THRESHOLD,
+            attack > ACTION_THRESHOLD,
+            share > ACTION_THRESHOLD,
+        ];
+        for (count, taken) in self.action_counts.iter_mut().zip(taken) {
+            *count += u32::from(taken);
+        }
+        self.steps += 1;
+
+        // Sample the first position immediately, then every `sample_interval`
+        self.time_since_sample += dt;
+        let due =
+            self.trajectory.is_empty() || self.time_since_sample >= params.novelty.sample_interval;
+        if due && self.trajectory.len() < params.novelty.trajectory_samples {
+            self.trajectory.push([
+                (pos[0] / params.box_width).clamp(0.0, 1.0),
+                (pos[1] / params.box_height).clamp(0.0, 1.0),
+            ]);
+            self.time_since_sample = 0.0;
+        }
+    }
+
+    /// Flattens the characterisation into a fixed-length feature vector.
+    ///
+    /// The trajectory is padded with its last sample (organisms that died
+    /// early "stayed" where they died), followed by the action frequencies.
+    pub fn to_vector(&self, trajectory_samples: usize) -> Vec<f32> {
+        let mut features = Vec::with_capacity(trajectory_samples * 2 + ACTION_BINS);
+        let last = self.trajectory.last().copied().unwrap_or([0.5, 0.5]);
+        for i in 0..trajectory_samples {
+            let sample = self.trajectory.get(i).copied().unwrap_or(last);
+            features.extend_from_slice(&sample);
+        }
+
+        let steps = self.steps.max(1) as f32;
+        features.extend(self.action_counts.iter().map(|&c| c as f32 / steps));
+        features
+    }
+}
+
+/// Archive of past behaviours used as the reference set for novelty.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NoveltyArchive {
+    /// Archived behaviour feature vectors, oldest first.
+    behaviours: VecDeque<Vec<f32>>,
+}
+
+impl NoveltyArchive {
+    /// Computes the novelty of a behaviour vector.
+    ///
+    /// Novelty is the mean Euclidean distance to the `k` nearest behaviours
+    /// in the archive and in `population` combined. Returns 0.0 when there is
+    /// nothing to compare against.
+    pub fn novelty<'p>(
+        &self,
+        behaviour: &[f32],
+        population: impl Iterator<Item = &'p [f32]>,
+        k: usize,
+    ) -> f32 {
+        let mut distances: Vec<f32> = self
+            .behaviours
+            .iter()
+            .filter(|other| other.len() == behaviour.len())
+            .map(|other| behaviour_distance(behaviour, other))
+            .collect();
+        distances.extend(
+            population
+                .filter(|other| other.len() == behaviour.len())
+                .map(|other| behaviour_distance(behaviour, other)),
+        );
+
+        if distances.is_empty() || k == 0 {
+            return 0.0;
+        }
+
+        let k = k.min(distances.len());
+        distances.select_nth_unstable_by(k - 1, f32::total_cmp);
+        distances[..k].iter().sum::<f32>() / k as f32
+    }
+
+    /// Adds a behaviour to the archive, evicting the oldest entry when full.
+    pub fn add(&mut self, behaviour: Vec<f32>, max_size: usize) {
+        self.behaviours.push_back(behaviour);
+        while self.behaviours.len() > max_size {
+            self.behaviours.pop_front();
+        }
+    }
+
+    /// Number of archived behaviours.
+    pub fn len(&self) -> usize {
+        self.behaviours.len()
+    }
+
+    /// Returns `true` if the archive holds no behaviours.
+    pub fn is_empty(&self) -> bool {
+        self.behaviours.is_empty()
+    }
+
+    /// Iterates over the archived behaviour vectors, oldest first.
+    pub fn behaviours(&self) -> impl Iterator<Item = &[f32]> {
+        self.behaviours.iter().map(Vec::as_slice)
+    }
+}
+
+/// Euclidean distance between two behaviour vectors.
+pub fn behaviour_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f32>()
+        .sqrt()
+}
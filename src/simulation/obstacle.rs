@@ -0,0 +1,241 @@
+//! Static obstacles such as walls, rocks and mazes.
+//!
+//! Obstacles are polygons in world coordinates. Organisms are pushed out of
+//! them, projectiles stop when they hit them, vision rays are blocked by them
+//! and food never spawns inside them. Geometry is handled with the `geo` crate.
+
+use geo::algorithm::line_intersection::{LineIntersection, line_intersection};
+use geo::{
+    Closest, ClosestPoint, Contains, Coord, Line, LineString, Point, Polygon, TriangulateEarcut,
+};
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+
+/// Obstacle configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ObstacleParams {
+    /// If false, the world has no obstacles and vision has no wall channel.
+    pub enabled: bool,
+    /// Obstacles placed in a new ecosystem.
+    pub obstacles: Vec<Obstacle>,
+}
+
+/// A solid polygon in world coordinates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "ObstacleShape", into = "ObstacleShape")]
+pub struct Obstacle {
+    /// Polygon vertices in order; the ring closes automatically.
+    vertices: Vec<[f32; 2]>,
+    /// The vertices as a `geo` polygon, built once for the geometry queries.
+    polygon: Polygon<f32>,
+}
+
+/// Serialized form of an [`Obstacle`]: just its vertices.
+#[derive(Serialize, Deserialize)]
+struct ObstacleShape {
+    vertices: Vec<[f32; 2]>,
+}
+
+impl From<ObstacleShape> for Obstacle {
+    fn from(shape: ObstacleShape) -> Self {
+        Self::new(shape.vertices)
+    }
+}
+
+impl From<Obstacle> for ObstacleShape {
+    fn from(obstacle: Obstacle) -> Self {
+        Self {
+            vertices: obstacle.vertices,
+        }
+    }
+}
+
+impl Obstacle {
+    /// Creates an obstacle from polygon vertices.
+    pub fn new(vertices: Vec<[f32; 2]>) -> Self {
+        let ring: Vec<Coord<f32>> = vertices.iter().map(|&[x, y]| Coord { x, y }).collect();
+        let polygon = Polygon::new(LineString::new(ring), vec![]);
+        Self { vertices, polygon }
+    }
+
+    /// Polygon vertices in order; the ring closes automatically.
+    pub fn vertices(&self) -> &[[f32; 2]] {
+        &self.vertices
+    }
+
+    /// Axis-aligned rectangle with top-left corner `(x, y)`.
+    pub fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self::new(vec![
+            [x, y],
+            [x + width, y],
+            [x + width, y + height],
+            [x, y + height],
+        ])
+    }
+
+    /// Straight wall of the given thickness between two points.
+    pub fn wall(start: [f32; 2], end: [f32; 2], thickness: f32) -> Self {
+        let (dx, dy) = (end[0] - start[0], end[1] - start[1]);
+        let length = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+        let (nx, ny) = (
+            -dy / length * thickness / 2.0,
+            dx / length * thickness / 2.0,
+        );
+        Self::new(vec![
+            [start[0] + nx, start[1] + ny],
+            [end[0] + nx, end[1] + ny],
+            [end[0] - nx, end[1] - ny],
+            [start[0] - nx, start[1] - ny],
+        ])
+    }
+
+    /// The obstacle as a `geo` polygon.
+    pub fn polygon(&self) -> &Polygon<f32> {
+        &self.polygon
+    }
+
+    /// Returns `true` if `pos` lies inside the obstacle.
+    pub fn contains(&self, pos: &Array1<f32>) -> bool {
+        self.near(pos, 0.0) && self.polygon.contains(&Point::new(pos[0], pos[1]))
+    }
+
+    /// Distance along the segment from `start` to `end` at which it first
+    /// crosses the obstacle's boundary, if it does.
+    pub fn segment_hit(&self, start: &Array1<f32>, end: &Array1<f32>) -> Option<f32> {
+        // A segment stays within half its length of its midpoint
+        let midpoint = (start + end) / 2.0;
+        let half_length = (end - start).mapv(|x| x.powi(2)).sum().sqrt() / 2.0;
+        if self.vertices.len() < 3 || !self.near(&midpoint, half_length) {
+            return None;
+        }
+        let origin = Coord {
+            x: start[0],
+            y: start[1],
+        };
+        let segment = Line::new(
+            origin,
+            Coord {
+                x: end[0],
+                y: end[1],
+            },
+        );
+        self.polygon
+            .exterior()
+            .lines()
+            .filter_map(|edge| line_intersection(segment, edge))
+            .map(|hit| match hit {
+                LineIntersection::SinglePoint { intersection, .. } => intersection,
+                // Running along an edge hits it at whichever end is nearer
+                LineIntersection::Collinear { intersection } => {
+                    if distance(origin, intersection.start) < distance(origin, intersection.end) {
+                        intersection.start
+                    } else {
+                        intersection.end
+                    }
+                }
+            })
+            .map(|point| distance(origin, point))
+            .min_by(f32::total_cmp)
+    }
+
+    /// Closest point on the obstacle's boundary to `pos`.
+    pub fn closest_boundary_point(&self, pos: &Array1<f32>) -> Option<[f32; 2]> {
+        match self
+            .polygon
+            .exterior()
+            .closest_point(&Point::new(pos[0], pos[1]))
+        {
+            Closest::Intersection(p) | Closest::SinglePoint(p) => Some([p.x(), p.y()]),
+            Closest::Indeterminate => None,
+        }
+    }
+
+    /// Triangles covering the obstacle, for rendering.
+    pub fn triangles(&self) -> Vec<[[f32; 2]; 3]> {
+        if self.vertices.len() < 3 {
+            return Vec::new();
+        }
+        self.polygon
+            .earcut_triangles_iter()
+            .map(|t| t.to_array().map(|c| [c.x, c.y]))
+            .collect()
+    }
+
+    /// Cheap bounding-box test: can anything within `margin` of `pos` touch the obstacle?
+    fn near(&self, pos: &Array1<f32>, margin: f32) -> bool {
+        let (x, y) = (pos[0], pos[1]);
+        self.vertices.iter().any(|v| v[0] <= x + margin)
+            && self.vertices.iter().any(|v| v[0] >= x - margin)
+            && self.vertices.iter().any(|v| v[1] <= y + margin)
+            && self.vertices.iter().any(|v| v[1] >= y - margin)
+    }
+}
+
+fn distance(a: Coord<f32>, b: Coord<f32>) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+/// Returns `true` if `pos` lies inside any obstacle.
+pub fn is_blocked(obstacles: &[Obstacle], pos: &Array1<f32>) -> bool {
+    obstacles.iter().any(|obstacle| obstacle.contains(pos))
+}
+
+/// Distance along the segment from `start` to `end` to the first obstacle it hits.
+pub fn ray_distance(obstacles: &[Obstacle], start: &Array1<f32>, end: &Array1<f32>) -> Option<f32> {
+    obstacles
+        .iter()
+        .filter_map(|obstacle| obstacle.segment_hit(start, end))
+        .min_by(f32::total_cmp)
+}
+
+/// Pushes a circular body out of every obstacle it overlaps.
+///
+/// Returns `true` if the position was changed.
+pub fn resolve_collision(pos: &mut Array1<f32>, radius: f32, obstacles: &[Obstacle]) -> bool {
+    let mut moved = false;
+    for obstacle in obstacles {
+        if !obstacle.near(pos, radius) {
+            continue;
+        }
+        let Some([cx, cy]) = obstacle.closest_boundary_point(pos) else {
+            continue;
+        };
+        let (dx, dy) = (pos[0] - cx, pos[1] - cy);
+        let dist = (dx * dx + dy * dy).sqrt();
+        let inside = obstacle.contains(pos);
+        if !inside && dist >= radius {
+            continue;
+        }
+
+        // Leave through the nearest edge, one radius clear of it
+        let (ux, uy) = if dist > f32::EPSILON {
+            let sign = if inside { -1.0 } else { 1.0 };
+            (sign * dx / dist, sign * dy / dist)
+        } else {
+            (0.0, 0.0)
+        };
+        pos[0] = cx + ux * radius;
+        pos[1] = cy + uy * radius;
+        moved = true;
+    }
+    moved
+}
+
+/// A small maze of walls and rocks that leaves room to move around.
+pub fn maze_preset(box_width: f32, box_height: f32) -> Vec<Obstacle> {
+    let (w, h) = (box_width, box_height);
+    let thickness = w.min(h) * 0.02;
+    vec![
+        Obstacle::wall([w * 0.2, h * 0.25], [w * 0.6, h * 0.25], thickness),
+        Obstacle::wall([w * 0.8, h * 0.15], [w * 0.8, h * 0.6], thickness),
+        Obstacle::wall([w * 0.4, h * 0.75], [w * 0.85, h * 0.75], thickness),
+        Obstacle::wall([w * 0.2, h * 0.45], [w * 0.2, h * 0.85], thickness),
+        Obstacle::new(vec![
+            [w * 0.45, h * 0.45],
+            [w * 0.55, h * 0.42],
+            [w * 0.6, h * 0.52],
+            [w * 0.5, h * 0.6],
+            [w * 0.42, h * 0.54],
+        ]),
+    ]
+}
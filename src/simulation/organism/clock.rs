@@ -0,0 +1,68 @@
+//! Clock sense - tells organisms the time of day and the season.
+//!
+//! Only active when the environment schedule is enabled; otherwise it
+//! produces no inputs.
+
+use ndarray::Array1;
+
+use super::super::environment;
+use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
+use super::Organism;
+use super::sense::Sense;
+
+/// Clock sense for environmental phase awareness.
+///
+/// Outputs (when the environment schedule is enabled):
+/// - Day phase (sin and cos)
+/// - Season phase (sin and cos)
+pub struct Clock;
+
+impl Clock {
+    /// Creates a new clock sense.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sense for Clock {
+    fn sense(
+        &self,
+        _organism: &Organism,
+        world: &WorldView,
+        params: &Params,
+        _neighbourhood: &Neighbourhood,
+    ) -> Array1<f32> {
+        if !params.environment.enabled {
+            return Array1::zeros(0);
+        }
+        let conditions = environment::conditions(world.time, params);
+        let day = conditions.day_phase * std::f32::consts::TAU;
+        let season = conditions.season_phase * std::f32::consts::TAU;
+        Array1::from_vec(vec![day.sin(), day.cos(), season.sin(), season.cos()])
+    }
+
+    fn input_size(&self, params: &Params) -> usize {
+        // day(sin, cos) + season(sin, cos), only when the schedule is enabled
+        if params.environment.enabled { 4 } else { 0 }
+    }
+
+    fn input_labels(&self, params: &Params) -> Vec<String> {
+        let labels = ["Day Sin", "Day Cos", "Season Sin", "Season Cos"];
+        labels[..self.input_size(params)]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "Clock"
+    }
+}
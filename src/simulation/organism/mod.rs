@@ -1,18 +1,27 @@
 //! Organism module containing organism behavior and perception systems.
 
+mod clock;
+#[allow(clippy::module_inception)]
 mod organism;
 mod perception;
+mod pheromones;
 mod proprioception;
 mod scent;
 mod sense;
+mod stereo_scent;
+mod touch;
 mod vision;
 
 // Re-export everything from the organism module
 pub use organism::*;
 
 // Re-export perception system components
-pub use perception::Perception;
+pub use clock::Clock;
+pub use perception::{Perception, SenseKind, default_senses};
+pub use pheromones::Pheromones;
 pub use proprioception::Proprioception;
 pub use scent::Scent;
 pub use sense::Sense;
+pub use stereo_scent::StereoScent;
+pub use touch::{Touch, TouchState};
 pub use vision::Vision;
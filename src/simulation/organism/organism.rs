@@ -9,8 +9,15 @@ use ndarray_rand::rand_distr::Uniform;
 use serde::{Deserialize, Serialize};
 
 use super::super::brain;
+use super::super::combat;
+use super::super::diet;
+use super::super::energy::{Flow, FlowTotals};
 use super::super::locatable::Locatable;
+use super::super::mate_choice;
+use super::super::morphology::Morphology;
+use super::super::novelty::BehaviourCharacterisation;
 use super::super::params::Params;
+use super::touch::TouchState;
 
 /// A simulated organism with a neural network brain.
 ///
@@ -43,14 +50,26 @@ pub struct Organism {
     pub brain: brain::Brain,
     /// Cooldown before next attack (seconds).
     pub attack_cooldown: f32,
+    /// Cooldown before next organism-initiated reproduction (seconds).
+    #[serde(default)]
+    pub reproduction_cooldown: f32,
     /// Last brain inputs (for visualization purposes).
     pub last_brain_inputs: Array1<f32>,
-    /// Vision ray angles relative to organism's rotation
+    /// Vision ray angles relative to organism's rotation (heritable morphology gene)
     pub vision_angles: Array1<f32>,
-    /// Vision ray lengths as fraction of max vision radius
+    /// Vision ray lengths in world units (heritable morphology gene)
     pub vision_lengths: Array1<f32>,
+    /// Heritable body size, speed and scent range
+    #[serde(default)]
+    pub morphology: Morphology,
+    /// Diet genes, one per food kind, setting digestion efficiency
+    #[serde(default)]
+    pub diet: Array1<f32>,
     /// DNA vector for breeding compatibility (2D space)
     pub dna: Array1<f32>,
+    /// Preferred mate signal followed by preferred mate DNA (`signal_size` + 2 values)
+    #[serde(default)]
+    pub mate_preference: Array1<f32>,
     /// Genetic pool ID (organisms can only breed within their pool)
     pub pool_id: usize,
     /// Birth generation number (for tracking lineage)
@@ -59,6 +78,27 @@ pub struct Organism {
     pub reproduction_method: u8,
     /// Parent score(s) at time of birth (for tracking improvement)
     pub parent_avg_score: f64,
+    /// Behaviour characterisation recorded over this organism's lifetime
+    #[serde(default)]
+    pub behaviour: BehaviourCharacterisation,
+    /// Novelty of this organism's behaviour, computed when it dies
+    #[serde(default)]
+    pub novelty: f32,
+    /// What the organism felt since it last perceived
+    #[serde(default)]
+    pub touch: TouchState,
+    /// Velocity in world units per second (thrust with physics, otherwise bounces only)
+    #[serde(default)]
+    pub velocity: [f32; 2],
+    /// Angular velocity in radians per second (only with physics)
+    #[serde(default)]
+    pub angular_velocity: f32,
+    /// Health, worn down by damage when combat is enabled (separate from energy)
+    #[serde(default = "combat::full_health")]
+    pub health: f32,
+    /// Energy flows not yet collected into the ecosystem's energy ledger
+    #[serde(skip)]
+    pub energy_flows: FlowTotals,
 }
 
 impl Organism {
@@ -106,22 +146,7 @@ impl Organism {
         let vision_lengths = Array1::from_elem(num_vision_directions, max_vision);
 
         // Create brain based on brain type
-        let brain = match params.brain_type {
-            brain::BrainType::MLP => brain::Brain::new(&layer_sizes, 0.1),
-            brain::BrainType::Transformer => {
-                let output_size = layer_sizes.last().copied().unwrap_or(40);
-                brain::Brain::new_transformer(
-                    input_size,
-                    output_size,
-                    params.transformer_model_dim,
-                    params.transformer_num_blocks,
-                    params.transformer_num_heads,
-                    params.transformer_head_dim,
-                    params.transformer_ff_dim,
-                    0.1,
-                )
-            }
-        };
+        let brain = brain::Brain::random(&layer_sizes, params);
 
         Self {
             id,
@@ -134,14 +159,25 @@ impl Organism {
             memory: Array1::zeros(memory_size),
             brain,
             attack_cooldown: 0.0,
+            reproduction_cooldown: 0.0,
             last_brain_inputs: Array1::zeros(input_size),
             vision_angles,
             vision_lengths,
+            morphology: Morphology::default(),
+            diet: diet::random_diet(params.food_kinds.len()),
             dna: Array1::random(2, Uniform::new(0.0, 1.0)),
+            mate_preference: mate_choice::random_preference(signal_size),
             pool_id,
             birth_generation: 0,
             reproduction_method: 0, // random initialization
             parent_avg_score: 0.0,
+            behaviour: BehaviourCharacterisation::default(),
+            novelty: 0.0,
+            touch: TouchState::default(),
+            velocity: [0.0, 0.0],
+            angular_velocity: 0.0,
+            health: params.combat.max_health,
+            energy_flows: FlowTotals::default(),
         }
     }
 
@@ -203,8 +239,10 @@ impl Organism {
     /// # Arguments
     ///
     /// * `amount` - Energy to subtract
-    pub fn consume_energy(&mut self, amount: f32) {
+    /// * `flow` - What the energy was spent on, for the energy ledger
+    pub fn consume_energy(&mut self, amount: f32, flow: Flow) {
         self.energy -= amount;
+        self.energy_flows.record(flow, -amount);
     }
 
     /// Increases the organism's energy up to a maximum.
@@ -213,12 +251,18 @@ impl Organism {
     ///
     /// * `amount` - Energy to add
     /// * `max_energy` - Maximum energy cap
-    pub fn gain_energy(&mut self, amount: f32, max_energy: f32) {
-        self.energy = (self.energy + amount).min(max_energy);
+    /// * `flow` - Where the energy came from, for the energy ledger
+    pub fn gain_energy(&mut self, amount: f32, max_energy: f32, flow: Flow) {
+        let energy = (self.energy + amount).min(max_energy);
+        self.energy_flows.record(flow, amount);
+        self.energy_flows
+            .record(Flow::Overflow, energy - self.energy - amount);
+        self.energy = energy;
     }
 
     /// Kills the organism by setting energy to 0.
     pub fn kill(&mut self) {
+        self.energy_flows.record(Flow::Death, -self.energy);
         self.energy = 0.0;
     }
 
@@ -240,7 +284,44 @@ impl Organism {
         self.attack_cooldown = cooldown_time;
     }
 
-    /// Decrements the attack cooldown timer.
+    /// Checks if the organism can reproduce (cooldown expired).
+    ///
+    /// # Returns
+    ///
+    /// `true` if reproduction cooldown <= 0, `false` otherwise.
+    pub fn can_reproduce(&self) -> bool {
+        self.reproduction_cooldown <= 0.0
+    }
+
+    /// Resets the reproduction cooldown timer.
+    ///
+    /// # Arguments
+    ///
+    /// * `cooldown_time` - Cooldown duration in seconds
+    pub fn reset_reproduction_cooldown(&mut self, cooldown_time: f32) {
+        self.reproduction_cooldown = cooldown_time;
+    }
+
+    /// Clears the lifetime state of a clone so it starts out as a newborn.
+    ///
+    /// Resets age, score, the novelty behaviour record, touch, motion and
+    /// health; identity, pool and position are left to the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Simulation parameters
+    pub fn reset_as_offspring(&mut self, params: &Params) {
+        self.age = 0.0;
+        self.score = 0;
+        self.behaviour = BehaviourCharacterisation::default();
+        self.novelty = 0.0;
+        self.touch.clear();
+        self.velocity = [0.0, 0.0];
+        self.angular_velocity = 0.0;
+        self.health = params.combat.max_health;
+    }
+
+    /// Decrements the attack and reproduction cooldown timers.
     ///
     /// # Arguments
     ///
@@ -249,6 +330,9 @@ impl Organism {
         if self.attack_cooldown > 0.0 {
             self.attack_cooldown -= dt;
         }
+        if self.reproduction_cooldown > 0.0 {
+            self.reproduction_cooldown -= dt;
+        }
     }
 }
 
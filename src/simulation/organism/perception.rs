@@ -1,14 +1,100 @@
 //! Perception system that combines multiple senses into brain inputs.
 //!
 //! The perception system manages different sensory modalities and combines
-//! their outputs into a single input vector for the organism's brain.
+//! their outputs into a single input vector for the organism's brain. Which
+//! senses organisms have, and in what order, is configured by
+//! `Params::senses`; the brain's input layer and the UI's input labels are
+//! both derived from it.
 
 use ndarray::Array1;
+use serde::{Deserialize, Serialize};
 
-use super::super::ecosystem::Ecosystem;
 use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
 use super::Organism;
+use super::clock::Clock;
+use super::pheromones::Pheromones;
+use super::proprioception::Proprioception;
+use super::scent::Scent;
 use super::sense::Sense;
+use super::stereo_scent::StereoScent;
+use super::touch::Touch;
+use super::vision::Vision;
+
+/// A sense that can be listed in `Params::senses`, with its options.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SenseKind {
+    /// Vision rays (see [`Vision`]).
+    Vision,
+    /// Undirected scent (see [`Scent`]).
+    Scent,
+    /// Directional scent split into `sectors` around the heading (see [`StereoScent`]).
+    StereoScent {
+        /// Number of sectors (2 = left and right).
+        sectors: usize,
+    },
+    /// Memory, energy, rotation, position and, with physics or combat,
+    /// velocity and health (see [`Proprioception`]).
+    Proprioception,
+    /// Day and season phase; empty unless environment cycles are enabled (see [`Clock`]).
+    Clock,
+    /// Pheromone concentration and gradient; empty unless the pheromone field
+    /// is enabled (see [`Pheromones`]).
+    Pheromones,
+    /// Damage, feeding, gifts and contact (see [`Touch`]).
+    Touch,
+}
+
+impl SenseKind {
+    /// Every kind of sense, with default options.
+    pub const ALL: [Self; 7] = [
+        Self::Vision,
+        Self::Scent,
+        Self::StereoScent { sectors: 2 },
+        Self::Proprioception,
+        Self::Clock,
+        Self::Pheromones,
+        Self::Touch,
+    ];
+
+    /// Builds the sense.
+    pub fn build(&self) -> Box<dyn Sense> {
+        match self {
+            Self::Vision => Box::new(Vision::new()),
+            Self::Scent => Box::new(Scent::new()),
+            Self::StereoScent { sectors } => Box::new(StereoScent::new(*sectors)),
+            Self::Proprioception => Box::new(Proprioception::new()),
+            Self::Clock => Box::new(Clock::new()),
+            Self::Pheromones => Box::new(Pheromones::new()),
+            Self::Touch => Box::new(Touch::new()),
+        }
+    }
+
+    /// Human-readable name, matching [`Sense::name`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Vision => "Vision",
+            Self::Scent => "Scent",
+            Self::StereoScent { .. } => "StereoScent",
+            Self::Proprioception => "Proprioception",
+            Self::Clock => "Clock",
+            Self::Pheromones => "Pheromones",
+            Self::Touch => "Touch",
+        }
+    }
+}
+
+/// The default sense list: vision, scent, proprioception, clock and pheromones.
+pub fn default_senses() -> Vec<SenseKind> {
+    vec![
+        SenseKind::Vision,
+        SenseKind::Scent,
+        SenseKind::Proprioception,
+        SenseKind::Clock,
+        SenseKind::Pheromones,
+    ]
+}
 
 /// Manages multiple senses and combines them into brain inputs.
 ///
@@ -35,14 +121,24 @@ impl Perception {
         Self { senses }
     }
 
+    /// Creates a perception system from a list of sense kinds, in order.
+    pub fn from_kinds(kinds: &[SenseKind]) -> Self {
+        Self::new(kinds.iter().map(SenseKind::build).collect())
+    }
+
+    /// Creates the perception system configured by `params.senses`.
+    pub fn from_params(params: &Params) -> Self {
+        Self::from_kinds(&params.senses)
+    }
+
     /// Process all senses and return combined brain inputs.
     ///
     /// # Arguments
     ///
     /// * `organism` - The organism doing the sensing
-    /// * `ecosystem` - The current state of the ecosystem
+    /// * `world` - What the organism can perceive of the world this step
     /// * `params` - Simulation parameters
-    /// * `trees` - Optional pre-built KD-trees for efficient spatial queries
+    /// * `neighbourhood` - Everything near the organism, gathered once per step
     ///
     /// # Returns
     ///
@@ -50,16 +146,16 @@ impl Perception {
     pub fn perceive(
         &self,
         organism: &Organism,
-        ecosystem: &Ecosystem,
+        world: &WorldView,
         params: &Params,
-        trees: Option<&super::super::ecosystem::SpatialTrees>,
+        neighbourhood: &Neighbourhood,
     ) -> Array1<f32> {
         let total_size = self.total_input_size(params);
         let mut combined_inputs = Array1::zeros(total_size);
 
         let mut offset = 0;
         for sense in &self.senses {
-            let sense_outputs = sense.sense(organism, ecosystem, params, trees);
+            let sense_outputs = sense.sense(organism, world, params, neighbourhood);
             let sense_size = sense.input_size(params);
 
             // Copy sense outputs into the combined array
@@ -86,6 +182,22 @@ impl Perception {
         self.senses.iter().map(|s| s.input_size(params)).sum()
     }
 
+    /// Returns a label for every brain input, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Simulation parameters
+    ///
+    /// # Returns
+    ///
+    /// The concatenated input labels of all senses (`total_input_size` entries).
+    pub fn input_labels(&self, params: &Params) -> Vec<String> {
+        self.senses
+            .iter()
+            .flat_map(|s| s.input_labels(params))
+            .collect()
+    }
+
     /// Returns a reference to the senses in this perception system.
     pub fn senses(&self) -> &[Box<dyn Sense>] {
         &self.senses
@@ -94,15 +206,6 @@ impl Perception {
 
 impl Default for Perception {
     fn default() -> Self {
-        // Default perception includes all available senses
-        use super::proprioception::Proprioception;
-        use super::scent::Scent;
-        use super::vision::Vision;
-
-        Self::new(vec![
-            Box::new(Vision::new()),
-            Box::new(Scent::new()),
-            Box::new(Proprioception::new()),
-        ])
+        Self::from_kinds(&default_senses())
     }
 }
@@ -0,0 +1,88 @@
+//! Pheromone sense - lets organisms follow trails in the pheromone field.
+//!
+//! Only active when the pheromone field is enabled; otherwise it produces no
+//! inputs.
+
+use ndarray::Array1;
+
+use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
+use super::Organism;
+use super::sense::Sense;
+
+/// Pheromone sense reading the field under the organism.
+///
+/// Outputs per channel (when the pheromone field is enabled):
+/// - Concentration, squashed to [0, 1) by `saturation`
+/// - Gradient direction relative to heading (forward and left components of
+///   a unit vector; zero on a flat field)
+pub struct Pheromones;
+
+impl Pheromones {
+    /// Creates a new pheromone sense.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Pheromones {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sense for Pheromones {
+    fn sense(
+        &self,
+        organism: &Organism,
+        world: &WorldView,
+        params: &Params,
+        _neighbourhood: &Neighbourhood,
+    ) -> Array1<f32> {
+        let mut outputs = Array1::zeros(self.input_size(params));
+        if outputs.is_empty() {
+            return outputs;
+        }
+
+        let field = world.pheromones;
+        let saturation = params.pheromones.saturation.max(f32::EPSILON);
+        let (sin, cos) = organism.rot.sin_cos();
+        for channel in 0..params.pheromones.channels {
+            let concentration = field.concentration_at(&organism.pos, channel).max(0.0);
+            outputs[channel * 3] = concentration / (concentration + saturation);
+
+            let [dx, dy] = field.gradient(&organism.pos, channel, params);
+            let magnitude = (dx * dx + dy * dy).sqrt();
+            if magnitude > f32::EPSILON {
+                // Rotate into the organism's frame: x forward, y to the left
+                outputs[channel * 3 + 1] = (dx * cos + dy * sin) / magnitude;
+                outputs[channel * 3 + 2] = (dy * cos - dx * sin) / magnitude;
+            }
+        }
+        outputs
+    }
+
+    fn input_size(&self, params: &Params) -> usize {
+        // concentration + gradient(forward, left) per channel, only when enabled
+        if params.pheromones.enabled {
+            params.pheromones.channels * 3
+        } else {
+            0
+        }
+    }
+
+    fn input_labels(&self, params: &Params) -> Vec<String> {
+        (0..self.input_size(params) / 3)
+            .flat_map(|channel| {
+                ["C", "Fwd", "Left"]
+                    .into_iter()
+                    .map(move |slot| format!("Ph{channel} {slot}"))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "Pheromones"
+    }
+}
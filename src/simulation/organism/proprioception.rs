@@ -1,12 +1,14 @@
 //! Proprioception sense - organism's awareness of its own state.
 //!
 //! Provides information about the organism's internal state such as
-//! memory and energy levels.
+//! memory and energy levels, its own motion when physics is enabled and its
+//! health when combat is enabled.
 
 use ndarray::Array1;
 
-use super::super::ecosystem::Ecosystem;
 use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
 use super::Organism;
 use super::sense::Sense;
 
@@ -17,6 +19,10 @@ use super::sense::Sense;
 /// - Energy level (normalized)
 /// - Rotation (sin and cos components for continuous encoding)
 /// - Position encoding (sin and cos of normalized x and y coordinates)
+/// - With physics: forward and leftward speed relative to heading (as a
+///   fraction of `move_multiplier`) and turn rate (as a fraction of 10 rad/s),
+///   each clamped to [-1, 1]
+/// - With combat: health as a fraction of `max_health`
 pub struct Proprioception;
 
 impl Proprioception {
@@ -36,13 +42,15 @@ impl Sense for Proprioception {
     fn sense(
         &self,
         organism: &Organism,
-        _ecosystem: &Ecosystem,
+        _world: &WorldView,
         params: &Params,
-        _trees: Option<&super::super::ecosystem::SpatialTrees>,
+        _neighbourhood: &Neighbourhood,
     ) -> Array1<f32> {
         let memory_size = organism.memory.len();
         // memory + energy + rotation(sin,cos) + position(sin_x, cos_x, sin_y, cos_y) = memory_size + 7
-        let mut proprio_outputs = Array1::zeros(memory_size + 7);
+        // [+ velocity(forward, left, angular)] [+ health]
+        let motion_size = self.input_size(params) - params.memory_size;
+        let mut proprio_outputs = Array1::zeros(memory_size + motion_size);
 
         let mut idx = 0;
 
@@ -63,9 +71,15 @@ impl Sense for Proprioception {
         idx += 1;
 
         // Add positional encoding using sine and cosine
-        // Normalize position to [0, 2π] range for periodic encoding
-        let norm_x = (organism.pos[0] / params.box_width) * 2.0 * std::f32::consts::PI;
-        let norm_y = (organism.pos[1] / params.box_height) * 2.0 * std::f32::consts::PI;
+        // On a torus, normalize position to [0, 2π] for periodic encoding; in a
+        // bounded world use [0, π] so opposite edges do not look alike
+        let period = if params.boundary_mode.is_torus() {
+            2.0 * std::f32::consts::PI
+        } else {
+            std::f32::consts::PI
+        };
+        let norm_x = (organism.pos[0] / params.box_width) * period;
+        let norm_y = (organism.pos[1] / params.box_height) * period;
 
         proprio_outputs[idx] = norm_x.sin();
         idx += 1;
@@ -74,13 +88,59 @@ impl Sense for Proprioception {
         proprio_outputs[idx] = norm_y.sin();
         idx += 1;
         proprio_outputs[idx] = norm_y.cos();
+        idx += 1;
+
+        // Add own motion, rotated into the organism's frame: x forward, y to the left
+        if params.physics.enabled {
+            let [vx, vy] = organism.velocity;
+            let (sin, cos) = organism.rot.sin_cos();
+            let top_speed = params.move_multiplier.max(f32::EPSILON);
+            proprio_outputs[idx] = ((vx * cos + vy * sin) / top_speed).clamp(-1.0, 1.0);
+            proprio_outputs[idx + 1] = ((vy * cos - vx * sin) / top_speed).clamp(-1.0, 1.0);
+            proprio_outputs[idx + 2] = (organism.angular_velocity / 10.0).clamp(-1.0, 1.0);
+            idx += 3;
+        }
+
+        // Add health
+        if params.combat.enabled {
+            proprio_outputs[idx] = organism.health / params.combat.max_health.max(f32::EPSILON);
+        }
 
         proprio_outputs
     }
 
     fn input_size(&self, params: &Params) -> usize {
         // memory_size + energy + rotation(sin,cos) + position(sin_x, cos_x, sin_y, cos_y)
-        params.memory_size + 7
+        // [+ velocity(forward, left, angular) with physics] [+ health with combat]
+        params.memory_size
+            + 7
+            + if params.physics.enabled { 3 } else { 0 }
+            + usize::from(params.combat.enabled)
+    }
+
+    fn input_labels(&self, params: &Params) -> Vec<String> {
+        (0..params.memory_size)
+            .map(|i| format!("Mem {i}"))
+            .chain(
+                [
+                    "Energy",
+                    "Rot Sin",
+                    "Rot Cos",
+                    "Pos X Sin",
+                    "Pos X Cos",
+                    "Pos Y Sin",
+                    "Pos Y Cos",
+                ]
+                .map(String::from),
+            )
+            .chain(
+                ["Vel Fwd", "Vel Left", "Spin"]
+                    .map(String::from)
+                    .into_iter()
+                    .take(if params.physics.enabled { 3 } else { 0 }),
+            )
+            .chain(std::iter::once("Health".to_string()).take(usize::from(params.combat.enabled)))
+            .collect()
     }
 
     fn name(&self) -> &'static str {
@@ -5,8 +5,9 @@
 
 use ndarray::Array1;
 
-use super::super::ecosystem::{Ecosystem, SpatialTrees};
 use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
 use super::Organism;
 
 /// Trait for different sensory modalities that organisms can use to perceive their environment.
@@ -19,9 +20,9 @@ pub trait Sense: Sync {
     /// # Arguments
     ///
     /// * `organism` - The organism doing the sensing
-    /// * `ecosystem` - The current state of the ecosystem
+    /// * `world` - What the organism can perceive of the world this step
     /// * `params` - Simulation parameters
-    /// * `trees` - Optional pre-built KD-trees for efficient spatial queries (if None, builds internally)
+    /// * `neighbourhood` - Everything near the organism, gathered once per step
     ///
     /// # Returns
     ///
@@ -29,9 +30,9 @@ pub trait Sense: Sync {
     fn sense(
         &self,
         organism: &Organism,
-        ecosystem: &Ecosystem,
+        world: &WorldView,
         params: &Params,
-        trees: Option<&SpatialTrees>,
+        neighbourhood: &Neighbourhood,
     ) -> Array1<f32>;
 
     /// Returns the number of neural network inputs this sense produces.
@@ -41,6 +42,13 @@ pub trait Sense: Sync {
     /// The size of the output array from `sense()`.
     fn input_size(&self, params: &Params) -> usize;
 
+    /// Returns a short label for each input, in the order produced by `sense()`.
+    ///
+    /// # Returns
+    ///
+    /// One label per input (`input_size()` entries), used to annotate brain inputs in the UI.
+    fn input_labels(&self, params: &Params) -> Vec<String>;
+
     /// Returns a human-readable name for this sense.
     fn name(&self) -> &str;
 }
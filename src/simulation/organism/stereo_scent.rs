@@ -0,0 +1,116 @@
+//! Stereo scent sense - lets organisms smell where things are.
+//!
+//! Like [`Scent`](super::Scent), but splits the surroundings into sectors
+//! around the heading and reports each sector separately, so organisms can
+//! climb scent gradients.
+
+use ndarray::Array1;
+
+use super::super::boundary;
+use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
+use super::Organism;
+use super::sense::Sense;
+
+/// Directional scent sense.
+///
+/// The full circle around the organism is split into `sectors` equal arcs,
+/// counter-clockwise from the heading, so with two sectors the first is on
+/// the left and the second on the right.
+///
+/// Outputs per sector:
+/// - Signal channels from nearby organisms in that sector
+/// - Food intensity in that sector
+///
+/// Strength falls off linearly with distance, as in [`Scent`](super::Scent).
+pub struct StereoScent {
+    /// Number of sectors around the heading (2 = left and right).
+    sectors: usize,
+}
+
+impl StereoScent {
+    /// Creates a new stereo scent sense with `sectors` sectors.
+    pub fn new(sectors: usize) -> Self {
+        Self { sectors }
+    }
+
+    /// Sector containing a point at `offset` from an organism facing `rot`.
+    pub fn sector(offset: &Array1<f32>, rot: f32, sectors: usize) -> usize {
+        let bearing = (offset[1].atan2(offset[0]) - rot).rem_euclid(std::f32::consts::TAU);
+        let width = std::f32::consts::TAU / sectors as f32;
+        ((bearing / width) as usize).min(sectors - 1)
+    }
+}
+
+impl Default for StereoScent {
+    /// Left and right.
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl Sense for StereoScent {
+    fn sense(
+        &self,
+        organism: &Organism,
+        world: &WorldView,
+        params: &Params,
+        neighbourhood: &Neighbourhood,
+    ) -> Array1<f32> {
+        let mut outputs = Array1::zeros(self.input_size(params));
+        if outputs.is_empty() {
+            return outputs;
+        }
+        let sectors = self.sectors;
+        let stride = params.signal_size + 1;
+        let scent_radius = organism.morphology.scent_radius(params);
+
+        let scent_orgs = neighbourhood.organisms(scent_radius);
+        let scent_foods = neighbourhood.food(scent_radius);
+
+        for (_, org_id) in scent_orgs {
+            let neighbor_org = &world.organisms[*org_id];
+            if neighbor_org.id == organism.id {
+                continue; // Skip self
+            }
+            let offset = boundary::displacement(&organism.pos, &neighbor_org.pos, params);
+            let dist = offset.mapv(|x| x.powi(2)).sum().sqrt();
+            let distance_factor = (1.0 - (dist / scent_radius)).max(0.0);
+            let start = Self::sector(&offset, organism.rot, sectors) * stride;
+            for i in 0..params.signal_size {
+                outputs[start + i] += neighbor_org.signal[i] * distance_factor;
+            }
+        }
+
+        for (_, food_id) in scent_foods {
+            let food_item = &world.food[*food_id];
+            let offset = boundary::displacement(&organism.pos, &food_item.pos, params);
+            let dist = offset.mapv(|x| x.powi(2)).sum().sqrt();
+            let distance_factor = (1.0 - (dist / scent_radius)).max(0.0);
+            let sector = Self::sector(&offset, organism.rot, sectors);
+            outputs[sector * stride + params.signal_size] += distance_factor;
+        }
+
+        outputs
+    }
+
+    fn input_size(&self, params: &Params) -> usize {
+        // (signal_size + food) per sector
+        self.sectors * (params.signal_size + 1)
+    }
+
+    fn input_labels(&self, params: &Params) -> Vec<String> {
+        (0..self.sectors)
+            .flat_map(|sector| {
+                (0..params.signal_size)
+                    .map(move |channel| format!("SS{sector} S{channel}"))
+                    .chain(std::iter::once(format!("SS{sector} Food")))
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "StereoScent"
+    }
+}
@@ -0,0 +1,135 @@
+//! Touch sense - tells organisms what happened to their body.
+//!
+//! Reports damage (and where it came from), energy received from others,
+//! food eaten or lost to a competitor, and contact with other bodies. Events
+//! are recorded into [`TouchState`] when they are applied and read on the
+//! next step; bodily contact is recorded as it is detected.
+
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+
+use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
+use super::Organism;
+use super::sense::Sense;
+
+/// What an organism felt since it last perceived.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TouchState {
+    /// Damage taken.
+    pub damage: f32,
+    /// Sum of unit vectors (world frame) pointing towards where damage came
+    /// from, weighted by damage.
+    pub damage_direction: [f32; 2],
+    /// Energy received from other organisms.
+    pub energy_received: f32,
+    /// Whether the organism ate food.
+    pub ate_food: bool,
+    /// Whether food it reached for was eaten by another organism.
+    pub lost_food: bool,
+    /// Number of bodies (organisms or obstacles) it touched.
+    pub contacts: usize,
+}
+
+impl TouchState {
+    /// Forgets everything felt so far.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Records `damage` arriving from direction `from` (need not be normalised).
+    pub fn record_damage(&mut self, damage: f32, from: &Array1<f32>) {
+        self.damage += damage;
+        let length = from.mapv(|x| x.powi(2)).sum().sqrt();
+        if length > f32::EPSILON {
+            self.damage_direction[0] += from[0] / length * damage;
+            self.damage_direction[1] += from[1] / length * damage;
+        }
+    }
+}
+
+/// Touch sense reading the organism's [`TouchState`].
+///
+/// Outputs:
+/// - Damage taken, as a fraction of `max_energy`
+/// - Damage direction relative to heading (forward and left components of a
+///   unit vector; zero without damage)
+/// - Energy received, as a fraction of `max_energy`
+/// - Ate food (0 or 1)
+/// - Lost food to a competitor (0 or 1)
+/// - Contact, squashed to [0, 1) by the number of bodies touched
+pub struct Touch;
+
+impl Touch {
+    /// Creates a new touch sense.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Touch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sense for Touch {
+    fn sense(
+        &self,
+        organism: &Organism,
+        _world: &WorldView,
+        params: &Params,
+        _neighbourhood: &Neighbourhood,
+    ) -> Array1<f32> {
+        let touch = &organism.touch;
+        let max_energy = params.max_energy.max(f32::EPSILON);
+
+        let [dx, dy] = touch.damage_direction;
+        let magnitude = (dx * dx + dy * dy).sqrt();
+        let (forward, left) = if magnitude > f32::EPSILON {
+            // Rotate into the organism's frame: x forward, y to the left
+            let (sin, cos) = organism.rot.sin_cos();
+            (
+                (dx * cos + dy * sin) / magnitude,
+                (dy * cos - dx * sin) / magnitude,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let contacts = touch.contacts as f32;
+
+        Array1::from_vec(vec![
+            (touch.damage / max_energy).min(1.0),
+            forward,
+            left,
+            (touch.energy_received / max_energy).min(1.0),
+            f32::from(u8::from(touch.ate_food)),
+            f32::from(u8::from(touch.lost_food)),
+            contacts / (contacts + 1.0),
+        ])
+    }
+
+    fn input_size(&self, _params: &Params) -> usize {
+        // damage + direction(forward, left) + energy received + ate + lost + contact
+        7
+    }
+
+    fn input_labels(&self, _params: &Params) -> Vec<String> {
+        [
+            "Damage",
+            "Dmg Fwd",
+            "Dmg Left",
+            "Received",
+            "Ate",
+            "Lost Food",
+            "Contact",
+        ]
+        .map(String::from)
+        .to_vec()
+    }
+
+    fn name(&self) -> &'static str {
+        "Touch"
+    }
+}
@@ -4,8 +4,12 @@
 
 use ndarray::Array1;
 
-use super::super::ecosystem::Ecosystem;
+use super::super::boundary;
+use super::super::environment;
+use super::super::obstacle;
 use super::super::params::Params;
+use super::super::spatial::Neighbourhood;
+use super::super::view::WorldView;
 use super::Organism;
 use super::sense::Sense;
 
@@ -14,7 +18,11 @@ use super::sense::Sense;
 /// For each vision direction, the sense outputs:
 /// - Proximity to nearest entity (inverted distance: 1.0 = very close, 0.0 = far)
 /// - Whether the entity is in the same genetic pool (1.0) or not (0.0)
-/// - Whether the entity is an organism (1.0) or food (0.0)
+/// - Entity type: 1.0 for organisms, -1.0 for projectiles, and the kind's
+///   `vision_code` for food (plants 0.0 by default)
+/// - With obstacles enabled, whether the ray hit a wall (1.0) or not (0.0)
+///
+/// Obstacles block rays, hiding anything behind them.
 pub struct Vision;
 
 impl Vision {
@@ -22,6 +30,12 @@ impl Vision {
     pub fn new() -> Self {
         Self
     }
+
+    /// Number of outputs per vision direction (3, plus the wall channel when
+    /// obstacles are enabled).
+    pub fn channels_per_direction(params: &Params) -> usize {
+        if params.obstacles.enabled { 4 } else { 3 }
+    }
 }
 
 impl Default for Vision {
@@ -34,130 +48,62 @@ impl Sense for Vision {
     fn sense(
         &self,
         organism: &Organism,
-        ecosystem: &Ecosystem,
+        world: &WorldView,
         params: &Params,
-        trees: Option<&super::super::ecosystem::SpatialTrees>,
+        neighbourhood: &Neighbourhood,
     ) -> Array1<f32> {
         use super::super::geometric_utils::line_circle_distance;
-        use kdtree::KdTree;
-        use kdtree::distance::squared_euclidean;
 
         let num_directions = params.num_vision_directions;
-        let mut vision_outputs = Array1::zeros(num_directions * 3);
-
-        // Get vision vectors
-        let vision_vectors = organism.get_vision_vectors();
-
-        // Use provided trees or build them
-        let (neighbors_orgs, neighbor_foods, neighbor_projectiles) =
-            if let Some(spatial_trees) = trees {
-                // Use pre-built trees (efficient path) and collect to owned
-                let temp_orgs = spatial_trees
-                    .organisms
-                    .within(
-                        &organism.pos.to_vec(),
-                        params.vision_radius.powi(2),
-                        &squared_euclidean,
-                    )
-                    .unwrap_or_default();
-                let neighbors_orgs: Vec<(f32, usize)> =
-                    temp_orgs.iter().map(|(d, idx)| (*d, **idx)).collect();
-
-                let temp_foods = spatial_trees
-                    .food
-                    .within(
-                        &organism.pos.to_vec(),
-                        params.vision_radius.powi(2),
-                        &squared_euclidean,
-                    )
-                    .unwrap_or_default();
-                let neighbor_foods: Vec<(f32, usize)> =
-                    temp_foods.iter().map(|(d, idx)| (*d, **idx)).collect();
-
-                let temp_projectiles = spatial_trees
-                    .projectiles
-                    .within(
-                        &organism.pos.to_vec(),
-                        params.vision_radius.powi(2),
-                        &squared_euclidean,
-                    )
-                    .unwrap_or_default();
-                let neighbor_projectiles: Vec<(f32, usize)> = temp_projectiles
-                    .iter()
-                    .map(|(d, idx)| (*d, **idx))
-                    .collect();
-
-                (neighbors_orgs, neighbor_foods, neighbor_projectiles)
-            } else {
-                // Build trees on demand (for testing) and collect results immediately
-                let mut kd_tree_orgs = KdTree::with_capacity(2, ecosystem.organisms.len());
-                for (i, org) in ecosystem.organisms.iter().enumerate() {
-                    let _ = kd_tree_orgs.add(org.pos.to_vec(), i);
-                }
-
-                let mut kd_tree_food = KdTree::with_capacity(2, ecosystem.food.len());
-                for (i, food) in ecosystem.food.iter().enumerate() {
-                    let _ = kd_tree_food.add(food.pos.to_vec(), i);
-                }
-
-                let mut kd_tree_projectiles = KdTree::with_capacity(2, ecosystem.projectiles.len());
-                for (i, proj) in ecosystem.projectiles.iter().enumerate() {
-                    let _ = kd_tree_projectiles.add(proj.pos.to_vec(), i);
-                }
-
-                // Collect owned indices to match the type from pre-built trees
-                let temp_orgs = kd_tree_orgs
-                    .within(
-                        &organism.pos.to_vec(),
-                        params.vision_radius.powi(2),
-                        &squared_euclidean,
-                    )
-                    .unwrap_or_default();
-                let neighbors_orgs: Vec<(f32, usize)> =
-                    temp_orgs.iter().map(|(d, idx)| (*d, **idx)).collect();
-
-                let temp_foods = kd_tree_food
-                    .within(
-                        &organism.pos.to_vec(),
-                        params.vision_radius.powi(2),
-                        &squared_euclidean,
-                    )
-                    .unwrap_or_default();
-                let neighbor_foods: Vec<(f32, usize)> =
-                    temp_foods.iter().map(|(d, idx)| (*d, **idx)).collect();
-
-                let temp_projectiles = kd_tree_projectiles
-                    .within(
-                        &organism.pos.to_vec(),
-                        params.vision_radius.powi(2),
-                        &squared_euclidean,
-                    )
-                    .unwrap_or_default();
-                let neighbor_projectiles: Vec<(f32, usize)> = temp_projectiles
-                    .iter()
-                    .map(|(d, idx)| (*d, **idx))
-                    .collect();
-
-                (neighbors_orgs, neighbor_foods, neighbor_projectiles)
-            };
+        let stride = Self::channels_per_direction(params);
+        let mut vision_outputs = Array1::zeros(num_directions * stride);
+
+        // Get vision vectors; evolved rays may reach past the default vision radius,
+        // and all rays shorten at night
+        let vision_scale = environment::conditions(world.time, params).vision_scale;
+        let vision_vectors: Vec<Array1<f32>> = organism
+            .get_vision_vectors()
+            .into_iter()
+            .map(|vector| vector * vision_scale)
+            .collect();
+        let vision_range = organism
+            .vision_lengths
+            .iter()
+            .copied()
+            .fold(params.vision_radius, f32::max)
+            * vision_scale;
+
+        // The neighbourhood also sees across the seam of a torus
+        let neighbors_orgs = neighbourhood.organisms(vision_range);
+        let neighbor_foods = neighbourhood.food(vision_range);
+        let neighbor_projectiles = neighbourhood.projectiles(vision_range);
 
         // Raycast for each vision direction
         for (i, vision_vector) in vision_vectors.iter().enumerate() {
             let end_point = &organism.pos + vision_vector;
             let mut min_distance = f32::MAX;
 
+            // Anything further away than the first wall is hidden behind it
+            let wall_distance = obstacle::ray_distance(world.obstacles, &organism.pos, &end_point);
+            let occluded = |pos: &Array1<f32>| {
+                wall_distance
+                    .is_some_and(|wall| boundary::distance(&organism.pos, pos, params) > wall)
+            };
+
             // Check organisms
-            for (_, neighbor_id) in &neighbors_orgs {
-                let neighbor_org = &ecosystem.organisms[*neighbor_id];
+            for (_, neighbor_id) in neighbors_orgs {
+                let neighbor_org = &world.organisms[*neighbor_id];
 
-                if neighbor_org.id == organism.id {
-                    continue; // skip self
+                if neighbor_org.id == organism.id || occluded(&neighbor_org.pos) {
+                    continue; // skip self and hidden organisms
                 }
 
-                let distance = line_circle_distance(&organism.pos, &end_point, &neighbor_org.pos);
-                if distance < params.body_radius && distance < min_distance {
+                let neighbor_pos =
+                    boundary::nearest_image(&organism.pos, &neighbor_org.pos, params);
+                let distance = line_circle_distance(&organism.pos, &end_point, &neighbor_pos);
+                if distance < neighbor_org.body_radius && distance < min_distance {
                     min_distance = distance;
-                    let base_idx = 3 * i;
+                    let base_idx = stride * i;
                     // Invert distance: closer = higher value
                     // Use vision_radius as max distance for normalization
                     let proximity = 1.0 - (distance / params.vision_radius).min(1.0);
@@ -174,34 +120,43 @@ impl Sense for Vision {
             }
 
             // Check food
-            for (_, food_id) in &neighbor_foods {
-                let food_item = &ecosystem.food[*food_id];
-                let distance = line_circle_distance(&organism.pos, &end_point, &food_item.pos);
+            for (_, food_id) in neighbor_foods {
+                let food_item = &world.food[*food_id];
+                if occluded(&food_item.pos) {
+                    continue;
+                }
+                let food_pos = boundary::nearest_image(&organism.pos, &food_item.pos, params);
+                let distance = line_circle_distance(&organism.pos, &end_point, &food_pos);
                 if distance < params.body_radius && distance < min_distance {
                     min_distance = distance;
-                    let base_idx = 3 * i;
+                    let base_idx = stride * i;
                     // Invert distance: closer = higher value
                     let proximity = 1.0 - (distance / params.vision_radius).min(1.0);
                     vision_outputs[base_idx] = proximity;
                     vision_outputs[base_idx + 1] = 0.0; // no pool match for food
-                    vision_outputs[base_idx + 2] = 0.0; // is_organism = 0 for food
+                    // Food kinds are told apart by their vision code
+                    vision_outputs[base_idx + 2] = food_item
+                        .kind
+                        .spec(&params.food_kinds)
+                        .map_or(0.0, |spec| spec.vision_code);
                 }
             }
 
             // Check projectiles
-            for (_, projectile_id) in &neighbor_projectiles {
-                let projectile_item = &ecosystem.projectiles[*projectile_id];
+            for (_, projectile_id) in neighbor_projectiles {
+                let projectile_item = &world.projectiles[*projectile_id];
 
                 // Skip projectiles owned by this organism
-                if projectile_item.owner_id == organism.id {
+                if projectile_item.owner_id == organism.id || occluded(&projectile_item.pos) {
                     continue;
                 }
 
-                let distance =
-                    line_circle_distance(&organism.pos, &end_point, &projectile_item.pos);
+                let projectile_pos =
+                    boundary::nearest_image(&organism.pos, &projectile_item.pos, params);
+                let distance = line_circle_distance(&organism.pos, &end_point, &projectile_pos);
                 if distance < params.projectile_radius && distance < min_distance {
                     min_distance = distance;
-                    let base_idx = 3 * i;
+                    let base_idx = stride * i;
                     // Invert distance: closer = higher value
                     let proximity = 1.0 - (distance / params.vision_radius).min(1.0);
                     vision_outputs[base_idx] = proximity;
@@ -209,14 +164,31 @@ impl Sense for Vision {
                     vision_outputs[base_idx + 2] = -1.0; // special marker for projectiles
                 }
             }
+
+            // A wall is reported only if no visible entity was found in front of it
+            if let Some(wall) = wall_distance
+                && min_distance >= f32::MAX
+            {
+                let base_idx = stride * i;
+                vision_outputs[base_idx] = 1.0 - (wall / params.vision_radius).min(1.0);
+                vision_outputs[base_idx + 3] = 1.0;
+            }
         }
 
         vision_outputs
     }
 
     fn input_size(&self, params: &Params) -> usize {
-        // 3 outputs per direction: proximity (inverted distance), pool_match, is_organism
-        params.num_vision_directions * 3
+        // Per direction: proximity (inverted distance), pool_match, entity type, [is_wall]
+        params.num_vision_directions * Self::channels_per_direction(params)
+    }
+
+    fn input_labels(&self, params: &Params) -> Vec<String> {
+        // Distance, pool match, type (organism vs food), wall
+        let channels = &["D", "P", "T", "W"][..Self::channels_per_direction(params)];
+        (0..params.num_vision_directions)
+            .flat_map(|direction| channels.iter().map(move |c| format!("V{direction} {c}")))
+            .collect()
     }
 
     fn name(&self) -> &'static str {
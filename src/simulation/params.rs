@@ -1,6 +1,28 @@
+use std::borrow::Cow;
+
 use serde::{Deserialize, Serialize};
 
+use super::actions::ActionKind;
+use super::boundary::BoundaryMode;
 use super::brain;
+use super::collision::CollisionParams;
+use super::combat::CombatParams;
+use super::conflict::ConflictParams;
+use super::diet::DietParams;
+use super::energy::EnergyLedgerParams;
+use super::environment::EnvironmentParams;
+use super::food::FoodKindSpec;
+use super::mate_choice::MateChoiceParams;
+use super::migration::MigrationParams;
+use super::morphology::MorphologyParams;
+use super::novelty::NoveltyParams;
+use super::obstacle::ObstacleParams;
+use super::organism::SenseKind;
+use super::pheromone::PheromoneParams;
+use super::physics::PhysicsParams;
+use super::projectile::ProjectileParams;
+use super::reproduction::ReproductionMode;
+use super::resource_field::ResourceFieldParams;
 
 /// Simulation parameters that control ecosystem behavior.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +43,9 @@ pub struct Params {
     pub dna_breeding_distance: f32,
     /// DNA mutation rate (standard deviation of Gaussian noise).
     pub dna_mutation_rate: f32,
+    /// Brain weight mutation scale for cloned and asexual offspring. Offspring
+    /// bred from the graveyard sample a scale between 1/500 and twice this.
+    pub brain_mutation_rate: f32,
     /// Energy consumed per second while idle.
     pub idle_energy_rate: f32,
     /// Energy cost per unit of movement.
@@ -49,6 +74,10 @@ pub struct Params {
     pub box_width: f32,
     /// Simulation area height.
     pub box_height: f32,
+    /// What happens at the edges of the world.
+    pub boundary_mode: BoundaryMode,
+    /// Energy per second lost while touching a hard wall ([`BoundaryMode::Walls`]).
+    pub boundary_contact_cost: f32,
     /// Neural network layer dimensions.
     pub layer_sizes: Vec<usize>,
     /// Energy cost multiplier for attacks.
@@ -57,12 +86,8 @@ pub struct Params {
     pub attack_damage_rate: f32,
     /// Cooldown duration between attacks (seconds).
     pub attack_cooldown: f32,
-    /// Fraction of organism energy converted to corpse food.
-    pub corpse_energy_ratio: f32,
     /// Maximum energy an organism can have.
     pub max_energy: f32,
-    /// Energy value of spawned food items.
-    pub food_energy: f32,
     /// Projectile travel speed.
     pub projectile_speed: f32,
     /// Maximum projectile travel distance.
@@ -73,8 +98,9 @@ pub struct Params {
     pub organism_spawn_rate: f32,
     /// Food items spawned per second when below target count.
     pub food_spawn_rate: f32,
-    /// Maximum lifetime of food in seconds
-    pub food_lifetime: f32,
+    /// Food kinds: plants (0), carrion (1), then user-defined kinds.
+    /// Sets each kind's energy, lifetime, spawn share and appearance.
+    pub food_kinds: Vec<FoodKindSpec>,
     /// Number of genetic pools (isolated breeding populations).
     /// Organisms can only breed within their pool. Range: 1-10.
     pub num_genetic_pools: usize,
@@ -100,6 +126,12 @@ pub struct Params {
     /// Energy multiplier for offspring (offspring gets `parent_energy` * this factor).
     /// Default: 1.2 (20% bonus). Range: 0.5-3.0.
     pub reproduction_energy_multiplier: f32,
+    /// Which mechanisms create new organisms (automatic spawning, organism-initiated, or both).
+    pub reproduction_mode: ReproductionMode,
+    /// Cooldown after an organism-initiated reproduction before the parent can reproduce again (seconds).
+    pub reproduction_cooldown: f32,
+    /// Fixed energy each parent loses per organism-initiated birth, on top of its contribution.
+    pub reproduction_energy_cost: f32,
     /// If true, spawn organisms from graveyard (evolution). If false, clone from living organisms.
     /// Default: true (evolution-based spawning).
     pub spawn_from_graveyard: bool,
@@ -109,4 +141,136 @@ pub struct Params {
     /// Number of organisms to seed into an empty pool from a non-empty pool.
     /// Default: 5. Range: 1-20.
     pub empty_pool_seed_count: usize,
+    /// Novelty search settings (behaviour archive and parent selection mode).
+    pub novelty: NoveltyParams,
+    /// Mate choice settings (preference-based partner acceptance).
+    pub mate_choice: MateChoiceParams,
+    /// Island model settings (migration topology, rate and policy between pools).
+    pub migration: MigrationParams,
+    /// Morphology evolution settings (heritable body, speed and sensor traits).
+    pub morphology: MorphologyParams,
+    /// Diet evolution settings (per-kind digestion efficiency).
+    pub diet: DietParams,
+    /// Fertility grid controlling where and how fast food grows.
+    pub resource_field: ResourceFieldParams,
+    /// Walls and rocks placed in the world.
+    pub obstacles: ObstacleParams,
+    /// Day/night and seasonal cycles.
+    pub environment: EnvironmentParams,
+    /// Pheromone trail field.
+    pub pheromones: PheromoneParams,
+    /// Inertial movement physics.
+    pub physics: PhysicsParams,
+    /// Response to contact between organisms.
+    pub collision: CollisionParams,
+    /// Health, armour and melee combat.
+    pub combat: CombatParams,
+    /// Optional projectile interactions with food and other projectiles.
+    pub projectiles: ProjectileParams,
+    /// Energy accounting checks.
+    pub energy_ledger: EnergyLedgerParams,
+    /// Resolution of contested food, targets and share receivers.
+    pub conflicts: ConflictParams,
+    /// Parameters each genetic pool overrides, indexed by pool id (missing = none).
+    #[serde(default)]
+    pub pool_overrides: Vec<PoolOverrides>,
+    /// Senses organisms perceive with, in brain input order.
+    pub senses: Vec<SenseKind>,
+    /// Actions organisms can take, in brain output order.
+    pub actions: Vec<ActionKind>,
+}
+
+impl Params {
+    /// Parameters seen by organisms of `pool_id`: the global ones with that
+    /// pool's overrides applied.
+    pub fn for_pool(&self, pool_id: usize) -> Cow<'_, Params> {
+        match self.pool_overrides.get(pool_id) {
+            Some(overrides) if !overrides.is_empty() => {
+                let mut params = self.clone();
+                overrides.apply(&mut params);
+                Cow::Owned(params)
+            }
+            _ => Cow::Borrowed(self),
+        }
+    }
+
+    /// [`Params::for_pool`] for every pool, indexed by pool id.
+    pub fn per_pool(&self) -> Vec<Cow<'_, Params>> {
+        (0..self.num_genetic_pools.max(self.pool_overrides.len()))
+            .map(|pool_id| self.for_pool(pool_id))
+            .collect()
+    }
+
+    /// Gives the pools the brain architectures in `brain_types` in turn
+    /// (pool 0 the first, pool 1 the second, ...), so they compete in one
+    /// world.
+    pub fn mix_brain_types(&mut self, brain_types: &[brain::BrainType]) {
+        if brain_types.is_empty() {
+            return;
+        }
+        let pools = self.num_genetic_pools.max(1);
+        if self.pool_overrides.len() < pools {
+            self.pool_overrides.resize(pools, PoolOverrides::default());
+        }
+        for (pool_id, overrides) in self.pool_overrides.iter_mut().enumerate() {
+            overrides.brain_type = Some(brain_types[pool_id % brain_types.len()]);
+        }
+    }
+}
+
+/// Parameters a genetic pool can override, for predator-prey and competition
+/// experiments. `None` keeps the global value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PoolOverrides {
+    /// Brain architecture.
+    pub brain_type: Option<brain::BrainType>,
+    /// Hidden layer sizes (input and output sizes follow the global senses and actions).
+    pub hidden_layers: Option<Vec<usize>>,
+    /// Movement speed multiplier.
+    pub move_multiplier: Option<f32>,
+    /// Energy cost per unit of attack strength.
+    pub attack_cost_rate: Option<f32>,
+    /// Damage per unit of attack strength.
+    pub attack_damage_rate: Option<f32>,
+    /// Maximum distance organisms can see.
+    pub vision_radius: Option<f32>,
+    /// DNA mutation rate.
+    pub dna_mutation_rate: Option<f32>,
+    /// Brain weight mutation rate.
+    pub brain_mutation_rate: Option<f32>,
+}
+
+impl PoolOverrides {
+    /// Returns true if nothing is overridden.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Writes the overridden values into `params`.
+    pub fn apply(&self, params: &mut Params) {
+        if let Some(brain_type) = self.brain_type {
+            params.brain_type = brain_type;
+        }
+        if let Some(hidden) = &self.hidden_layers {
+            let input = params.layer_sizes.first().copied().unwrap_or(0);
+            let output = params.layer_sizes.last().copied().unwrap_or(0);
+            params.layer_sizes = std::iter::once(input)
+                .chain(hidden.iter().copied())
+                .chain(std::iter::once(output))
+                .collect();
+        }
+        let values = [
+            (self.move_multiplier, &mut params.move_multiplier),
+            (self.attack_cost_rate, &mut params.attack_cost_rate),
+            (self.attack_damage_rate, &mut params.attack_damage_rate),
+            (self.vision_radius, &mut params.vision_radius),
+            (self.dna_mutation_rate, &mut params.dna_mutation_rate),
+            (self.brain_mutation_rate, &mut params.brain_mutation_rate),
+        ];
+        for (value, field) in values {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+    }
 }
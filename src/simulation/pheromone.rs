@@ -0,0 +1,228 @@
+//! Pheromone trails that organisms write and read.
+//!
+//! The world is covered by a grid of cells, each holding one concentration
+//! per pheromone channel. Organisms deposit pheromone through dedicated brain
+//! outputs, paying energy for what they release. Every step the field
+//! evaporates and diffuses to neighbouring cells, so trails fade unless they
+//! are refreshed. The [`Pheromones`](super::organism::Pheromones) sense reads
+//! the local concentration and gradient of every channel.
+
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+
+use super::params::Params;
+
+/// Pheromone field configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PheromoneParams {
+    /// If false, there is no field, no deposit outputs and no pheromone sense.
+    pub enabled: bool,
+    /// Number of independent pheromone channels.
+    pub channels: usize,
+    /// Side length of a grid cell in world units.
+    pub cell_size: f32,
+    /// Concentration deposited per second at full output.
+    pub deposit_rate: f32,
+    /// Energy spent per unit of concentration deposited.
+    pub deposit_energy_cost: f32,
+    /// Fraction of the concentration that evaporates per second.
+    pub evaporation_rate: f32,
+    /// Fraction of the concentration difference exchanged with each neighbour per second.
+    pub diffusion_rate: f32,
+    /// Concentration at which the sense reads 0.5.
+    pub saturation: f32,
+}
+
+impl Default for PheromoneParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            channels: 2,
+            cell_size: 20.0,
+            deposit_rate: 1.0,
+            deposit_energy_cost: 0.01,
+            evaporation_rate: 0.1,
+            diffusion_rate: 0.1,
+            saturation: 1.0,
+        }
+    }
+}
+
+/// Number of deposit outputs the brain needs (one per channel when enabled).
+pub fn output_size(params: &Params) -> usize {
+    if params.pheromones.enabled {
+        params.pheromones.channels
+    } else {
+        0
+    }
+}
+
+/// Multi-channel concentration grid over the world.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PheromoneField {
+    /// Number of cell columns.
+    pub cols: usize,
+    /// Number of cell rows.
+    pub rows: usize,
+    /// Side length of a cell in world units.
+    pub cell_size: f32,
+    /// Number of channels per cell.
+    pub channels: usize,
+    /// Concentrations, cell-major (row-major cells, then channel).
+    pub concentration: Vec<f32>,
+}
+
+impl PheromoneField {
+    /// Builds an empty field for the current world size.
+    pub fn new(params: &Params) -> Self {
+        let cell_size = params.pheromones.cell_size.max(1.0);
+        let cols = (params.box_width / cell_size).ceil().max(1.0) as usize;
+        let rows = (params.box_height / cell_size).ceil().max(1.0) as usize;
+        let channels = params.pheromones.channels;
+        Self {
+            cols,
+            rows,
+            cell_size,
+            channels,
+            concentration: vec![0.0; cols * rows * channels],
+        }
+    }
+
+    /// Returns `true` if the grid matches the current world, cell size and channels.
+    pub fn fits(&self, params: &Params) -> bool {
+        let cell_size = params.pheromones.cell_size.max(1.0);
+        (self.cell_size - cell_size).abs() < f32::EPSILON
+            && self.channels == params.pheromones.channels
+            && self.cols == (params.box_width / cell_size).ceil().max(1.0) as usize
+            && self.rows == (params.box_height / cell_size).ceil().max(1.0) as usize
+            && self.concentration.len() == self.cols * self.rows * self.channels
+    }
+
+    /// Column and row of the cell containing `pos`, clamped to the grid.
+    pub fn cell(&self, pos: &Array1<f32>) -> (usize, usize) {
+        let col = ((pos[0] / self.cell_size).max(0.0) as usize).min(self.cols - 1);
+        let row = ((pos[1] / self.cell_size).max(0.0) as usize).min(self.rows - 1);
+        (col, row)
+    }
+
+    /// Concentration of `channel` in the cell containing `pos`.
+    pub fn concentration_at(&self, pos: &Array1<f32>, channel: usize) -> f32 {
+        if channel >= self.channels || self.concentration.is_empty() {
+            return 0.0;
+        }
+        let (col, row) = self.cell(pos);
+        self.value(col, row, channel)
+    }
+
+    /// Concentration gradient of `channel` at `pos`, in concentration per world unit.
+    ///
+    /// Uses central differences between neighbouring cells; on a torus the
+    /// neighbours wrap around, otherwise the edge cell stands in for its
+    /// missing neighbour.
+    pub fn gradient(&self, pos: &Array1<f32>, channel: usize, params: &Params) -> [f32; 2] {
+        if channel >= self.channels || self.concentration.is_empty() {
+            return [0.0, 0.0];
+        }
+        let (col, row) = self.cell(pos);
+        let wrap = params.boundary_mode.is_torus();
+        let (left, right) = neighbours(col, self.cols, wrap);
+        let (up, down) = neighbours(row, self.rows, wrap);
+        let dx = (self.value(right, row, channel) - self.value(left, row, channel))
+            / (2.0 * self.cell_size);
+        let dy = (self.value(col, down, channel) - self.value(col, up, channel))
+            / (2.0 * self.cell_size);
+        [dx, dy]
+    }
+
+    /// Adds `amount` of `channel` to the cell containing `pos`.
+    pub fn deposit(&mut self, pos: &Array1<f32>, channel: usize, amount: f32) {
+        if channel >= self.channels || self.concentration.is_empty() {
+            return;
+        }
+        let (col, row) = self.cell(pos);
+        let index = (row * self.cols + col) * self.channels + channel;
+        self.concentration[index] += amount;
+    }
+
+    /// Total concentration of `channel` over the whole field.
+    pub fn total(&self, channel: usize) -> f32 {
+        if channel >= self.channels {
+            return 0.0;
+        }
+        self.concentration
+            .iter()
+            .skip(channel)
+            .step_by(self.channels)
+            .sum()
+    }
+
+    /// Advances evaporation and diffusion by `dt` seconds.
+    ///
+    /// Rebuilds the grid first if it is missing or the world size changed.
+    pub fn update(&mut self, params: &Params, dt: f32) {
+        if !params.pheromones.enabled {
+            return;
+        }
+        if !self.fits(params) {
+            *self = Self::new(params);
+        }
+        let decay = (-params.pheromones.evaporation_rate.max(0.0) * dt).exp();
+        for value in &mut self.concentration {
+            *value *= decay;
+        }
+        self.diffuse(params, dt);
+    }
+
+    fn value(&self, col: usize, row: usize, channel: usize) -> f32 {
+        self.concentration[(row * self.cols + col) * self.channels + channel]
+    }
+
+    fn diffuse(&mut self, params: &Params, dt: f32) {
+        // Explicit scheme, clamped to stay stable
+        let rate = (params.pheromones.diffusion_rate * dt).clamp(0.0, 0.25);
+        if rate <= 0.0 {
+            return;
+        }
+        let wrap = params.boundary_mode.is_torus();
+        let old = self.concentration.clone();
+        let at = |col: usize, row: usize, channel: usize| {
+            old[(row * self.cols + col) * self.channels + channel]
+        };
+        let mut next = vec![0.0; old.len()];
+        for row in 0..self.rows {
+            let (up, down) = neighbours(row, self.rows, wrap);
+            for col in 0..self.cols {
+                let (left, right) = neighbours(col, self.cols, wrap);
+                for channel in 0..self.channels {
+                    let here = at(col, row, channel);
+                    let sum = at(left, row, channel)
+                        + at(right, row, channel)
+                        + at(col, up, channel)
+                        + at(col, down, channel);
+                    next[(row * self.cols + col) * self.channels + channel] =
+                        here + rate * (sum - 4.0 * here);
+                }
+            }
+        }
+        self.concentration = next;
+    }
+}
+
+/// Previous and next index along one grid axis; edges wrap or repeat themselves.
+fn neighbours(index: usize, len: usize, wrap: bool) -> (usize, usize) {
+    let prev = if index > 0 {
+        index - 1
+    } else if wrap {
+        len - 1
+    } else {
+        index
+    };
+    let next = if index + 1 < len {
+        index + 1
+    } else if wrap {
+        0
+    } else {
+        index
+    };
+    (prev, next)
+}
@@ -0,0 +1,135 @@
+//! Inertial movement physics.
+//!
+//! Without physics, the movement and rotation outputs set speed and turn rate
+//! directly. With physics enabled they become thrust and torque: organisms
+//! accelerate according to their mass, keep their momentum between steps and
+//! slow down through drag. Either way, momentum picked up from collisions is
+//! carried in [`Organism::velocity`] and integrated here.
+
+use serde::{Deserialize, Serialize};
+
+use super::energy::Flow;
+use super::organism::Organism;
+use super::params::Params;
+
+/// How an organism's mass is determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MassMode {
+    /// Every organism has unit mass.
+    Fixed,
+    /// Mass grows with body area (1 for a default body).
+    #[default]
+    Body,
+    /// Body area plus `energy_mass` per unit of stored energy.
+    BodyAndEnergy,
+}
+
+/// Inertial physics configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhysicsParams {
+    /// If false, movement is kinematic and only collisions carry momentum.
+    pub enabled: bool,
+    /// How mass is determined (also weighs bodies against each other in collisions).
+    pub mass_mode: MassMode,
+    /// Mass added per unit of energy (`BodyAndEnergy`).
+    pub energy_mass: f32,
+    /// Force at full movement output, scaled by the body's speed gene.
+    pub thrust: f32,
+    /// Torque at full rotation output.
+    pub torque: f32,
+    /// Rate at which linear velocity decays, per second.
+    pub linear_drag: f32,
+    /// Rate at which angular velocity decays, per second.
+    pub angular_drag: f32,
+    /// Energy spent per unit of thrust per second.
+    pub force_energy_rate: f32,
+    /// Energy spent per unit of torque per second.
+    pub torque_energy_rate: f32,
+}
+
+impl Default for PhysicsParams {
+    /// Terminal speed and turn rate of a default body match kinematic movement
+    /// with `move_multiplier` 60.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mass_mode: MassMode::Body,
+            energy_mass: 0.5,
+            thrust: 120.0,
+            torque: 40.0,
+            linear_drag: 2.0,
+            angular_drag: 4.0,
+            force_energy_rate: 2e-7,
+            torque_energy_rate: 1e-8,
+        }
+    }
+}
+
+/// Mass of an organism.
+pub fn mass(organism: &Organism, params: &Params) -> f32 {
+    let body = organism.morphology.body_scale.powi(2);
+    let mass = match params.physics.mass_mode {
+        MassMode::Fixed => 1.0,
+        MassMode::Body => body,
+        MassMode::BodyAndEnergy => body + params.physics.energy_mass * organism.energy.max(0.0),
+    };
+    mass.max(f32::EPSILON)
+}
+
+/// Accelerates an organism along its heading.
+///
+/// # Arguments
+///
+/// * `entity` - The organism thrusting
+/// * `output` - Movement output from the brain
+/// * `params` - Simulation parameters
+/// * `dt` - Time delta
+pub fn apply_thrust(entity: &mut Organism, output: f32, params: &Params, dt: f32) {
+    let physics = &params.physics;
+    let force = output * physics.thrust * entity.morphology.speed_scale;
+    let acceleration = force / mass(entity, params);
+    entity.velocity[0] += acceleration * entity.rot.cos() * dt;
+    entity.velocity[1] += acceleration * entity.rot.sin() * dt;
+    entity.consume_energy(force.abs() * physics.force_energy_rate * dt, Flow::Movement);
+}
+
+/// Spins an organism up or down.
+///
+/// # Arguments
+///
+/// * `entity` - The organism turning
+/// * `output` - Rotation output from the brain
+/// * `params` - Simulation parameters
+/// * `dt` - Time delta
+pub fn apply_torque(entity: &mut Organism, output: f32, params: &Params, dt: f32) {
+    let physics = &params.physics;
+    let torque = output * physics.torque;
+    entity.angular_velocity += torque / mass(entity, params) * dt;
+    entity.consume_energy(
+        torque.abs() * physics.torque_energy_rate * dt,
+        Flow::Rotation,
+    );
+}
+
+/// Moves and turns an organism by its velocities, then applies drag.
+///
+/// Without physics only collision momentum is carried, decaying with
+/// `collision.damping`.
+pub fn integrate(entity: &mut Organism, params: &Params, dt: f32) {
+    let physics = &params.physics;
+    let [vx, vy] = entity.velocity;
+    entity.pos[0] += vx * dt;
+    entity.pos[1] += vy * dt;
+    let drag = if physics.enabled {
+        physics.linear_drag
+    } else {
+        params.collision.damping
+    };
+    let decay = (-drag.max(0.0) * dt).exp();
+    entity.velocity = [vx * decay, vy * decay];
+
+    if physics.enabled {
+        entity.rot += entity.angular_velocity * dt;
+        entity.angular_velocity *= (-physics.angular_drag.max(0.0) * dt).exp();
+    }
+}
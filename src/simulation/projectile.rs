@@ -1,11 +1,31 @@
 //! Projectile system for organism attacks.
 //!
 //! Organisms can shoot projectiles in the direction they're facing to attack other organisms.
+//! Hits are found by sweeping each projectile's path over the step, so fast
+//! projectiles cannot tunnel through targets; the earliest hit along the path wins.
 
 use ndarray::Array1;
 use serde::{Deserialize, Serialize};
 
+use super::boundary;
+use super::ecosystem::Ecosystem;
+use super::energy::Flow;
+use super::events::SimulationEvent;
+use super::geometric_utils::segment_circle_entry;
 use super::locatable::Locatable;
+use super::morphology;
+use super::obstacle;
+use super::params::Params;
+use super::spatial::SpatialIndex;
+
+/// Optional projectile interactions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectileParams {
+    /// If true, projectiles whose paths cross destroy each other.
+    pub hit_projectiles: bool,
+    /// If true, projectiles destroy food they hit.
+    pub hit_food: bool,
+}
 
 /// A projectile fired by an organism during an attack.
 ///
@@ -102,3 +122,204 @@ impl Locatable for Projectile {
         self.distance_traveled += distance;
     }
 }
+
+/// What a projectile's path ran into.
+enum Target {
+    /// A wall or rock.
+    Obstacle,
+    /// Organism at this index.
+    Organism(usize),
+    /// Food item at this index.
+    Food(usize),
+    /// Another projectile at this index.
+    Projectile(usize),
+}
+
+/// A candidate hit, `fraction` of the way along a projectile's path for the step.
+struct Hit {
+    fraction: f32,
+    projectile: usize,
+    target: Target,
+}
+
+/// Moves every projectile and resolves what its path hit.
+///
+/// Each path is swept against obstacles, organisms and (optionally) food and
+/// other projectiles. Candidate hits are resolved in the order they happen,
+/// so every projectile stops at the first thing it reaches, and two
+/// projectiles only collide if neither was stopped earlier. Stopped and
+/// spent projectiles are removed; survivors get the boundary applied.
+///
+/// # Arguments
+///
+/// * `state` - Ecosystem whose projectiles to update
+/// * `spatial_index` - Index of the step's food and projectiles, with organisms
+///   at their current positions (see [`SpatialIndex::rebuild_organisms`])
+/// * `params` - Simulation parameters
+/// * `dt` - Time delta
+///
+/// # Returns
+///
+/// A `ProjectileHit` event for every organism hit.
+pub fn update_all(
+    state: &mut Ecosystem,
+    spatial_index: &SpatialIndex,
+    params: &Params,
+    dt: f32,
+) -> Vec<SimulationEvent> {
+    let starts: Vec<Array1<f32>> = state.projectiles.iter().map(|p| p.pos.clone()).collect();
+    for projectile in &mut state.projectiles {
+        projectile.update(dt);
+    }
+
+    let mut hits = Vec::new();
+    for (index, projectile) in state.projectiles.iter().enumerate() {
+        let (start, end) = (&starts[index], &projectile.pos);
+        let length = (end - start).mapv(|x| x.powi(2)).sum().sqrt();
+        // Only the part of the path within range can hit anything
+        let reach = (projectile.max_range - projectile.distance_traveled + length).min(length);
+        let mut candidate = |along: f32, target: Target| {
+            if along <= reach {
+                let fraction = if length > f32::EPSILON {
+                    along / length
+                } else {
+                    0.0
+                };
+                hits.push(Hit {
+                    fraction,
+                    projectile: index,
+                    target,
+                });
+            }
+        };
+
+        // Obstacles stop projectiles
+        if let Some(along) = obstacle::ray_distance(&state.obstacles, start, end) {
+            candidate(along, Target::Obstacle);
+        } else if obstacle::is_blocked(&state.obstacles, end) {
+            candidate(length, Target::Obstacle);
+        }
+
+        let midpoint = (start + end) / 2.0;
+        let organism_radius = morphology::max_body_radius(params) + params.projectile_radius;
+        let midpoint = [midpoint[0], midpoint[1]];
+        spatial_index.organisms().for_each_within(
+            midpoint,
+            length / 2.0 + organism_radius,
+            |_, org_index| {
+                let organism = &state.organisms[org_index];
+                if organism.id == projectile.owner_id || !organism.is_alive() {
+                    return; // Don't hit self or the dead
+                }
+                let centre = boundary::nearest_image(start, &organism.pos, params);
+                let radius = organism.morphology.body_radius(params) + params.projectile_radius;
+                if let Some(along) = segment_circle_entry(start, end, &centre, radius) {
+                    candidate(along, Target::Organism(org_index));
+                }
+            },
+        );
+
+        if params.projectiles.hit_food {
+            // Food items are as large as a default body
+            let radius = params.body_radius + params.projectile_radius;
+            spatial_index.food().for_each_within(
+                midpoint,
+                length / 2.0 + radius,
+                |_, food_index| {
+                    let food_item = &state.food[food_index];
+                    if food_item.is_consumed() {
+                        return;
+                    }
+                    let centre = boundary::nearest_image(start, &food_item.pos, params);
+                    if let Some(along) = segment_circle_entry(start, end, &centre, radius) {
+                        candidate(along, Target::Food(food_index));
+                    }
+                },
+            );
+        }
+
+        if params.projectiles.hit_projectiles {
+            // Closest approach is found in this projectile's frame, where the
+            // other one moves along a straight segment relative to it
+            let radius = 2.0 * params.projectile_radius;
+            let origin = Array1::zeros(2);
+            let start_point = [start[0], start[1]];
+            spatial_index.projectiles().for_each_within(
+                start_point,
+                2.0 * length + radius,
+                |_, other| {
+                    if other <= index || other >= state.projectiles.len() {
+                        return; // each pair once
+                    }
+                    let relative_start = boundary::displacement(start, &starts[other], params);
+                    let relative_end = &relative_start
+                        + &((&state.projectiles[other].velocity - &projectile.velocity) * dt);
+                    if let Some(along) =
+                        segment_circle_entry(&relative_start, &relative_end, &origin, radius)
+                    {
+                        let relative_length = (&relative_end - &relative_start)
+                            .mapv(|x| x.powi(2))
+                            .sum()
+                            .sqrt();
+                        let fraction = if relative_length > f32::EPSILON {
+                            along / relative_length
+                        } else {
+                            0.0
+                        };
+                        candidate(fraction * length, Target::Projectile(other));
+                    }
+                },
+            );
+        }
+    }
+
+    // Earliest hit wins
+    hits.sort_by(|a, b| a.fraction.total_cmp(&b.fraction));
+    let mut stopped = vec![false; state.projectiles.len()];
+    let mut events = Vec::new();
+    for hit in hits {
+        if stopped[hit.projectile] {
+            continue;
+        }
+        match hit.target {
+            Target::Obstacle => {}
+            Target::Organism(org_index) => {
+                let projectile = &state.projectiles[hit.projectile];
+                events.push(SimulationEvent::ProjectileHit {
+                    target_id: state.organisms[org_index].id,
+                    damage: projectile.damage,
+                    owner_id: projectile.owner_id,
+                    from: -&projectile.velocity,
+                });
+            }
+            Target::Food(food_index) => {
+                if state.food[food_index].is_consumed() {
+                    continue;
+                }
+                state
+                    .energy_ledger
+                    .record_food(Flow::FoodLost, -state.food[food_index].energy);
+                state.food[food_index].consume();
+            }
+            Target::Projectile(other) => {
+                if stopped[other] {
+                    continue;
+                }
+                stopped[other] = true;
+            }
+        }
+        stopped[hit.projectile] = true;
+    }
+
+    for (projectile, stopped) in state.projectiles.iter_mut().zip(stopped) {
+        if stopped {
+            projectile.distance_traveled = projectile.max_range;
+        } else if !projectile.is_expired() {
+            // Wrap, stop or bounce at the world edge
+            boundary::apply_to_projectile(projectile, params);
+        }
+    }
+    state.projectiles.retain(|p| !p.is_expired());
+
+    events
+}
@@ -3,6 +3,34 @@ use std::collections::VecDeque;
 
 use super::organism;
 
+/// Which mechanisms are allowed to create new organisms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReproductionMode {
+    /// Births only come from `Ecosystem::spawn` (graveyard evolution or living clones).
+    Spawning,
+    /// Births only come from organisms' own reproduction outputs.
+    /// Empty pools are still re-seeded so the simulation cannot go extinct.
+    OrganismInitiated,
+    /// Both automatic spawning and organism-initiated reproduction.
+    Both,
+}
+
+impl ReproductionMode {
+    /// Returns `true` if `Ecosystem::spawn` may create organisms.
+    pub fn allows_spawning(self) -> bool {
+        matches!(self, Self::Spawning | Self::Both)
+    }
+
+    /// Returns `true` if organisms may reproduce through their brain outputs.
+    pub fn allows_organism_initiated(self) -> bool {
+        matches!(self, Self::OrganismInitiated | Self::Both)
+    }
+}
+
+/// Energy a parent must have left after its contribution and the reproduction
+/// overhead, and the least a partner needs to be chosen as a mate.
+pub const ENERGY_RESERVE: f32 = 0.5;
+
 /// Statistics tracking reproduction strategy effectiveness based on organism deaths.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReproductionStats {
@@ -0,0 +1,298 @@
+//! Spatial resource field that controls where and how fast food grows.
+//!
+//! The world is covered by a grid of cells. Each cell has a static fertility
+//! in [0, 1], generated from value noise or from user-defined patches, and a
+//! nutrient level that regrows logistically towards `capacity * fertility`,
+//! diffuses to neighbouring cells, and is replenished by decomposing dead
+//! organisms. Spawning food draws its energy out of a cell's nutrients, so
+//! food appears more often in fertile, well-stocked areas.
+
+use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::params::Params;
+
+/// How cell fertility is generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FertilitySource {
+    /// Smooth value noise controlled by `noise_scale` and `seed`.
+    Noise,
+    /// Circular patches from [`ResourceFieldParams::patches`].
+    Patches,
+}
+
+/// A circular fertile area with linear falloff towards its edge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FertilityPatch {
+    /// Centre x in world coordinates.
+    pub x: f32,
+    /// Centre y in world coordinates.
+    pub y: f32,
+    /// Radius in world units.
+    pub radius: f32,
+    /// Fertility at the centre, in [0, 1].
+    pub fertility: f32,
+}
+
+/// Resource field configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceFieldParams {
+    /// If false, food spawns uniformly across the world.
+    pub enabled: bool,
+    /// Side length of a grid cell in world units.
+    pub cell_size: f32,
+    /// How fertility is generated.
+    pub source: FertilitySource,
+    /// Size of noise features, in cells.
+    pub noise_scale: f32,
+    /// Seed for the fertility noise.
+    pub seed: u64,
+    /// Fertile patches used with [`FertilitySource::Patches`].
+    pub patches: Vec<FertilityPatch>,
+    /// Nutrients a fully fertile cell holds at equilibrium.
+    pub capacity: f32,
+    /// Logistic regrowth rate per second.
+    pub regrowth_rate: f32,
+    /// Fraction of the nutrient difference exchanged with each neighbour per second.
+    pub diffusion_rate: f32,
+    /// Nutrients a dead organism returns to the cell it died in.
+    pub decomposition_energy: f32,
+}
+
+impl Default for ResourceFieldParams {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cell_size: 50.0,
+            source: FertilitySource::Noise,
+            noise_scale: 4.0,
+            seed: 0,
+            patches: Vec::new(),
+            capacity: 5.0,
+            regrowth_rate: 0.1,
+            diffusion_rate: 0.05,
+            decomposition_energy: 0.5,
+        }
+    }
+}
+
+/// Fraction of a cell's capacity that logistic regrowth always starts from,
+/// so depleted cells recover instead of staying empty.
+const REGROWTH_SEED: f32 = 0.05;
+
+/// Fertility and nutrient grid over the world.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResourceField {
+    /// Number of cell columns.
+    pub cols: usize,
+    /// Number of cell rows.
+    pub rows: usize,
+    /// Side length of a cell in world units.
+    pub cell_size: f32,
+    /// Static fertility per cell (row-major), in [0, 1].
+    pub fertility: Vec<f32>,
+    /// Current nutrients per cell (row-major).
+    pub nutrients: Vec<f32>,
+}
+
+impl ResourceField {
+    /// Builds a field for the current world size, with every cell at equilibrium.
+    pub fn new(params: &Params) -> Self {
+        let field_params = &params.resource_field;
+        let cell_size = field_params.cell_size.max(1.0);
+        let cols = (params.box_width / cell_size).ceil().max(1.0) as usize;
+        let rows = (params.box_height / cell_size).ceil().max(1.0) as usize;
+
+        let fertility = match field_params.source {
+            FertilitySource::Noise => noise_fertility(cols, rows, field_params),
+            FertilitySource::Patches => (0..rows)
+                .flat_map(|row| (0..cols).map(move |col| (col, row)))
+                .map(|(col, row)| {
+                    let x = (col as f32 + 0.5) * cell_size;
+                    let y = (row as f32 + 0.5) * cell_size;
+                    patch_fertility(x, y, &field_params.patches)
+                })
+                .collect(),
+        };
+        let nutrients = fertility
+            .iter()
+            .map(|f| f * field_params.capacity)
+            .collect();
+
+        Self {
+            cols,
+            rows,
+            cell_size,
+            fertility,
+            nutrients,
+        }
+    }
+
+    /// Returns `true` if the grid matches the current world and cell size.
+    pub fn fits(&self, params: &Params) -> bool {
+        let cell_size = params.resource_field.cell_size.max(1.0);
+        (self.cell_size - cell_size).abs() < f32::EPSILON
+            && self.cols == (params.box_width / cell_size).ceil().max(1.0) as usize
+            && self.rows == (params.box_height / cell_size).ceil().max(1.0) as usize
+            && self.nutrients.len() == self.cols * self.rows
+    }
+
+    /// Index of the cell containing `pos`, clamped to the grid.
+    pub fn cell_index(&self, pos: &Array1<f32>) -> usize {
+        let col = ((pos[0] / self.cell_size).max(0.0) as usize).min(self.cols - 1);
+        let row = ((pos[1] / self.cell_size).max(0.0) as usize).min(self.rows - 1);
+        row * self.cols + col
+    }
+
+    /// Total nutrients stored in the field.
+    pub fn total_nutrients(&self) -> f32 {
+        self.nutrients.iter().sum()
+    }
+
+    /// Advances regrowth and diffusion by `dt` seconds.
+    ///
+    /// Rebuilds the grid first if it is missing or the world size changed.
+    pub fn update(&mut self, params: &Params, dt: f32) {
+        if !params.resource_field.enabled {
+            return;
+        }
+        if !self.fits(params) {
+            *self = Self::new(params);
+        }
+        self.regrow(params, dt);
+        self.diffuse(params, dt);
+    }
+
+    /// Returns nutrients to the soil where an organism died.
+    pub fn decompose(&mut self, pos: &Array1<f32>, params: &Params) {
+        if !params.resource_field.enabled {
+            return;
+        }
+        self.deposit(pos, params.resource_field.decomposition_energy);
+    }
+
+    /// Adds `amount` nutrients to the cell containing `pos`.
+    pub fn deposit(&mut self, pos: &Array1<f32>, amount: f32) {
+        if self.nutrients.is_empty() {
+            return;
+        }
+        let index = self.cell_index(pos);
+        self.nutrients[index] += amount;
+    }
+
+    /// Picks a spawn position for food worth `energy`, weighted by nutrients.
+    ///
+    /// Only cells holding at least `energy` nutrients can grow food; the energy
+    /// is taken out of the chosen cell. Returns `None` if no cell can afford it.
+    pub fn take_food_position(&mut self, energy: f32) -> Option<Array1<f32>> {
+        let total: f32 = self.nutrients.iter().filter(|&&n| n >= energy).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut rng = rand::rng();
+        let mut target = rng.random::<f32>() * total;
+        let mut chosen = None;
+        for (index, &nutrients) in self.nutrients.iter().enumerate() {
+            if nutrients < energy {
+                continue;
+            }
+            chosen = Some(index);
+            if target < nutrients {
+                break;
+            }
+            target -= nutrients;
+        }
+
+        let index = chosen?;
+        self.nutrients[index] -= energy;
+        let (col, row) = (index % self.cols, index / self.cols);
+        Some(Array1::from_vec(vec![
+            (col as f32 + rng.random::<f32>()) * self.cell_size,
+            (row as f32 + rng.random::<f32>()) * self.cell_size,
+        ]))
+    }
+
+    fn regrow(&mut self, params: &Params, dt: f32) {
+        let field_params = &params.resource_field;
+        for (nutrients, fertility) in self.nutrients.iter_mut().zip(&self.fertility) {
+            let capacity = field_params.capacity * fertility;
+            if capacity <= 0.0 {
+                // Barren cells slowly lose whatever decomposition left behind
+                *nutrients -= field_params.regrowth_rate * *nutrients * dt;
+                continue;
+            }
+            let stock = nutrients.max(REGROWTH_SEED * capacity);
+            let growth = field_params.regrowth_rate * stock * (1.0 - *nutrients / capacity);
+            *nutrients = (*nutrients + growth * dt).max(0.0);
+        }
+    }
+
+    fn diffuse(&mut self, params: &Params, dt: f32) {
+        // Explicit scheme, clamped to stay stable
+        let rate = (params.resource_field.diffusion_rate * dt).clamp(0.0, 0.25);
+        if rate <= 0.0 {
+            return;
+        }
+        let (cols, rows) = (self.cols, self.rows);
+        let old = self.nutrients.clone();
+        for row in 0..rows {
+            for col in 0..cols {
+                let index = row * cols + col;
+                let here = old[index];
+                // Edges reflect, so nutrients are conserved
+                let left = if col > 0 { old[index - 1] } else { here };
+                let right = if col + 1 < cols { old[index + 1] } else { here };
+                let up = if row > 0 { old[index - cols] } else { here };
+                let down = if row + 1 < rows {
+                    old[index + cols]
+                } else {
+                    here
+                };
+                self.nutrients[index] = here + rate * (left + right + up + down - 4.0 * here);
+            }
+        }
+    }
+}
+
+/// Fertility at a point from overlapping patches (the strongest patch wins).
+fn patch_fertility(x: f32, y: f32, patches: &[FertilityPatch]) -> f32 {
+    patches
+        .iter()
+        .filter(|patch| patch.radius > 0.0)
+        .map(|patch| {
+            let distance = ((x - patch.x).powi(2) + (y - patch.y).powi(2)).sqrt();
+            patch.fertility * (1.0 - distance / patch.radius).max(0.0)
+        })
+        .fold(0.0, f32::max)
+        .clamp(0.0, 1.0)
+}
+
+/// Smooth value noise over the grid: random lattice values every
+/// `noise_scale` cells, blended with smoothstep interpolation.
+fn noise_fertility(cols: usize, rows: usize, params: &ResourceFieldParams) -> Vec<f32> {
+    let scale = params.noise_scale.max(1.0);
+    let lattice_cols = (cols as f32 / scale).ceil() as usize + 2;
+    let lattice_rows = (rows as f32 / scale).ceil() as usize + 2;
+    let mut rng = StdRng::seed_from_u64(params.seed);
+    let lattice: Vec<f32> = (0..lattice_cols * lattice_rows)
+        .map(|_| rng.random::<f32>())
+        .collect();
+
+    let smooth = |t: f32| t * t * (3.0 - 2.0 * t);
+    let mut fertility = Vec::with_capacity(cols * rows);
+    for row in 0..rows {
+        for col in 0..cols {
+            let (gx, gy) = (col as f32 / scale, row as f32 / scale);
+            let (x0, y0) = (gx.floor() as usize, gy.floor() as usize);
+            let (tx, ty) = (smooth(gx.fract()), smooth(gy.fract()));
+            let at = |x: usize, y: usize| lattice[y * lattice_cols + x];
+            let top = at(x0, y0) + (at(x0 + 1, y0) - at(x0, y0)) * tx;
+            let bottom = at(x0, y0 + 1) + (at(x0 + 1, y0 + 1) - at(x0, y0 + 1)) * tx;
+            fertility.push(top + (bottom - top) * ty);
+        }
+    }
+    fertility
+}
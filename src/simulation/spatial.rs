@@ -1,130 +1,399 @@
 //! Spatial indexing for efficient neighbor queries.
 //!
-//! Provides a unified interface for building and querying KD-trees for spatial queries.
+//! Entities are binned into a uniform grid of cells (a cell list) that is
+//! rebuilt in place every step. A radius query only visits the cells the
+//! query square overlaps and calls back for every hit, so it never
+//! allocates. On a torus the cells wrap around and distances use the nearest
+//! periodic image, so entities just across the seam are found too.
+//!
+//! Each organism's [`Neighbourhood`] is gathered once per step from the
+//! index and shared by its senses, collisions and actions.
 
-use kdtree::distance::squared_euclidean;
-use kdtree::{ErrorKind as KdTreeError, KdTree};
 use ndarray::Array1;
 
+use super::boundary;
 use super::ecosystem::Ecosystem;
+use super::environment;
+use super::morphology;
+use super::organism::Organism;
+use super::params::Params;
+
+/// Most cells along either axis of a grid.
+const MAX_CELLS_PER_AXIS: usize = 256;
 
-/// Type alias for 2D spatial KD-tree used for efficient neighbor queries.
-pub type Tree2D = KdTree<f32, usize, Vec<f32>>;
-
-/// Container for pre-built KD-trees for spatial queries.
-pub struct SpatialTrees<'a> {
-    /// KD-tree for organism positions.
-    pub organisms: &'a Tree2D,
-    /// KD-tree for food positions.
-    pub food: &'a Tree2D,
-    /// KD-tree for projectile positions.
-    pub projectiles: &'a Tree2D,
+/// Result of a spatial radius query: `(distance_squared, index)` pairs.
+pub type SpatialQueryResult = Vec<(f32, usize)>;
+
+/// Uniform grid of cells over the world, holding the indices of the
+/// entities in each cell.
+#[derive(Debug, Clone, Default)]
+pub struct CellGrid {
+    /// Cells along x.
+    cols: usize,
+    /// Cells along y.
+    rows: usize,
+    /// Width of a cell.
+    cell_width: f32,
+    /// Height of a cell.
+    cell_height: f32,
+    /// World width.
+    width: f32,
+    /// World height.
+    height: f32,
+    /// Whether the world wraps around.
+    torus: bool,
+    /// Start of each cell's run in `entries` (one extra at the end).
+    cell_starts: Vec<usize>,
+    /// Entity indices ordered by cell.
+    entries: Vec<usize>,
+    /// Entity positions, indexed like the entities.
+    positions: Vec<[f32; 2]>,
+    /// Cell of each entity, kept to avoid recomputing it while sorting.
+    entity_cells: Vec<usize>,
 }
 
-/// Spatial index containing KD-trees for all entity types.
-pub struct SpatialIndex {
-    /// KD-tree for organism positions.
-    organisms: Tree2D,
-    /// KD-tree for food positions.
-    food: Tree2D,
-    /// KD-tree for projectile positions.
-    projectiles: Tree2D,
+impl CellGrid {
+    /// Rebuilds the grid for `positions` (indexed like the entities), reusing
+    /// its allocations.
+    ///
+    /// Cells are at least `cell_size` wide and tile the world exactly, so
+    /// they wrap cleanly on a torus.
+    pub fn rebuild(
+        &mut self,
+        positions: impl Iterator<Item = [f32; 2]>,
+        cell_size: f32,
+        params: &Params,
+    ) {
+        let axis = |size: f32| {
+            let cells = (size / cell_size.max(f32::EPSILON)).floor();
+            let cells = if cells.is_finite() { cells as usize } else { 1 };
+            let cells = cells.clamp(1, MAX_CELLS_PER_AXIS);
+            (cells, (size / cells as f32).max(f32::EPSILON))
+        };
+        (self.cols, self.cell_width) = axis(params.box_width);
+        (self.rows, self.cell_height) = axis(params.box_height);
+        self.width = params.box_width;
+        self.height = params.box_height;
+        self.torus = params.boundary_mode.is_torus();
+
+        self.positions.clear();
+        self.positions.extend(positions);
+
+        // Counting sort of the entities by cell
+        self.entity_cells.clear();
+        self.cell_starts.clear();
+        self.cell_starts.resize(self.cols * self.rows + 1, 0);
+        for index in 0..self.positions.len() {
+            let cell = self.cell_of(self.positions[index]);
+            self.entity_cells.push(cell);
+            self.cell_starts[cell + 1] += 1;
+        }
+        for cell in 0..self.cols * self.rows {
+            self.cell_starts[cell + 1] += self.cell_starts[cell];
+        }
+        // Place entities using the starts as cursors, which leaves each at
+        // the start of the next cell; shifting them back restores the starts
+        self.entries.clear();
+        self.entries.resize(self.positions.len(), 0);
+        for (index, &cell) in self.entity_cells.iter().enumerate() {
+            self.entries[self.cell_starts[cell]] = index;
+            self.cell_starts[cell] += 1;
+        }
+        self.cell_starts.rotate_right(1);
+        self.cell_starts[0] = 0;
+    }
+
+    /// Number of entities in the grid.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns true if the grid holds no entities.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Calls `visit(distance_squared, index)` for every entity within
+    /// `radius` of `pos`, in no particular order.
+    pub fn for_each_within(&self, pos: [f32; 2], radius: f32, mut visit: impl FnMut(f32, usize)) {
+        if self.is_empty() || radius.is_nan() || radius < 0.0 {
+            return;
+        }
+        let radius_sq = radius * radius;
+        let (x0, x1) = self.span(pos[0], radius, self.cell_width, self.cols);
+        let (y0, y1) = self.span(pos[1], radius, self.cell_height, self.rows);
+
+        for cy in y0..=y1 {
+            let row = Self::wrap(cy, self.rows) * self.cols;
+            for cx in x0..=x1 {
+                let cell = row + Self::wrap(cx, self.cols);
+                for &index in &self.entries[self.cell_starts[cell]..self.cell_starts[cell + 1]] {
+                    let [x, y] = self.positions[index];
+                    let (mut dx, mut dy) = (x - pos[0], y - pos[1]);
+                    if self.torus {
+                        dx = boundary::wrap_delta(dx, self.width);
+                        dy = boundary::wrap_delta(dy, self.height);
+                    }
+                    let dist_sq = dx * dx + dy * dy;
+                    if dist_sq <= radius_sq {
+                        visit(dist_sq, index);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every entity within `radius` of `pos`, nearest first.
+    pub fn query(&self, pos: [f32; 2], radius: f32) -> SpatialQueryResult {
+        let mut results = Vec::new();
+        self.for_each_within(pos, radius, |dist_sq, index| results.push((dist_sq, index)));
+        sort_nearest_first(&mut results);
+        results
+    }
+
+    /// Cell holding `pos` (wrapped on a torus, clamped to the edge cells otherwise).
+    fn cell_of(&self, pos: [f32; 2]) -> usize {
+        let coord = |value: f32, size: f32, cell: f32, cells: usize| {
+            let value = if self.torus {
+                value.rem_euclid(size)
+            } else {
+                value
+            };
+            ((value / cell).floor().max(0.0) as usize).min(cells - 1)
+        };
+        let cx = coord(pos[0], self.width, self.cell_width, self.cols);
+        let cy = coord(pos[1], self.height, self.cell_height, self.rows);
+        cy * self.cols + cx
+    }
+
+    /// Range of cell coordinates a query from `value - radius` to
+    /// `value + radius` overlaps along one axis.
+    ///
+    /// The range is padded slightly so rounding never drops an entity on a
+    /// cell edge. On a torus it may run past the edges and is wrapped by
+    /// [`CellGrid::wrap`]; it never covers a cell twice.
+    fn span(&self, value: f32, radius: f32, cell: f32, cells: usize) -> (isize, isize) {
+        let margin = cell * 1e-3;
+        let low = ((value - radius - margin) / cell).floor();
+        let high = ((value + radius + margin) / cell).floor();
+        let last = (cells - 1) as f32;
+        if self.torus {
+            let covered = high - low;
+            if covered.is_nan() || covered >= last {
+                return (0, cells.cast_signed() - 1);
+            }
+            (low as isize, high as isize)
+        } else {
+            (
+                low.clamp(0.0, last) as isize,
+                high.clamp(0.0, last) as isize,
+            )
+        }
+    }
+
+    /// Cell coordinate `coord` brought into the grid.
+    fn wrap(coord: isize, cells: usize) -> usize {
+        coord.rem_euclid(cells.cast_signed()).cast_unsigned()
+    }
 }
 
-/// Result of a spatial radius query.
-pub type SpatialQueryResult = Vec<(f32, usize)>;
+/// Spatial index holding a cell grid for every entity type.
+#[derive(Debug, Clone, Default)]
+pub struct SpatialIndex {
+    /// Grid of organism positions.
+    organisms: CellGrid,
+    /// Grid of food positions.
+    food: CellGrid,
+    /// Grid of projectile positions.
+    projectiles: CellGrid,
+}
 
 impl SpatialIndex {
     /// Builds a spatial index from the current ecosystem state.
+    pub fn build(ecosystem: &Ecosystem, params: &Params) -> Self {
+        let mut index = Self::default();
+        index.rebuild(ecosystem, params);
+        index
+    }
+
+    /// Rebuilds the index from the current ecosystem state, reusing its
+    /// allocations.
     ///
-    /// # Arguments
-    ///
-    /// * `ecosystem` - The ecosystem to build indices for
-    ///
-    /// # Returns
-    ///
-    /// A spatial index or an error if tree building fails.
-    pub fn build(ecosystem: &Ecosystem) -> Result<Self, KdTreeError> {
-        let organisms = build_tree(&ecosystem.organisms, |org| org.pos.to_vec())?;
-        let food = build_tree(&ecosystem.food, |f| f.pos.to_vec())?;
-        let projectiles = build_tree(&ecosystem.projectiles, |proj| proj.pos.to_vec())?;
-
-        Ok(Self {
-            organisms,
-            food,
-            projectiles,
-        })
-    }
-
-    /// Returns references to the trees as a `SpatialTrees` struct.
-    pub fn as_trees(&self) -> SpatialTrees<'_> {
-        SpatialTrees {
-            organisms: &self.organisms,
-            food: &self.food,
-            projectiles: &self.projectiles,
-        }
+    /// Cells are as wide as the default vision radius, the most common query.
+    pub fn rebuild(&mut self, ecosystem: &Ecosystem, params: &Params) {
+        let cell_size = params.vision_radius;
+        let pos = |pos: &Array1<f32>| [pos[0], pos[1]];
+        self.rebuild_organisms(ecosystem, params);
+        self.food.rebuild(
+            ecosystem.food.iter().map(|f| pos(&f.pos)),
+            cell_size,
+            params,
+        );
+        self.projectiles.rebuild(
+            ecosystem.projectiles.iter().map(|p| pos(&p.pos)),
+            cell_size,
+            params,
+        );
+    }
+
+    /// Rebuilds only the organism grid, for when organisms have moved but
+    /// food and projectiles haven't.
+    pub fn rebuild_organisms(&mut self, ecosystem: &Ecosystem, params: &Params) {
+        self.organisms.rebuild(
+            ecosystem.organisms.iter().map(|o| [o.pos[0], o.pos[1]]),
+            params.vision_radius,
+            params,
+        );
+    }
+
+    /// Grid of organism positions.
+    pub fn organisms(&self) -> &CellGrid {
+        &self.organisms
+    }
+
+    /// Grid of food positions.
+    pub fn food(&self) -> &CellGrid {
+        &self.food
+    }
+
+    /// Grid of projectile positions.
+    pub fn projectiles(&self) -> &CellGrid {
+        &self.projectiles
     }
 
     /// Query organisms within a radius.
     ///
-    /// # Arguments
-    ///
-    /// * `pos` - Center position for the query
-    /// * `radius` - Search radius (will be squared internally)
-    ///
     /// # Returns
     ///
-    /// Vector of (`distance_squared`, index) pairs for organisms within radius.
+    /// Vector of (`distance_squared`, index) pairs for organisms within radius,
+    /// nearest first.
     pub fn query_organisms(&self, pos: &Array1<f32>, radius: f32) -> SpatialQueryResult {
-        self.organisms
-            .within(&pos.to_vec(), radius.powi(2), &squared_euclidean)
-            .unwrap_or_default()
-            .into_iter()
-            .map(|(dist, &idx)| (dist, idx))
-            .collect()
+        self.organisms.query([pos[0], pos[1]], radius)
     }
 
     /// Query food within a radius.
     pub fn query_food(&self, pos: &Array1<f32>, radius: f32) -> SpatialQueryResult {
-        self.food
-            .within(&pos.to_vec(), radius.powi(2), &squared_euclidean)
-            .unwrap_or_default()
-            .into_iter()
-            .map(|(dist, &idx)| (dist, idx))
-            .collect()
+        self.food.query([pos[0], pos[1]], radius)
     }
 
     /// Query projectiles within a radius.
     pub fn query_projectiles(&self, pos: &Array1<f32>, radius: f32) -> SpatialQueryResult {
-        self.projectiles
-            .within(&pos.to_vec(), radius.powi(2), &squared_euclidean)
-            .unwrap_or_default()
-            .into_iter()
-            .map(|(dist, &idx)| (dist, idx))
-            .collect()
+        self.projectiles.query([pos[0], pos[1]], radius)
     }
+}
 
-    /// Get direct references to the trees (for backwards compatibility).
-    pub fn organisms_tree(&self) -> &Tree2D {
-        &self.organisms
+/// Everything near one organism, gathered once per step and shared by its
+/// senses, collisions and actions.
+///
+/// Each list holds `(distance_squared, index)` pairs, nearest first, so the
+/// entities within any smaller radius are a prefix of it.
+#[derive(Debug, Clone, Default)]
+pub struct Neighbourhood {
+    /// Radius the lists were gathered with.
+    radius: f32,
+    /// Nearby organisms.
+    organisms: SpatialQueryResult,
+    /// Nearby food.
+    food: SpatialQueryResult,
+    /// Nearby projectiles.
+    projectiles: SpatialQueryResult,
+}
+
+impl Neighbourhood {
+    /// Refills the neighbourhood with everything within `radius` of `pos`,
+    /// reusing its allocations.
+    pub fn gather(&mut self, index: &SpatialIndex, pos: &Array1<f32>, radius: f32) {
+        let pos = [pos[0], pos[1]];
+        self.radius = radius;
+        for (list, grid) in [
+            (&mut self.organisms, &index.organisms),
+            (&mut self.food, &index.food),
+            (&mut self.projectiles, &index.projectiles),
+        ] {
+            list.clear();
+            grid.for_each_within(pos, radius, |dist_sq, i| list.push((dist_sq, i)));
+            sort_nearest_first(list);
+        }
     }
 
-    /// Get direct reference to the food KD-tree.
-    pub fn food_tree(&self) -> &Tree2D {
-        &self.food
+    /// Neighbourhood of `organism` built from scratch, for sensing outside
+    /// the simulation step (tests and tools).
+    pub fn around(organism: &Organism, ecosystem: &Ecosystem, params: &Params) -> Self {
+        let index = SpatialIndex::build(ecosystem, params);
+        let vision_scale = environment::conditions(ecosystem.time, params).vision_scale;
+        let mut neighbourhood = Self::default();
+        neighbourhood.gather(
+            &index,
+            &organism.pos,
+            Self::sensing_radius(organism, params, vision_scale),
+        );
+        neighbourhood
     }
 
-    /// Get direct reference to the projectiles KD-tree.
-    pub fn projectiles_tree(&self) -> &Tree2D {
-        &self.projectiles
+    /// Radius that covers every sense and action of `organism`: its longest
+    /// (night-scaled) vision ray, its scent radius and its
+    /// [`Neighbourhood::action_radius`].
+    pub fn sensing_radius(organism: &Organism, params: &Params, vision_scale: f32) -> f32 {
+        let vision = organism
+            .vision_lengths
+            .iter()
+            .copied()
+            .fold(params.vision_radius, f32::max)
+            * vision_scale;
+        vision
+            .max(organism.morphology.scent_radius(params))
+            .max(Self::action_radius(organism, params))
+    }
+
+    /// Radius that covers every action of `organism`: sharing, mating,
+    /// biting and eating, and touching the largest body it could collide with.
+    pub fn action_radius(organism: &Organism, params: &Params) -> f32 {
+        let body = organism.morphology.body_radius(params);
+        let contact = body + morphology::max_body_radius(params);
+        let bite = contact + params.combat.bite_range;
+        let eat = body + params.body_radius;
+        params
+            .share_radius
+            .max(params.reproduction_radius)
+            .max(bite)
+            .max(eat)
+            .max(contact)
+    }
+
+    /// Radius the neighbourhood was gathered with.
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Organisms within `radius`, nearest first.
+    pub fn organisms(&self, radius: f32) -> &[(f32, usize)] {
+        self.within(&self.organisms, radius)
+    }
+
+    /// Food within `radius`, nearest first.
+    pub fn food(&self, radius: f32) -> &[(f32, usize)] {
+        self.within(&self.food, radius)
     }
-}
 
-/// Helper function to build a KD-tree from a collection of items.
-fn build_tree<T>(items: &[T], get_pos: impl Fn(&T) -> Vec<f32>) -> Result<Tree2D, KdTreeError> {
-    let mut tree = KdTree::with_capacity(2, items.len());
-    for (i, item) in items.iter().enumerate() {
-        tree.add(get_pos(item), i)?;
+    /// Projectiles within `radius`, nearest first.
+    pub fn projectiles(&self, radius: f32) -> &[(f32, usize)] {
+        self.within(&self.projectiles, radius)
     }
-    Ok(tree)
+
+    fn within<'a>(&self, list: &'a [(f32, usize)], radius: f32) -> &'a [(f32, usize)] {
+        debug_assert!(
+            radius <= self.radius * (1.0 + 1e-5),
+            "neighbourhood of radius {} queried at {}",
+            self.radius,
+            radius
+        );
+        let radius_sq = radius * radius;
+        &list[..list.partition_point(|&(dist_sq, _)| dist_sq <= radius_sq)]
+    }
+}
+
+/// Sorts query results nearest first, breaking ties by index so the order is
+/// deterministic.
+fn sort_nearest_first(results: &mut [(f32, usize)]) {
+    results.sort_unstable_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
 }
@@ -0,0 +1,115 @@
+//! Read-only view of the world for the parallel phase of a step.
+//!
+//! While organisms are updated in parallel each one is mutated in place, so
+//! what they perceive of each other can't be read from the organisms
+//! themselves. Instead each organism is summarised once per step in an
+//! [`OrganismView`] holding only what neighbours sense and act on, and the
+//! rest of the world (food, projectiles, obstacles, pheromones) is borrowed
+//! as it is.
+
+use ndarray::Array1;
+
+use super::ecosystem::Ecosystem;
+use super::food::Food;
+use super::obstacle::Obstacle;
+use super::organism::Organism;
+use super::params::Params;
+use super::pheromone::PheromoneField;
+use super::physics;
+use super::projectile::Projectile;
+
+/// What other organisms can see of an organism during a step.
+#[derive(Debug, Clone)]
+pub struct OrganismView {
+    /// Unique identifier.
+    pub id: usize,
+    /// Genetic pool.
+    pub pool_id: usize,
+    /// Position.
+    pub pos: Array1<f32>,
+    /// Heading in radians.
+    pub rot: f32,
+    /// Displayed signal.
+    pub signal: Array1<f32>,
+    /// Velocity (with physics), for collisions.
+    pub velocity: [f32; 2],
+    /// Energy, for mate choice and telling the living from the dead.
+    pub energy: f32,
+    /// Whether it is off its reproduction cooldown.
+    pub can_reproduce: bool,
+    /// DNA, for mate compatibility.
+    pub dna: Array1<f32>,
+    /// Body radius.
+    pub body_radius: f32,
+    /// Mass, for collisions.
+    pub mass: f32,
+}
+
+impl OrganismView {
+    /// Summarises `organism`, with `params` being its pool's parameters.
+    pub fn of(organism: &Organism, params: &Params) -> Self {
+        Self {
+            id: organism.id,
+            pool_id: organism.pool_id,
+            pos: organism.pos.clone(),
+            rot: organism.rot,
+            signal: organism.signal.clone(),
+            velocity: organism.velocity,
+            energy: organism.energy,
+            can_reproduce: organism.can_reproduce(),
+            dna: organism.dna.clone(),
+            body_radius: organism.morphology.body_radius(params),
+            mass: physics::mass(organism, params),
+        }
+    }
+
+    /// Summarises every organism, indexed like `organisms`.
+    pub fn all(organisms: &[Organism], params: &Params) -> Vec<Self> {
+        let pool_params = params.per_pool();
+        organisms
+            .iter()
+            .map(|organism| {
+                let params = pool_params
+                    .get(organism.pool_id)
+                    .map_or(params, AsRef::as_ref);
+                Self::of(organism, params)
+            })
+            .collect()
+    }
+
+    /// Returns true if the organism has energy left.
+    pub fn is_alive(&self) -> bool {
+        self.energy > 0.0
+    }
+}
+
+/// Everything organisms perceive and act on during the parallel phase.
+#[derive(Debug, Clone)]
+pub struct WorldView<'a> {
+    /// Simulation time.
+    pub time: f32,
+    /// Every organism, indexed like `Ecosystem::organisms`.
+    pub organisms: Vec<OrganismView>,
+    /// Food items.
+    pub food: &'a [Food],
+    /// Active projectiles.
+    pub projectiles: &'a [Projectile],
+    /// Static obstacles.
+    pub obstacles: &'a [Obstacle],
+    /// Pheromone concentrations.
+    pub pheromones: &'a PheromoneField,
+}
+
+impl<'a> WorldView<'a> {
+    /// View of the current state of `ecosystem`.
+    pub fn of(ecosystem: &'a Ecosystem, params: &Params) -> Self {
+        Self {
+            time: ecosystem.time,
+            organisms: OrganismView::all(&ecosystem.organisms, params),
+            food: &ecosystem.food,
+            projectiles: &ecosystem.projectiles,
+            obstacles: &ecosystem.obstacles,
+            pheromones: &ecosystem.pheromones,
+        }
+    }
+}
@@ -49,6 +49,9 @@ pub fn draw_events_panel(egui_ctx: &egui::Context, ecosystem: &simulation::ecosy
                             simulation::event_log::EventColor::Food => {
                                 egui::Color32::from_rgb(255, 200, 100) // Yellow
                             }
+                            simulation::event_log::EventColor::Migration => {
+                                egui::Color32::from_rgb(200, 130, 255) // Purple
+                            }
                         };
 
                         // Display time and event description
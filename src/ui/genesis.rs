@@ -1,5 +1,16 @@
 use crate::simulation;
-use crate::simulation::params::Params;
+use crate::simulation::actions::ActionKind;
+use crate::simulation::boundary::BoundaryMode;
+use crate::simulation::collision::CollisionMode;
+use crate::simulation::conflict::ConflictPolicy;
+use crate::simulation::environment::CycleCurve;
+use crate::simulation::food::FoodKindSpec;
+use crate::simulation::migration::{MigrantPolicy, MigrationKind, MigrationTopology};
+use crate::simulation::obstacle::{self, Obstacle};
+use crate::simulation::organism::SenseKind;
+use crate::simulation::params::{Params, PoolOverrides};
+use crate::simulation::physics::MassMode;
+use crate::simulation::resource_field::{FertilityPatch, FertilitySource};
 use egui_macroquad::egui;
 use macroquad::prelude::*;
 
@@ -72,6 +83,76 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                     ui.label("Number of organisms to seed into empty pools");
                 });
 
+                ui.collapsing("Senses", |ui| {
+                    ui.label("Brain inputs, in order:");
+                    let mut moved_up = None;
+                    let mut removed = None;
+                    for (index, kind) in params.senses.iter_mut().enumerate() {
+                        ui.push_id(index, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(kind.name());
+                                if let SenseKind::StereoScent { sectors } = kind {
+                                    ui.add(egui::Slider::new(sectors, 1..=8).text("Sectors"));
+                                }
+                                if index > 0 && ui.small_button("⬆").clicked() {
+                                    moved_up = Some(index);
+                                }
+                                if ui.small_button("✖").clicked() {
+                                    removed = Some(index);
+                                }
+                            });
+                        });
+                    }
+                    if let Some(index) = moved_up {
+                        params.senses.swap(index - 1, index);
+                    }
+                    if let Some(index) = removed {
+                        params.senses.remove(index);
+                    }
+                    ui.horizontal_wrapped(|ui| {
+                        for kind in SenseKind::ALL {
+                            let present = params.senses.iter().any(|s| s.name() == kind.name());
+                            if !present && ui.button(format!("+ {}", kind.name())).clicked() {
+                                params.senses.push(kind);
+                            }
+                        }
+                    });
+                });
+
+                ui.collapsing("Actions", |ui| {
+                    ui.label("Brain outputs, in order:");
+                    let mut moved_up = None;
+                    let mut removed = None;
+                    for (index, kind) in params.actions.iter().enumerate() {
+                        ui.push_id(index, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(kind.name());
+                                if index > 0 && ui.small_button("⬆").clicked() {
+                                    moved_up = Some(index);
+                                }
+                                if ui.small_button("✖").clicked() {
+                                    removed = Some(index);
+                                }
+                            });
+                        });
+                    }
+                    if let Some(index) = moved_up {
+                        params.actions.swap(index - 1, index);
+                    }
+                    if let Some(index) = removed {
+                        params.actions.remove(index);
+                    }
+                    ui.horizontal_wrapped(|ui| {
+                        for kind in ActionKind::ALL {
+                            if !params.actions.contains(&kind)
+                                && ui.button(format!("+ {}", kind.name())).clicked()
+                            {
+                                params.actions.push(kind);
+                            }
+                        }
+                    });
+                });
+
                 ui.collapsing("Brain Architecture", |ui| {
                     ui.horizontal(|ui| {
                         ui.label("Brain Type:");
@@ -119,9 +200,6 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                     ui.add(
                         egui::Slider::new(&mut params.max_energy, 0.5..=10.0).text("Max Energy"),
                     );
-                    ui.add(
-                        egui::Slider::new(&mut params.food_energy, 0.1..=5.0).text("Food Energy"),
-                    );
                     ui.add(
                         egui::Slider::new(&mut params.idle_energy_rate, 0.001..=0.5)
                             .text("Idle Energy Rate"),
@@ -138,10 +216,16 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                         egui::Slider::new(&mut params.move_multiplier, 10.0..=200.0)
                             .text("Move Multiplier"),
                     );
-                    ui.add(
-                        egui::Slider::new(&mut params.corpse_energy_ratio, 0.1..=2.0)
-                            .text("Corpse Energy Ratio"),
-                    );
+                    let ledger = &mut params.energy_ledger;
+                    ui.checkbox(&mut ledger.strict, "Strict Energy Accounting");
+                    ui.label("Stop if a step's energy flows don't add up");
+                    if ledger.strict {
+                        ui.add(
+                            egui::Slider::new(&mut ledger.tolerance, 1e-6..=1e-1)
+                                .text("Tolerance")
+                                .logarithmic(true),
+                        );
+                    }
                 });
 
                 ui.collapsing("DNA & Breeding", |ui| {
@@ -153,6 +237,10 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                         egui::Slider::new(&mut params.dna_mutation_rate, 0.001..=0.5)
                             .text("DNA Mutation Rate"),
                     );
+                    ui.add(
+                        egui::Slider::new(&mut params.brain_mutation_rate, 0.001..=0.5)
+                            .text("Brain Mutation Rate"),
+                    );
                     ui.add(
                         egui::Slider::new(&mut params.reproduction_radius, 5.0..=50.0)
                             .text("Reproduction Radius"),
@@ -161,6 +249,211 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                         egui::Slider::new(&mut params.reproduction_energy_multiplier, 0.5..=3.0)
                             .text("Reproduction Energy Multiplier"),
                     );
+
+                    ui.horizontal(|ui| {
+                        ui.label("Reproduction Mode:");
+                        ui.radio_value(
+                            &mut params.reproduction_mode,
+                            simulation::reproduction::ReproductionMode::Spawning,
+                            "Spawning",
+                        );
+                        ui.radio_value(
+                            &mut params.reproduction_mode,
+                            simulation::reproduction::ReproductionMode::OrganismInitiated,
+                            "Organism-Initiated",
+                        );
+                        ui.radio_value(
+                            &mut params.reproduction_mode,
+                            simulation::reproduction::ReproductionMode::Both,
+                            "Both",
+                        );
+                    });
+                    ui.add(
+                        egui::Slider::new(&mut params.reproduction_cooldown, 0.0..=30.0)
+                            .text("Reproduction Cooldown (s)"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.reproduction_energy_cost, 0.0..=1.0)
+                            .text("Reproduction Energy Cost"),
+                    );
+                });
+
+                ui.collapsing("Novelty Search", |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Parent Selection:");
+                        ui.radio_value(
+                            &mut params.novelty.selection_mode,
+                            simulation::novelty::SelectionMode::Fitness,
+                            "Fitness",
+                        );
+                        ui.radio_value(
+                            &mut params.novelty.selection_mode,
+                            simulation::novelty::SelectionMode::Novelty,
+                            "Novelty",
+                        );
+                        ui.radio_value(
+                            &mut params.novelty.selection_mode,
+                            simulation::novelty::SelectionMode::Blend,
+                            "Blend",
+                        );
+                    });
+                    if params.novelty.selection_mode == simulation::novelty::SelectionMode::Blend {
+                        ui.add(
+                            egui::Slider::new(&mut params.novelty.novelty_weight, 0.0..=1.0)
+                                .text("Novelty Weight"),
+                        );
+                    }
+                    ui.add(
+                        egui::Slider::new(&mut params.novelty.k_nearest, 1..=50)
+                            .text("K Nearest Neighbours"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.novelty.archive_size, 10..=2000)
+                            .text("Archive Size"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.novelty.archive_add_prob, 0.0..=1.0)
+                            .text("Archive Add Probability"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.novelty.trajectory_samples, 1..=32)
+                            .text("Trajectory Samples"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.novelty.sample_interval, 0.1..=10.0)
+                            .text("Sample Interval (s)"),
+                    );
+                });
+
+                ui.collapsing("Morphology", |ui| {
+                    ui.checkbox(&mut params.morphology.enabled, "Evolve Body & Sensors");
+                    ui.add(
+                        egui::Slider::new(&mut params.morphology.mutation_rate, 0.0..=0.5)
+                            .text("Morphology Mutation Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.morphology.min_scale, 0.1..=1.0)
+                            .text("Min Trait Scale"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.morphology.max_scale, 1.0..=5.0)
+                            .text("Max Trait Scale"),
+                    );
+                    ui.label("Metabolic cost per second:");
+                    ui.add(
+                        egui::Slider::new(&mut params.morphology.body_cost, 0.0..=0.05)
+                            .text("Body Size"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.morphology.speed_cost, 0.0..=0.05)
+                            .text("Speed"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.morphology.vision_cost, 0.0..=0.05)
+                            .text("Vision Range"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.morphology.scent_cost, 0.0..=0.05)
+                            .text("Scent Range"),
+                    );
+                });
+
+                ui.collapsing("Island Migration", |ui| {
+                    ui.checkbox(&mut params.migration.enabled, "Enable Migration");
+                    ui.horizontal(|ui| {
+                        ui.label("Topology:");
+                        ui.radio_value(
+                            &mut params.migration.topology,
+                            MigrationTopology::Ring,
+                            "Ring",
+                        );
+                        ui.radio_value(
+                            &mut params.migration.topology,
+                            MigrationTopology::Star,
+                            "Star",
+                        );
+                        ui.radio_value(
+                            &mut params.migration.topology,
+                            MigrationTopology::FullyConnected,
+                            "Fully Connected",
+                        );
+                        ui.radio_value(
+                            &mut params.migration.topology,
+                            MigrationTopology::Custom,
+                            "Custom",
+                        );
+                    });
+                    if params.migration.topology == MigrationTopology::Custom {
+                        // Keep the matrix sized to the pool count
+                        let n = params.num_genetic_pools;
+                        params.migration.adjacency.resize(n, Vec::new());
+                        for row in &mut params.migration.adjacency {
+                            row.resize(n, false);
+                        }
+                        ui.label("Adjacency (row = from, column = to):");
+                        egui::Grid::new("migration_adjacency").show(ui, |ui| {
+                            for (from, row) in params.migration.adjacency.iter_mut().enumerate() {
+                                ui.label(format!("{}", from));
+                                for (to, connected) in row.iter_mut().enumerate() {
+                                    ui.add_enabled(
+                                        from != to,
+                                        egui::Checkbox::without_text(connected),
+                                    );
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Migrants:");
+                        ui.radio_value(&mut params.migration.policy, MigrantPolicy::Best, "Best");
+                        ui.radio_value(
+                            &mut params.migration.policy,
+                            MigrantPolicy::Random,
+                            "Random",
+                        );
+                        ui.radio_value(
+                            &mut params.migration.policy,
+                            MigrantPolicy::MostNovel,
+                            "Most Novel",
+                        );
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Mode:");
+                        ui.radio_value(&mut params.migration.kind, MigrationKind::Copy, "Copy");
+                        ui.radio_value(&mut params.migration.kind, MigrationKind::Move, "Move");
+                    });
+                    ui.add(
+                        egui::Slider::new(&mut params.migration.rate, 0.0..=0.5)
+                            .text("Migration Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.migration.interval, 1.0..=120.0)
+                            .text("Migration Interval (s)"),
+                    );
+                });
+
+                ui.collapsing("Mate Choice", |ui| {
+                    ui.checkbox(&mut params.mate_choice.enabled, "Enable Mate Preferences");
+                    ui.add(
+                        egui::Slider::new(&mut params.mate_choice.selectivity, 0.0..=20.0)
+                            .text("Selectivity"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.mate_choice.dna_weight, 0.0..=1.0)
+                            .text("DNA Weight"),
+                    );
+                    ui.add(
+                        egui::Slider::new(
+                            &mut params.mate_choice.preference_mutation_rate,
+                            0.0..=0.5,
+                        )
+                        .text("Preference Mutation Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut params.mate_choice.max_proposals, 1..=50)
+                            .text("Max Proposals"),
+                    );
                 });
 
                 ui.collapsing("Population Parameters", |ui| {
@@ -181,9 +474,177 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                         egui::Slider::new(&mut params.food_spawn_rate, 0.1..=10.0)
                             .text("Food Spawn Rate"),
                     );
+                });
+
+                ui.collapsing("Pool Overrides", |ui| {
+                    ui.label("Give a genetic pool its own parameters (e.g. predators and prey)");
+                    let pools = params.num_genetic_pools.max(1);
+                    if params.pool_overrides.len() < pools {
+                        params
+                            .pool_overrides
+                            .resize(pools, PoolOverrides::default());
+                    }
+
+                    // Selected pool tab, kept in egui's memory between frames
+                    let tab_id = ui.id().with("pool_override_tab");
+                    let mut tab = ui
+                        .data(|data| data.get_temp::<usize>(tab_id))
+                        .unwrap_or(0)
+                        .min(pools - 1);
+                    ui.horizontal(|ui| {
+                        for pool_id in 0..pools {
+                            let label = if params.pool_overrides[pool_id].is_empty() {
+                                format!("Pool {}", pool_id)
+                            } else {
+                                format!("Pool {} *", pool_id)
+                            };
+                            ui.selectable_value(&mut tab, pool_id, label);
+                        }
+                    });
+                    ui.data_mut(|data| data.insert_temp(tab_id, tab));
+
+                    if ui
+                        .button("Mix Architectures")
+                        .on_hover_text("Alternate MLP and Transformer brains across pools")
+                        .clicked()
+                    {
+                        params.mix_brain_types(&simulation::brain::BrainType::ALL);
+                    }
+
+                    let global = params.clone();
+                    pool_overrides_editor(ui, &mut params.pool_overrides[tab], &global);
+                });
+
+                ui.collapsing("Food Kinds", |ui| {
+                    for (index, kind) in params.food_kinds.iter_mut().enumerate() {
+                        ui.push_id(index, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.color_edit_button_srgb(&mut kind.color);
+                                if index < 2 {
+                                    ui.label(&kind.name);
+                                } else {
+                                    ui.text_edit_singleline(&mut kind.name);
+                                }
+                            });
+                            ui.add(egui::Slider::new(&mut kind.energy, 0.0..=5.0).text("Energy"));
+                            ui.add(
+                                egui::Slider::new(&mut kind.lifetime, 0.0..=180.0)
+                                    .text("Lifetime (0 = unlimited)"),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut kind.spawn_weight, 0.0..=5.0)
+                                    .text("Spawn Weight"),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut kind.vision_code, -1.0..=1.0)
+                                    .text("Vision Code"),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut kind.scent_channel, 0..=7)
+                                    .text("Scent Channel"),
+                            );
+                            ui.separator();
+                        });
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button("Add Kind").clicked() {
+                            params.food_kinds.push(FoodKindSpec {
+                                name: format!("Custom {}", params.food_kinds.len() - 1),
+                                energy: 1.0,
+                                lifetime: 0.0,
+                                spawn_weight: 0.5,
+                                color: [200, 200, 60],
+                                vision_code: -0.5,
+                                scent_channel: 1,
+                            });
+                        }
+                        // Only the last custom kind can go, so other kinds keep their indices
+                        if params.food_kinds.len() > 2 && ui.button("Remove Last Kind").clicked() {
+                            params.food_kinds.pop();
+                        }
+                    });
+
+                    ui.separator();
+                    ui.checkbox(&mut params.diet.enabled, "Evolve Diets");
+                    ui.add(
+                        egui::Slider::new(&mut params.diet.mutation_rate, 0.0..=0.5)
+                            .text("Diet Mutation Rate"),
+                    );
                     ui.add(
-                        egui::Slider::new(&mut params.food_lifetime, 0.0..=100.0)
-                            .text("Food Lifetime"),
+                        egui::Slider::new(&mut params.diet.tradeoff, 0.0..=4.0)
+                            .text("Specialization Tradeoff"),
+                    );
+                });
+
+                ui.collapsing("Resource Field", |ui| {
+                    let field = &mut params.resource_field;
+                    ui.checkbox(&mut field.enabled, "Grow Food From Fertile Soil");
+                    ui.add(egui::Slider::new(&mut field.cell_size, 10.0..=200.0).text("Cell Size"));
+                    ui.horizontal(|ui| {
+                        ui.label("Fertility:");
+                        ui.radio_value(&mut field.source, FertilitySource::Noise, "Noise");
+                        ui.radio_value(&mut field.source, FertilitySource::Patches, "Patches");
+                    });
+                    match field.source {
+                        FertilitySource::Noise => {
+                            ui.add(
+                                egui::Slider::new(&mut field.noise_scale, 1.0..=20.0)
+                                    .text("Noise Scale (cells)"),
+                            );
+                            ui.add(egui::DragValue::new(&mut field.seed).prefix("Seed: "));
+                        }
+                        FertilitySource::Patches => {
+                            let mut removed = None;
+                            for (index, patch) in field.patches.iter_mut().enumerate() {
+                                ui.push_id(index, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.add(egui::DragValue::new(&mut patch.x).prefix("x: "));
+                                        ui.add(egui::DragValue::new(&mut patch.y).prefix("y: "));
+                                        ui.add(
+                                            egui::DragValue::new(&mut patch.radius)
+                                                .prefix("r: ")
+                                                .range(0.0..=5000.0),
+                                        );
+                                        ui.add(
+                                            egui::DragValue::new(&mut patch.fertility)
+                                                .prefix("fertility: ")
+                                                .speed(0.01)
+                                                .range(0.0..=1.0),
+                                        );
+                                        if ui.button("Remove").clicked() {
+                                            removed = Some(index);
+                                        }
+                                    });
+                                });
+                            }
+                            if let Some(index) = removed {
+                                field.patches.remove(index);
+                            }
+                            if ui.button("Add Patch").clicked() {
+                                field.patches.push(FertilityPatch {
+                                    x: params.box_width / 2.0,
+                                    y: params.box_height / 2.0,
+                                    radius: params.box_width.min(params.box_height) / 4.0,
+                                    fertility: 1.0,
+                                });
+                            }
+                        }
+                    }
+                    let field = &mut params.resource_field;
+                    ui.add(
+                        egui::Slider::new(&mut field.capacity, 0.5..=20.0).text("Cell Capacity"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut field.regrowth_rate, 0.0..=1.0)
+                            .text("Regrowth Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut field.diffusion_rate, 0.0..=0.25)
+                            .text("Diffusion Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut field.decomposition_energy, 0.0..=5.0)
+                            .text("Decomposition Energy"),
                     );
                 });
 
@@ -196,6 +657,203 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                         egui::Slider::new(&mut params.box_height, 100.0..=5000.0)
                             .text("World Height"),
                     );
+                    ui.horizontal(|ui| {
+                        ui.label("Boundary:");
+                        ui.radio_value(&mut params.boundary_mode, BoundaryMode::Torus, "Torus");
+                        ui.radio_value(&mut params.boundary_mode, BoundaryMode::Walls, "Walls");
+                        ui.radio_value(
+                            &mut params.boundary_mode,
+                            BoundaryMode::Reflective,
+                            "Reflective",
+                        );
+                        ui.radio_value(
+                            &mut params.boundary_mode,
+                            BoundaryMode::Absorbing,
+                            "Absorbing",
+                        );
+                    });
+                    if params.boundary_mode == BoundaryMode::Walls {
+                        ui.add(
+                            egui::Slider::new(&mut params.boundary_contact_cost, 0.0..=5.0)
+                                .text("Wall Contact Cost"),
+                        );
+                    }
+                });
+
+                ui.collapsing("Obstacles", |ui| {
+                    ui.checkbox(&mut params.obstacles.enabled, "Enable Obstacles");
+                    ui.label(format!("{} obstacle(s)", params.obstacles.obstacles.len()));
+                    ui.horizontal(|ui| {
+                        if ui.button("Maze Preset").clicked() {
+                            params.obstacles.enabled = true;
+                            params.obstacles.obstacles =
+                                obstacle::maze_preset(params.box_width, params.box_height);
+                        }
+                        if ui.button("Add Rock").clicked() {
+                            let size = params.box_width.min(params.box_height) * 0.1;
+                            params.obstacles.obstacles.push(Obstacle::rectangle(
+                                rand::gen_range(0.0, params.box_width - size),
+                                rand::gen_range(0.0, params.box_height - size),
+                                size,
+                                size,
+                            ));
+                        }
+                        if ui.button("Clear").clicked() {
+                            params.obstacles.obstacles.clear();
+                        }
+                    });
+                });
+
+                ui.collapsing("Pheromones", |ui| {
+                    let pheromones = &mut params.pheromones;
+                    ui.checkbox(&mut pheromones.enabled, "Enable Pheromone Trails");
+                    ui.add(egui::Slider::new(&mut pheromones.channels, 1..=4).text("Channels"));
+                    ui.add(
+                        egui::Slider::new(&mut pheromones.cell_size, 5.0..=100.0).text("Cell Size"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut pheromones.deposit_rate, 0.0..=10.0)
+                            .text("Deposit Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut pheromones.deposit_energy_cost, 0.0..=0.5)
+                            .text("Deposit Energy Cost"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut pheromones.evaporation_rate, 0.0..=2.0)
+                            .text("Evaporation Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut pheromones.diffusion_rate, 0.0..=0.25)
+                            .text("Diffusion Rate"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut pheromones.saturation, 0.1..=10.0)
+                            .text("Sense Saturation"),
+                    );
+                });
+
+                ui.collapsing("Environment Cycles", |ui| {
+                    let env = &mut params.environment;
+                    ui.checkbox(&mut env.enabled, "Enable Day/Night And Seasons");
+                    ui.add(egui::Slider::new(&mut env.day_length, 5.0..=600.0).text("Day Length"));
+                    curve_selector(ui, "Day Curve:", &mut env.day_curve);
+                    ui.add(
+                        egui::Slider::new(&mut env.night_vision_scale, 0.0..=1.0)
+                            .text("Night Vision Scale"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut env.year_length, 30.0..=6000.0).text("Year Length"),
+                    );
+                    curve_selector(ui, "Season Curve:", &mut env.season_curve);
+                    ui.add(
+                        egui::Slider::new(&mut env.winter_food_rate_scale, 0.0..=1.0)
+                            .text("Winter Food Rate Scale"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut env.winter_food_energy_scale, 0.0..=1.0)
+                            .text("Winter Food Energy Scale"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut env.winter_idle_energy_scale, 0.5..=3.0)
+                            .text("Winter Idle Cost Scale"),
+                    );
+                });
+
+                ui.collapsing("Physics", |ui| {
+                    let physics = &mut params.physics;
+                    ui.checkbox(&mut physics.enabled, "Inertial Movement");
+                    ui.label("Movement and rotation outputs become thrust and torque");
+                    if physics.enabled {
+                        ui.horizontal(|ui| {
+                            ui.label("Mass:");
+                            ui.radio_value(&mut physics.mass_mode, MassMode::Fixed, "Fixed");
+                            ui.radio_value(&mut physics.mass_mode, MassMode::Body, "Body Size");
+                            ui.radio_value(
+                                &mut physics.mass_mode,
+                                MassMode::BodyAndEnergy,
+                                "Body + Energy",
+                            );
+                        });
+                        if physics.mass_mode == MassMode::BodyAndEnergy {
+                            ui.add(
+                                egui::Slider::new(&mut physics.energy_mass, 0.0..=2.0)
+                                    .text("Mass per Energy"),
+                            );
+                        }
+                        ui.add(egui::Slider::new(&mut physics.thrust, 10.0..=500.0).text("Thrust"));
+                        ui.add(egui::Slider::new(&mut physics.torque, 1.0..=200.0).text("Torque"));
+                        ui.add(
+                            egui::Slider::new(&mut physics.linear_drag, 0.0..=10.0)
+                                .text("Linear Drag"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut physics.angular_drag, 0.0..=20.0)
+                                .text("Angular Drag"),
+                        );
+                    }
+                });
+
+                ui.collapsing("Collisions", |ui| {
+                    let collision = &mut params.collision;
+                    ui.horizontal(|ui| {
+                        ui.label("Contact:");
+                        ui.radio_value(&mut collision.mode, CollisionMode::Lethal, "Lethal");
+                        ui.radio_value(&mut collision.mode, CollisionMode::Soft, "Soft");
+                        ui.radio_value(&mut collision.mode, CollisionMode::Elastic, "Elastic");
+                        ui.radio_value(&mut collision.mode, CollisionMode::Penalty, "Penalty");
+                    });
+                    match collision.mode {
+                        CollisionMode::Lethal => {}
+                        CollisionMode::Soft => {
+                            ui.add(
+                                egui::Slider::new(&mut collision.stiffness, 0.0..=1.0)
+                                    .text("Stiffness"),
+                            );
+                        }
+                        CollisionMode::Elastic => {
+                            ui.add(
+                                egui::Slider::new(&mut collision.stiffness, 0.0..=1.0)
+                                    .text("Stiffness"),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut collision.restitution, 0.0..=1.0)
+                                    .text("Restitution"),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut collision.damping, 0.0..=10.0)
+                                    .text("Damping"),
+                            );
+                        }
+                        CollisionMode::Penalty => {
+                            ui.add(
+                                egui::Slider::new(&mut collision.energy_penalty, 0.0..=5.0)
+                                    .text("Energy Penalty / s"),
+                            );
+                        }
+                    }
+                });
+
+                ui.collapsing("Contested Resources", |ui| {
+                    let conflicts = &mut params.conflicts;
+                    ui.horizontal(|ui| {
+                        ui.label("Policy:");
+                        ui.radio_value(
+                            &mut conflicts.policy,
+                            ConflictPolicy::NearestWins,
+                            "Nearest Wins",
+                        );
+                        ui.radio_value(
+                            &mut conflicts.policy,
+                            ConflictPolicy::EqualSplit,
+                            "Equal Split",
+                        );
+                        ui.radio_value(&mut conflicts.policy, ConflictPolicy::Random, "Random");
+                    });
+                    ui.label("Settles food, targets and receivers claimed by several at once");
+                    if conflicts.policy == ConflictPolicy::Random {
+                        ui.add(egui::DragValue::new(&mut conflicts.seed).prefix("Seed: "));
+                    }
                 });
 
                 ui.collapsing("Combat Parameters", |ui| {
@@ -223,6 +881,60 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
                         egui::Slider::new(&mut params.projectile_radius, 0.5..=10.0)
                             .text("Projectile Radius"),
                     );
+                    ui.checkbox(
+                        &mut params.projectiles.hit_projectiles,
+                        "Projectiles Destroy Each Other",
+                    );
+                    ui.checkbox(&mut params.projectiles.hit_food, "Projectiles Destroy Food");
+                    if ui
+                        .checkbox(&mut params.combat.enabled, "Health, Armour & Bites")
+                        .changed()
+                    {
+                        // Bites only make sense with combat, so they come and go with it
+                        params.actions.retain(|&kind| kind != ActionKind::Bite);
+                        if params.combat.enabled {
+                            params.actions.push(ActionKind::Bite);
+                        }
+                    }
+                    ui.label("Damage wears down health instead of energy; adds the Bite action");
+                    let combat = &mut params.combat;
+                    if combat.enabled {
+                        ui.add(
+                            egui::Slider::new(&mut combat.max_health, 0.1..=5.0).text("Max Health"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.regen_rate, 0.0..=0.5)
+                                .text("Health Regen / s"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.regen_energy_cost, 0.0..=2.0)
+                                .text("Energy per Health"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.armour_strength, 0.0..=1.0)
+                                .text("Armour Strength"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.armour_cost, 0.0..=0.1)
+                                .text("Armour Cost / s"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.armour_mutation_rate, 0.0..=0.5)
+                                .text("Armour Mutation"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.bite_range, 0.0..=10.0)
+                                .text("Bite Range"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.bite_damage, 0.0..=1.0)
+                                .text("Bite Damage"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut combat.bite_energy, 0.0..=1.0)
+                                .text("Bite Energy Stolen"),
+                        );
+                    }
                 });
 
                 ui.add_space(20.0);
@@ -243,3 +955,132 @@ pub fn draw_genesis_screen(params: &mut Params) -> bool {
 
     start_simulation
 }
+
+/// Radio buttons choosing between a sine cycle and a piecewise cycle with
+/// sharp transitions (long plateaus at both extremes).
+fn curve_selector(ui: &mut egui::Ui, label: &str, curve: &mut CycleCurve) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        if ui.radio(*curve == CycleCurve::Sine, "Sine").clicked() {
+            *curve = CycleCurve::Sine;
+        }
+        if ui
+            .radio(matches!(curve, CycleCurve::Piecewise(_)), "Sharp")
+            .clicked()
+            && !matches!(curve, CycleCurve::Piecewise(_))
+        {
+            *curve = CycleCurve::Piecewise(vec![
+                [0.0, 0.0],
+                [0.2, 0.0],
+                [0.3, 1.0],
+                [0.7, 1.0],
+                [0.8, 0.0],
+            ]);
+        }
+    });
+}
+
+/// Controls for one pool's overrides: each parameter can be ticked to
+/// override the global value shown next to it.
+fn pool_overrides_editor(ui: &mut egui::Ui, overrides: &mut PoolOverrides, global: &Params) {
+    ui.horizontal(|ui| {
+        let mut enabled = overrides.brain_type.is_some();
+        ui.checkbox(&mut enabled, "Brain Type");
+        let brain_type = overrides.brain_type.get_or_insert(global.brain_type);
+        if enabled {
+            ui.radio_value(brain_type, simulation::brain::BrainType::MLP, "MLP");
+            ui.radio_value(
+                brain_type,
+                simulation::brain::BrainType::Transformer,
+                "Transformer",
+            );
+        } else {
+            overrides.brain_type = None;
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let mut enabled = overrides.hidden_layers.is_some();
+        ui.checkbox(&mut enabled, "Hidden Layers");
+        if enabled {
+            let global_hidden = &global.layer_sizes[1..global.layer_sizes.len().saturating_sub(1)];
+            let hidden = overrides
+                .hidden_layers
+                .get_or_insert_with(|| global_hidden.to_vec());
+            for size in hidden.iter_mut() {
+                ui.add(egui::DragValue::new(size).range(1..=512));
+            }
+            if ui.small_button("+").clicked() {
+                hidden.push(hidden.last().copied().unwrap_or(32));
+            }
+            if !hidden.is_empty() && ui.small_button("-").clicked() {
+                hidden.pop();
+            }
+        } else {
+            overrides.hidden_layers = None;
+        }
+    });
+
+    override_slider(
+        ui,
+        &mut overrides.move_multiplier,
+        global.move_multiplier,
+        10.0..=200.0,
+        "Move Multiplier",
+    );
+    override_slider(
+        ui,
+        &mut overrides.attack_cost_rate,
+        global.attack_cost_rate,
+        0.0..=1.0,
+        "Attack Cost Rate",
+    );
+    override_slider(
+        ui,
+        &mut overrides.attack_damage_rate,
+        global.attack_damage_rate,
+        0.0..=2.0,
+        "Attack Damage Rate",
+    );
+    override_slider(
+        ui,
+        &mut overrides.vision_radius,
+        global.vision_radius,
+        10.0..=200.0,
+        "Vision Radius",
+    );
+    override_slider(
+        ui,
+        &mut overrides.dna_mutation_rate,
+        global.dna_mutation_rate,
+        0.0..=0.5,
+        "DNA Mutation Rate",
+    );
+    override_slider(
+        ui,
+        &mut overrides.brain_mutation_rate,
+        global.brain_mutation_rate,
+        0.0..=0.5,
+        "Brain Mutation Rate",
+    );
+}
+
+/// A checkbox that overrides `global` with a slider value while ticked.
+fn override_slider(
+    ui: &mut egui::Ui,
+    value: &mut Option<f32>,
+    global: f32,
+    range: std::ops::RangeInclusive<f32>,
+    text: &str,
+) {
+    ui.horizontal(|ui| {
+        let mut enabled = value.is_some();
+        ui.checkbox(&mut enabled, text);
+        if enabled {
+            ui.add(egui::Slider::new(value.get_or_insert(global), range));
+        } else {
+            *value = None;
+            ui.label(format!("{:.3}", global));
+        }
+    });
+}
@@ -5,6 +5,7 @@ mod genesis;
 mod nn;
 mod organisms;
 mod stats;
+#[allow(clippy::module_inception)]
 mod ui;
 
 // Re-export the public interface
@@ -15,6 +15,11 @@ pub(super) fn draw_neural_network(
 
     layer_activations.push(current_activation.clone());
 
+    // Input labels come from the senses that produced the inputs
+    let input_labels = simulation::organism::Perception::from_params(params).input_labels(params);
+    // Output labels come from the actions that read the outputs
+    let output_labels = simulation::actions::Actuation::from_params(params).output_labels(params);
+
     // Forward pass through all layers based on brain type
     match &organism.brain {
         simulation::brain::Brain::MLP { layers } => {
@@ -177,9 +182,9 @@ pub(super) fn draw_neural_network(
             // Add labels for input and output neurons
             if is_input_layer || is_output_layer {
                 let label = if is_input_layer {
-                    super::organisms::get_input_label(neuron_idx, params)
+                    input_labels.get(neuron_idx).cloned()
                 } else {
-                    super::organisms::get_output_label(neuron_idx, params)
+                    output_labels.get(neuron_idx).cloned()
                 };
 
                 if let Some(label_text) = label {
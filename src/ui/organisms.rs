@@ -24,6 +24,12 @@ pub(super) fn draw_organism_detail_panel(
             }
             ui.label(format!("Age: {:.2}", organism.age));
             ui.label(format!("Energy: {:.3}", organism.energy));
+            if params.combat.enabled {
+                ui.label(format!(
+                    "Health: {:.2}/{:.2}, Armour: {:.2}",
+                    organism.health, params.combat.max_health, organism.morphology.armour
+                ));
+            }
             ui.label(format!("Score: {}", organism.score));
             ui.label(format!(
                 "Position: ({:.1}, {:.1})",
@@ -31,6 +37,28 @@ pub(super) fn draw_organism_detail_panel(
             ));
             ui.label(format!("Rotation: {:.2}", organism.rot));
             ui.label(format!("Genetic Pool: {}", organism.pool_id));
+            ui.label(format!(
+                "Morphology: body x{:.2}, speed x{:.2}, scent x{:.2}",
+                organism.morphology.body_scale,
+                organism.morphology.speed_scale,
+                organism.morphology.scent_scale
+            ));
+            if params.diet.enabled {
+                let efficiencies: Vec<String> = params
+                    .food_kinds
+                    .iter()
+                    .enumerate()
+                    .map(|(index, kind)| {
+                        let efficiency = simulation::diet::efficiency(
+                            organism,
+                            simulation::food::FoodKind::from_index(index),
+                            params,
+                        );
+                        format!("{} {:.0}%", kind.name, efficiency * 100.0)
+                    })
+                    .collect();
+                ui.label(format!("Diet: {}", efficiencies.join(", ")));
+            }
             let brain_type_str = match organism.brain.brain_type() {
                 simulation::brain::BrainType::MLP => "MLP",
                 simulation::brain::BrainType::Transformer => "Transformer",
@@ -231,99 +259,6 @@ fn draw_signal_bars(ui: &mut egui::Ui, signal: &ndarray::Array1<f32>) {
     });
 }
 
-pub(super) fn get_input_label(neuron_idx: usize, params: &Params) -> Option<String> {
-    // Input structure: vision rays (distance+pool_match+is_organism for each direction) + scent (signal) + memory + energy + rotation + position
-    // vision: 3 * num_vision_directions
-    // scent: signal_size
-    // memory: memory_size
-    // energy: 1
-    // rotation: 2 (sin, cos)
-    // position: 4 (sin_x, cos_x, sin_y, cos_y)
-
-    let vision_inputs = 3 * params.num_vision_directions;
-    let scent_start = vision_inputs;
-    let scent_end = scent_start + params.signal_size;
-    let memory_start = scent_end;
-    let memory_end = memory_start + params.memory_size;
-    let energy_idx = memory_end;
-    let rotation_start = energy_idx + 1;
-    let rotation_end = rotation_start + 2;
-    let position_start = rotation_end;
-    let position_end = position_start + 4;
-
-    if neuron_idx < vision_inputs {
-        let direction = neuron_idx / 3;
-        let offset = neuron_idx % 3;
-        if offset == 0 {
-            Some(format!("V{} D", direction)) // Distance
-        } else if offset == 1 {
-            Some(format!("V{} P", direction)) // Pool match
-        } else {
-            Some(format!("V{} T", direction)) // Type (organism vs food)
-        }
-    } else if neuron_idx < scent_end {
-        let signal_idx = neuron_idx - scent_start;
-        Some(format!("Scent {}", signal_idx))
-    } else if neuron_idx < memory_end {
-        let mem_idx = neuron_idx - memory_start;
-        Some(format!("Mem {}", mem_idx))
-    } else if neuron_idx == energy_idx {
-        Some("Energy".to_string())
-    } else if neuron_idx < rotation_end {
-        let offset = neuron_idx - rotation_start;
-        if offset == 0 {
-            Some("Rot Sin".to_string())
-        } else {
-            Some("Rot Cos".to_string())
-        }
-    } else if neuron_idx < position_end {
-        let offset = neuron_idx - position_start;
-        match offset {
-            0 => Some("Pos X Sin".to_string()),
-            1 => Some("Pos X Cos".to_string()),
-            2 => Some("Pos Y Sin".to_string()),
-            3 => Some("Pos Y Cos".to_string()),
-            _ => None,
-        }
-    } else {
-        None
-    }
-}
-
-pub(super) fn get_output_label(neuron_idx: usize, params: &Params) -> Option<String> {
-    // Output structure: signal + memory + rotation + acceleration + attack + share
-    // signal: signal_size
-    // memory: memory_size
-    // rotation: 1
-    // acceleration: 1
-    // attack: 1
-    // share: 1
-
-    let signal_end = params.signal_size;
-    let memory_end = signal_end + params.memory_size;
-    let rotation_idx = memory_end;
-    let accel_idx = rotation_idx + 1;
-    let attack_idx = accel_idx + 1;
-    let share_idx = attack_idx + 1;
-
-    if neuron_idx < signal_end {
-        Some(format!("Signal {}", neuron_idx))
-    } else if neuron_idx < memory_end {
-        let mem_idx = neuron_idx - signal_end;
-        Some(format!("Mem {}", mem_idx))
-    } else if neuron_idx == rotation_idx {
-        Some("Rotation".to_string())
-    } else if neuron_idx == accel_idx {
-        Some("Accel".to_string())
-    } else if neuron_idx == attack_idx {
-        Some("Attack".to_string())
-    } else if neuron_idx == share_idx {
-        Some("Share".to_string())
-    } else {
-        None
-    }
-}
-
 /// Visualizes transformer architecture with weight heatmaps
 fn draw_transformer_visualization(
     ui: &mut egui::Ui,
@@ -4,7 +4,7 @@ use egui_macroquad::egui;
 use egui_plot::{Line, Plot, PlotPoints};
 use std::collections::VecDeque;
 
-use super::ui::UIState;
+use super::ui::{ArchitectureHistory, UIState};
 
 /// Get a distinct color for each genetic pool matching the organism rendering colors
 fn get_pool_color(pool_id: usize) -> egui::Color32 {
@@ -56,6 +56,21 @@ pub(super) fn draw_stats_panel(
                 }
             });
 
+            // Pheromone overlay channel
+            if params.pheromones.enabled {
+                ui.horizontal(|ui| {
+                    ui.label("Pheromones:");
+                    ui.radio_value(&mut state.pheromone_overlay, None, "Off");
+                    for channel in 0..params.pheromones.channels {
+                        ui.radio_value(
+                            &mut state.pheromone_overlay,
+                            Some(channel),
+                            channel.to_string(),
+                        );
+                    }
+                });
+            }
+
             // Show status message if any
             if let Some(ref msg) = state.status_message {
                 ui.label(msg);
@@ -78,10 +93,7 @@ pub(super) fn draw_stats_panel(
             ui.collapsing("Timing Breakdown", |ui| {
                 let timing = &ecosystem.timing_stats;
                 ui.label(format!("Spatial index: {:.2}ms", timing.spatial_index_ms));
-                ui.label(format!(
-                    "Ecosystem clone: {:.2}ms",
-                    timing.ecosystem_clone_ms
-                ));
+                ui.label(format!("World view: {:.2}ms", timing.world_view_ms));
                 ui.label(format!(
                     "Parallel update: {:.2}ms",
                     timing.parallel_update_ms
@@ -95,6 +107,14 @@ pub(super) fn draw_stats_panel(
             ui.separator();
 
             ui.label(format!("Time: {:.1}s", ecosystem.time));
+            if params.environment.enabled {
+                let conditions = simulation::environment::conditions(ecosystem.time, params);
+                ui.label(format!(
+                    "Daylight: {:.0}%, Warmth: {:.0}%",
+                    conditions.daylight * 100.0,
+                    conditions.warmth * 100.0
+                ));
+            }
             ui.label(format!("Generation: {}", ecosystem.generation));
             ui.separator();
 
@@ -119,8 +139,33 @@ pub(super) fn draw_stats_panel(
                     "Top Graveyard: fitness={:.1} (age={:.1}, score={})",
                     top_fitness, top_age, top_score
                 ));
+
+                let max_novelty = ecosystem
+                    .graveyard()
+                    .iter()
+                    .map(|o| o.novelty)
+                    .fold(0.0f32, f32::max);
+                ui.label(format!("Max Graveyard Novelty: {:.3}", max_novelty));
+            }
+            ui.label(format!(
+                "Novelty Archive: {}/{}",
+                ecosystem.novelty_archive().len(),
+                params.novelty.archive_size
+            ));
+            if params.combat.enabled {
+                let kills = &ecosystem.kill_log;
+                ui.label(format!(
+                    "Recent Kills: {} by projectile, {} by bite",
+                    kills.count(simulation::combat::DamageSource::Projectile),
+                    kills.count(simulation::combat::DamageSource::Bite)
+                ));
             }
 
+            // Where energy came from and went
+            ui.collapsing("Energy Flows", |ui| {
+                draw_energy_flows(ui, state, ecosystem, params);
+            });
+
             // Show pool populations
             if params.num_genetic_pools > 1 {
                 ui.separator();
@@ -131,7 +176,17 @@ pub(super) fn draw_stats_panel(
                         .iter()
                         .filter(|org| org.pool_id == pool_id)
                         .count();
-                    ui.label(format!("  Pool {}: {}", pool_id, pool_count));
+                    if params.migration.enabled {
+                        ui.label(format!(
+                            "  Pool {}: {} (in {}, out {})",
+                            pool_id,
+                            pool_count,
+                            ecosystem.migration.inflow(pool_id),
+                            ecosystem.migration.outflow(pool_id)
+                        ));
+                    } else {
+                        ui.label(format!("  Pool {}: {}", pool_id, pool_count));
+                    }
                 }
             }
 
@@ -140,29 +195,29 @@ pub(super) fn draw_stats_panel(
             // Reproduction Strategy Effectiveness (based on recent deaths)
             ui.label("Reproduction Strategy Stats:");
             ui.label("(avg final score - parent score)");
-            let stats = &ecosystem.reproduction_stats;
+            let repro_stats = &ecosystem.reproduction_stats;
 
-            if stats.asexual_count() > 0 {
+            if repro_stats.asexual_count() > 0 {
                 ui.label(format!(
                     "  Asexual (n={}): {:.2}",
-                    stats.asexual_count(),
-                    stats.avg_asexual_delta()
+                    repro_stats.asexual_count(),
+                    repro_stats.avg_asexual_delta()
                 ));
             }
 
-            if stats.sexual_count() > 0 {
+            if repro_stats.sexual_count() > 0 {
                 ui.label(format!(
                     "  Sexual (n={}): {:.2}",
-                    stats.sexual_count(),
-                    stats.avg_sexual_delta()
+                    repro_stats.sexual_count(),
+                    repro_stats.avg_sexual_delta()
                 ));
             }
 
-            if stats.interpool_count() > 0 {
+            if repro_stats.interpool_count() > 0 {
                 ui.label(format!(
                     "  Inter-Pool (n={}): {:.2}",
-                    stats.interpool_count(),
-                    stats.avg_interpool_delta()
+                    repro_stats.interpool_count(),
+                    repro_stats.avg_interpool_delta()
                 ));
             }
 
@@ -214,17 +269,16 @@ pub(super) fn draw_stats_panel(
 
                 ui.separator();
                 ui.label("Food");
-                ui.add(
-                    egui::Slider::new(&mut params.food_lifetime, 0.0..=180.0).text("Lifetime (s)"),
-                );
-                ui.label("(0 = unlimited)");
-
-                ui.separator();
-                ui.label("Other");
-                ui.add(
-                    egui::Slider::new(&mut params.corpse_energy_ratio, 0.0..=1.0)
-                        .text("Corpse Energy"),
-                );
+                for kind in &mut params.food_kinds {
+                    ui.push_id(&kind.name, |ui| {
+                        ui.label(&kind.name);
+                        ui.add(egui::Slider::new(&mut kind.energy, 0.0..=5.0).text("Energy"));
+                        ui.add(
+                            egui::Slider::new(&mut kind.lifetime, 0.0..=180.0).text("Lifetime (s)"),
+                        );
+                    });
+                }
+                ui.label("(lifetime 0 = unlimited)");
             });
 
             ui.separator();
@@ -265,6 +319,14 @@ pub(super) fn draw_stats_panel(
                     ui.separator();
                 }
 
+                // Brain architectures competing in the same world
+                let architectures = simulation::brain::architecture_stats(&ecosystem.organisms);
+                if architectures.len() > 1 {
+                    ui.heading("Architectures");
+                    draw_architecture_section(ui, state, &architectures);
+                    ui.separator();
+                }
+
                 ui.separator();
             }
 
@@ -280,10 +342,69 @@ pub(super) fn draw_stats_panel(
                 draw_pool_scores_plot(ui, state, params);
                 ui.separator();
             }
+
+            // Sexual selection: preferred vs displayed signal
+            if params.mate_choice.enabled {
+                ui.heading("Preference–Display Correlation");
+                draw_time_series_plot(
+                    ui,
+                    "preference_correlation_plot",
+                    &state.preference_correlation_history,
+                    "Time",
+                    "Correlation",
+                );
+                ui.separator();
+            }
         });
 }
 
-#[allow(dead_code)]
+fn draw_energy_flows(
+    ui: &mut egui::Ui,
+    state: &mut UIState,
+    ecosystem: &simulation::ecosystem::Ecosystem,
+    params: &Params,
+) {
+    use simulation::energy::Flow;
+
+    let ledger = &ecosystem.energy_ledger;
+    let (step, total) = (ledger.last_step(), ledger.total());
+    ui.label(format!(
+        "Stock: {:.1} (organisms {:.1}, food {:.1})",
+        simulation::energy::stock(ecosystem),
+        ecosystem.organisms.iter().map(|o| o.energy).sum::<f32>(),
+        ecosystem.food.iter().map(|f| f.energy).sum::<f32>()
+    ));
+    ui.label("Flow: last step / total");
+    for flow in Flow::ALL {
+        if total.total(flow).abs() > f32::EPSILON || step.total(flow).abs() > f32::EPSILON {
+            ui.label(format!(
+                "  {}: {:+.3} / {:+.1}",
+                flow.name(),
+                step.total(flow),
+                total.total(flow)
+            ));
+        }
+    }
+    ui.label(format!("Net: {:+.3} / {:+.1}", step.net(), total.net()));
+    ui.label(format!("Unaccounted: {:+.5}", ledger.imbalance()));
+
+    if params.num_genetic_pools > 1 {
+        ui.label("Net per pool (total):");
+        for pool_id in 0..params.num_genetic_pools {
+            ui.label(format!(
+                "  Pool {}: {:+.1}",
+                pool_id,
+                total.pool(pool_id).net()
+            ));
+        }
+    }
+    ui.label(format!("Food (total): {:+.1}", total.food.net()));
+
+    if ui.button("📤 Export Ledger").clicked() {
+        state.ledger_export_requested = true;
+    }
+}
+
 fn draw_time_series_plot(
     ui: &mut egui::Ui,
     id: &str,
@@ -375,6 +496,76 @@ fn draw_pool_ages_plot(ui: &mut egui::Ui, state: &UIState, params: &Params) {
         });
 }
 
+fn draw_architecture_section(
+    ui: &mut egui::Ui,
+    state: &UIState,
+    architectures: &[simulation::brain::ArchitectureStats],
+) {
+    egui::Grid::new("architecture_grid")
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Brain");
+            ui.label("Count");
+            ui.label("Fitness");
+            ui.label("Age");
+            ui.label("Cost");
+            ui.end_row();
+            for stats in architectures {
+                ui.label(stats.brain_type.name());
+                ui.label(stats.count.to_string());
+                ui.label(format!("{:.1}", stats.avg_fitness));
+                ui.label(format!("{:.1}", stats.avg_age));
+                ui.label(format!("{:.0}", stats.avg_compute_cost));
+                ui.end_row();
+            }
+        });
+    ui.label("(cost = multiply-adds per thought)");
+
+    ui.label("Average Fitness Over Time");
+    draw_architecture_plot(ui, "architecture_fitness_plot", "Fitness", state, |h| {
+        &h.fitness
+    });
+    ui.label("Average Age Over Time");
+    draw_architecture_plot(ui, "architecture_age_plot", "Age", state, |h| &h.age);
+    ui.label("Average Compute Cost Over Time");
+    draw_architecture_plot(ui, "architecture_cost_plot", "Cost", state, |h| {
+        &h.compute_cost
+    });
+}
+
+fn draw_architecture_plot(
+    ui: &mut egui::Ui,
+    id: &str,
+    y_label: &str,
+    state: &UIState,
+    select: impl Fn(&ArchitectureHistory) -> &VecDeque<(f64, f64)>,
+) {
+    Plot::new(id)
+        .height(150.0)
+        .show_axes([true, true])
+        .label_formatter(|name, value| {
+            format!(
+                "{}: Time: {:.1}s, {}: {:.1}",
+                name, value.x, y_label, value.y
+            )
+        })
+        .show(ui, |plot_ui| {
+            for (index, history) in state.architecture_histories.iter().enumerate() {
+                let data = select(history);
+                if data.is_empty() {
+                    continue;
+                }
+                let points: PlotPoints = data.iter().map(|&(x, y)| [x, y]).collect();
+                let brain_type = simulation::brain::BrainType::ALL[index];
+                plot_ui.line(
+                    Line::new(points)
+                        .color(get_pool_color(index))
+                        .name(brain_type.name()),
+                );
+            }
+        });
+}
+
 fn draw_population_plot(
     ui: &mut egui::Ui,
     organism_data: &VecDeque<(f64, f64)>,
@@ -5,6 +5,14 @@ use std::collections::VecDeque;
 
 const MAX_HISTORY_POINTS: usize = 500;
 
+/// History of one brain architecture's averages, for comparing architectures.
+#[derive(Default)]
+pub struct ArchitectureHistory {
+    pub fitness: VecDeque<(f64, f64)>,
+    pub age: VecDeque<(f64, f64)>,
+    pub compute_cost: VecDeque<(f64, f64)>,
+}
+
 #[allow(clippy::struct_excessive_bools)]
 pub struct UIState {
     pub hovered_organism_id: Option<usize>,
@@ -15,14 +23,18 @@ pub struct UIState {
     pub food_count_history: VecDeque<(f64, f64)>,
     pub pool_score_histories: Vec<VecDeque<(f64, f64)>>, // One history per pool
     pub pool_age_histories: Vec<VecDeque<(f64, f64)>>,   // Average age per pool
+    pub preference_correlation_history: VecDeque<(f64, f64)>,
+    pub architecture_histories: Vec<ArchitectureHistory>, // Indexed like BrainType::ALL
     last_update_time: f32,
     update_interval: f32,
     pub save_requested: bool,
     pub load_requested: bool,
     pub reset_requested: bool,
+    pub ledger_export_requested: bool,
     pub status_message: Option<String>,
     pub simulation_speed: f32,
     pub rendering_enabled: bool,
+    pub pheromone_overlay: Option<usize>,
     plot_time_counter: f64,
     pub last_step_time_ms: f32,
     pub actual_steps_per_sec: f32,
@@ -39,14 +51,18 @@ impl UIState {
             food_count_history: VecDeque::new(),
             pool_score_histories: Vec::new(),
             pool_age_histories: Vec::new(),
+            preference_correlation_history: VecDeque::new(),
+            architecture_histories: Vec::new(),
             last_update_time: 0.0,
             update_interval: 0.5, // Update every 0.5 seconds
             save_requested: false,
             load_requested: false,
             reset_requested: false,
+            ledger_export_requested: false,
             status_message: None,
             simulation_speed: 1.0, // Default 1x speed
             rendering_enabled: true,
+            pheromone_overlay: None,
             plot_time_counter: 0.0,
             last_step_time_ms: 0.0,
             actual_steps_per_sec: 0.0,
@@ -61,7 +77,11 @@ impl UIState {
         self.plot_time_counter = 0.0;
     }
 
-    pub fn update_history(&mut self, ecosystem: &simulation::ecosystem::Ecosystem) {
+    pub fn update_history(
+        &mut self,
+        ecosystem: &simulation::ecosystem::Ecosystem,
+        params: &Params,
+    ) {
         if ecosystem.time - self.last_update_time >= self.update_interval {
             self.last_update_time = ecosystem.time;
 
@@ -83,6 +103,42 @@ impl UIState {
                 }
             }
 
+            // Track how well mate preferences match displayed signals
+            if let Some(correlation) = simulation::mate_choice::preference_display_correlation(
+                &ecosystem.organisms,
+                params.signal_size,
+            ) {
+                self.preference_correlation_history
+                    .push_back((ecosystem.time as f64, correlation as f64));
+
+                if self.preference_correlation_history.len() > MAX_HISTORY_POINTS {
+                    self.preference_correlation_history.pop_front();
+                }
+            }
+
+            // Compare brain architectures competing in the world
+            if self.architecture_histories.is_empty() {
+                self.architecture_histories = simulation::brain::BrainType::ALL
+                    .iter()
+                    .map(|_| ArchitectureHistory::default())
+                    .collect();
+            }
+            let time = ecosystem.time as f64;
+            for stats in simulation::brain::architecture_stats(&ecosystem.organisms) {
+                let index = stats.brain_type as usize;
+                let history = &mut self.architecture_histories[index];
+                for (series, value) in [
+                    (&mut history.fitness, stats.avg_fitness),
+                    (&mut history.age, stats.avg_age as f64),
+                    (&mut history.compute_cost, stats.avg_compute_cost as f64),
+                ] {
+                    series.push_back((time, value));
+                    if series.len() > MAX_HISTORY_POINTS {
+                        series.pop_front();
+                    }
+                }
+            }
+
             if self.organism_count_history.len() > MAX_HISTORY_POINTS {
                 self.organism_count_history.pop_front();
             }
@@ -0,0 +1,114 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::actions::{ActionContext, ActionKind, Actuation};
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::SimulationEvent;
+use evo::simulation::params::Params;
+use ndarray::Array1;
+
+fn act(actuation: &Actuation, outputs: Vec<f32>, params: &Params) -> Vec<SimulationEvent> {
+    let mut ecosystem = Ecosystem::new(params);
+    let entity = &mut ecosystem.organisms[0];
+    entity.energy = params.max_energy;
+    let context = ActionContext {
+        neighbors_orgs: &[],
+        neighbor_foods: &[],
+        organisms: &[],
+        food_items: &[],
+    };
+    actuation.execute(entity, &Array1::from_vec(outputs), &context, params, 0.1)
+}
+
+#[test]
+fn test_default_actions_keep_classic_output_layout() {
+    let mut params = common::small_test_params();
+    params.pheromones.enabled = true;
+    let actuation = Actuation::from_params(&params);
+
+    let classic = params.signal_size + params.memory_size + 6 + params.pheromones.channels;
+    assert_eq!(actuation.total_output_size(&params), classic);
+
+    let labels = actuation.output_labels(&params);
+    let offset = params.signal_size + params.memory_size;
+    assert_eq!(labels[0], "Signal 0");
+    assert_eq!(labels[params.signal_size], "Mem 0");
+    assert_eq!(
+        labels[offset..offset + 6],
+        ["Rotation", "Accel", "Attack", "Share", "Asexual", "Sexual"]
+    );
+    assert_eq!(labels.last().unwrap(), "Deposit 1");
+}
+
+#[test]
+fn test_actuation_follows_params_actions() {
+    let mut params = common::small_test_params();
+    params.actions = vec![ActionKind::Move, ActionKind::Eat, ActionKind::Signal];
+    let actuation = Actuation::from_params(&params);
+
+    let names: Vec<&str> = actuation.actions().iter().map(|a| a.name()).collect();
+    assert_eq!(names, ["Move", "Eat", "Signal"]);
+    assert_eq!(actuation.total_output_size(&params), 1 + params.signal_size);
+    assert_eq!(actuation.output_labels(&params)[..2], ["Accel", "Signal 0"]);
+}
+
+#[test]
+fn test_every_action_labels_each_output() {
+    let mut params = common::small_test_params();
+    params.pheromones.enabled = true;
+
+    for kind in ActionKind::ALL {
+        let action = kind.build();
+        assert_eq!(action.name(), kind.name());
+        assert_eq!(
+            action.output_labels(&params).len(),
+            action.output_size(&params),
+            "{}",
+            kind.name()
+        );
+    }
+}
+
+#[test]
+fn test_actions_read_their_own_outputs() {
+    let mut params = common::small_test_params();
+    params.actions = vec![ActionKind::Rotate, ActionKind::Attack];
+
+    let events = act(&Actuation::from_params(&params), vec![0.0, 1.0], &params);
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, SimulationEvent::ProjectileCreated { .. }))
+    );
+}
+
+#[test]
+fn test_removed_action_never_fires() {
+    let mut params = common::small_test_params();
+    params.actions = vec![ActionKind::Rotate, ActionKind::Move];
+
+    // Brains only have outputs for the remaining actions, so nothing attacks
+    let actuation = Actuation::from_params(&params);
+    let outputs = vec![1.0; actuation.total_output_size(&params)];
+    let events = act(&actuation, outputs, &params);
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_behaviour_slots_follow_action_kinds() {
+    let slots: Vec<(ActionKind, usize)> = ActionKind::ALL
+        .into_iter()
+        .filter_map(|kind| kind.build().behaviour_slot().map(|slot| (kind, slot)))
+        .collect();
+    assert_eq!(
+        slots,
+        vec![
+            (ActionKind::Rotate, 0),
+            (ActionKind::Move, 1),
+            (ActionKind::Attack, 2),
+            (ActionKind::Share, 3),
+        ]
+    );
+}
@@ -0,0 +1,100 @@
+#![allow(missing_docs)]
+
+mod common;
+
+use evo::simulation::brain::{self, Brain, BrainType};
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::params::Params;
+
+fn transformer_like(brain: &Brain, params: &Params) -> Brain {
+    let Brain::MLP { layers } = brain else {
+        panic!("expected an MLP brain");
+    };
+    Brain::new_transformer(
+        layers[0].weights.dim().1,
+        layers.last().unwrap().weights.dim().0,
+        params.transformer_model_dim,
+        params.transformer_num_blocks,
+        params.transformer_num_heads,
+        params.transformer_head_dim,
+        params.transformer_ff_dim,
+        0.1,
+    )
+}
+
+#[test]
+fn test_mismatched_crossover_is_refused_with_reason() {
+    let params = common::small_test_params();
+    let mlp = Brain::new(&params.layer_sizes, 0.1);
+    let transformer = transformer_like(&mlp, &params);
+
+    let error = Brain::crossover(&mlp, &transformer).unwrap_err();
+    let reason = error.to_string();
+    assert!(reason.contains("MLP [33, 16, 17]"), "{reason}");
+    assert!(
+        reason.contains("Transformer [33 -> 64 x2 -> 17]"),
+        "{reason}"
+    );
+    assert!(Brain::crossover_weighted(&transformer, &mlp, 0.5).is_err());
+
+    assert!(Brain::distance(&mlp, &transformer).is_none());
+    assert!(Brain::distance(&mlp, &mlp.clone()).unwrap().abs() < f32::EPSILON);
+    assert!(Brain::crossover(&mlp, &mlp.clone()).is_ok());
+}
+
+#[test]
+fn test_compute_cost_counts_multiply_adds() {
+    let params = common::small_test_params();
+    let mlp = Brain::new(&[10, 8, 4], 0.1);
+    assert_eq!(mlp.compute_cost(), 10 * 8 + 8 * 4);
+
+    // A transformer costs at least its embedding and projection
+    let transformer = transformer_like(&Brain::new(&params.layer_sizes, 0.1), &params);
+    assert!(transformer.compute_cost() > 33 * 64 + 64 * 17);
+}
+
+#[test]
+fn test_mixed_pools_compete_with_per_architecture_stats() {
+    let mut params = common::small_test_params();
+    params.num_genetic_pools = 4;
+    params.n_organism = 20;
+    params.mix_brain_types(&BrainType::ALL);
+
+    let expected: Vec<Option<BrainType>> = params
+        .pool_overrides
+        .iter()
+        .map(|overrides| overrides.brain_type)
+        .collect();
+    assert_eq!(
+        expected,
+        vec![
+            Some(BrainType::MLP),
+            Some(BrainType::Transformer),
+            Some(BrainType::MLP),
+            Some(BrainType::Transformer),
+        ]
+    );
+
+    let mut ecosystem = Ecosystem::new(&params);
+    for _ in 0..20 {
+        ecosystem.step(&params, 0.1);
+    }
+
+    let stats = brain::architecture_stats(&ecosystem.organisms);
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].brain_type, BrainType::MLP);
+    assert_eq!(stats[1].brain_type, BrainType::Transformer);
+    assert_eq!(
+        stats.iter().map(|s| s.count).sum::<usize>(),
+        ecosystem.organisms.len()
+    );
+    assert!(stats[1].avg_compute_cost > stats[0].avg_compute_cost);
+    for organism in &ecosystem.organisms {
+        let expected = if organism.pool_id % 2 == 0 {
+            BrainType::MLP
+        } else {
+            BrainType::Transformer
+        };
+        assert_eq!(organism.brain.brain_type(), expected);
+    }
+}
@@ -0,0 +1,133 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::boundary::{self, BoundaryMode};
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::organism::{Scent, Sense};
+use evo::simulation::projectile::Projectile;
+use evo::simulation::spatial::{Neighbourhood, SpatialIndex};
+use evo::simulation::view::WorldView;
+use ndarray::Array1;
+
+fn point(x: f32, y: f32) -> Array1<f32> {
+    Array1::from_vec(vec![x, y])
+}
+
+#[test]
+fn test_torus_distance_crosses_the_seam() {
+    let mut params = common::test_params();
+    let (a, b) = (point(5.0, 500.0), point(995.0, 500.0));
+
+    assert!((boundary::distance(&a, &b, &params) - 10.0).abs() < 1e-4);
+    assert!((boundary::nearest_image(&a, &b, &params)[0] + 5.0).abs() < 1e-4);
+
+    params.boundary_mode = BoundaryMode::Walls;
+    assert!((boundary::distance(&a, &b, &params) - 990.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_spatial_queries_see_across_the_seam_only_on_a_torus() {
+    let mut params = common::test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms.truncate(2);
+    ecosystem.organisms[0].pos = point(5.0, 5.0);
+    ecosystem.organisms[1].pos = point(995.0, 995.0);
+    let index = SpatialIndex::build(&ecosystem, &params);
+
+    let found = index.query_organisms(&point(5.0, 5.0), 20.0);
+    assert_eq!(found.len(), 2);
+    assert!(
+        found
+            .iter()
+            .any(|&(dist_sq, idx)| idx == 1 && (dist_sq - 200.0).abs() < 1e-2)
+    );
+
+    params.boundary_mode = BoundaryMode::Reflective;
+    let index = SpatialIndex::build(&ecosystem, &params);
+    let found = index.query_organisms(&point(5.0, 5.0), 20.0);
+    assert_eq!(found.len(), 1);
+}
+
+#[test]
+fn test_scent_crosses_the_seam() {
+    let mut params = common::test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.food.clear();
+    ecosystem.organisms.truncate(2);
+    ecosystem.organisms[0].pos = point(2.0, 500.0);
+    ecosystem.organisms[0].signal = Array1::zeros(params.signal_size);
+    ecosystem.organisms[1].pos = point(995.0, 500.0);
+    ecosystem.organisms[1].signal = Array1::ones(params.signal_size);
+
+    let scent = Scent::new().sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert!(scent[0] > 0.0);
+
+    params.boundary_mode = BoundaryMode::Walls;
+    let scent = Scent::new().sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert_eq!(scent[0], 0.0);
+}
+
+#[test]
+fn test_walls_clamp_and_cost_energy() {
+    let mut params = common::test_params();
+    params.boundary_mode = BoundaryMode::Walls;
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.pos = point(1010.0, 500.0);
+    organism.energy = 1.0;
+
+    boundary::apply_to_organism(organism, &params, 0.1);
+
+    assert_eq!(organism.pos[0], 1000.0);
+    assert!((organism.energy - (1.0 - params.boundary_contact_cost * 0.1)).abs() < 1e-6);
+    assert!(organism.is_alive());
+}
+
+#[test]
+fn test_reflective_edges_bounce() {
+    let mut params = common::test_params();
+    params.boundary_mode = BoundaryMode::Reflective;
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.pos = point(-4.0, 500.0);
+    organism.rot = std::f32::consts::PI;
+
+    boundary::apply_to_organism(organism, &params, 0.1);
+
+    assert_eq!(organism.pos[0], 4.0);
+    assert!(organism.rot.cos() > 0.99);
+
+    let mut projectile = Projectile::new(point(500.0, 1003.0), 0.0, 10.0, 1.0, 0, 100.0);
+    projectile.velocity = point(0.0, 10.0);
+    boundary::apply_to_projectile(&mut projectile, &params);
+    assert_eq!(projectile.pos[1], 997.0);
+    assert_eq!(projectile.velocity[1], -10.0);
+}
+
+#[test]
+fn test_absorbing_edges_kill() {
+    let mut params = common::test_params();
+    params.boundary_mode = BoundaryMode::Absorbing;
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.pos = point(500.0, -1.0);
+
+    boundary::apply_to_organism(organism, &params, 0.1);
+    assert!(!organism.is_alive());
+
+    let mut projectile = Projectile::new(point(1001.0, 500.0), 0.0, 10.0, 1.0, 0, 100.0);
+    boundary::apply_to_projectile(&mut projectile, &params);
+    assert!(projectile.is_expired());
+}
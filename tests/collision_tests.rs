@@ -0,0 +1,122 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::collision::{self, CollisionMode};
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::organism::Organism;
+use evo::simulation::params::Params;
+use evo::simulation::physics;
+use evo::simulation::view::OrganismView;
+use ndarray::Array1;
+
+/// Two organisms of equal size overlapping by 2 along x, far from the edges.
+fn overlapping_pair(params: &Params) -> Vec<Organism> {
+    let ecosystem = Ecosystem::new(params);
+    let mut pair: Vec<Organism> = ecosystem.organisms.into_iter().take(2).collect();
+    pair[0].pos = Array1::from_vec(vec![100.0, 100.0]);
+    pair[1].pos = Array1::from_vec(vec![100.0 + 2.0 * params.body_radius - 2.0, 100.0]);
+    for organism in &mut pair {
+        organism.energy = 1.0;
+        organism.velocity = [0.0, 0.0];
+    }
+    pair
+}
+
+/// Resolves both sides of the contact from the same snapshot.
+fn resolve_pair(params: &Params) -> (Vec<Organism>, Vec<Organism>) {
+    let snapshot = overlapping_pair(params);
+    let mut pair = snapshot.clone();
+    let neighbors = [(0.0, 0), (0.0, 1)];
+    let views = OrganismView::all(&snapshot, params);
+    for organism in &mut pair {
+        organism.touch.contacts += collision::resolve(organism, &neighbors, &views, params, 0.1);
+    }
+    (snapshot, pair)
+}
+
+#[test]
+fn test_lethal_contact_kills_both() {
+    let params = common::small_test_params();
+    let (_, pair) = resolve_pair(&params);
+    assert!(pair.iter().all(|o| !o.is_alive()));
+    assert!(pair.iter().all(|o| o.touch.contacts == 1));
+}
+
+#[test]
+fn test_soft_contact_separates_symmetrically() {
+    let mut params = common::small_test_params();
+    params.collision.mode = CollisionMode::Soft;
+    params.collision.stiffness = 1.0;
+    let (snapshot, pair) = resolve_pair(&params);
+
+    assert!(pair.iter().all(Organism::is_alive));
+    let left_shift = pair[0].pos[0] - snapshot[0].pos[0];
+    let right_shift = pair[1].pos[0] - snapshot[1].pos[0];
+    assert!((left_shift + 1.0).abs() < 1e-4);
+    assert!((right_shift - 1.0).abs() < 1e-4);
+    assert!((pair[1].pos[0] - pair[0].pos[0] - 2.0 * params.body_radius).abs() < 1e-4);
+}
+
+#[test]
+fn test_elastic_contact_conserves_momentum() {
+    let mut params = common::small_test_params();
+    params.collision.mode = CollisionMode::Elastic;
+    let mut snapshot = overlapping_pair(&params);
+    snapshot[1].morphology.body_scale = 1.5;
+    snapshot[1].pos[0] = 100.0 + 2.5 * params.body_radius - 2.0;
+    snapshot[0].velocity = [10.0, 0.0];
+
+    let mut pair = snapshot.clone();
+    let neighbors = [(0.0, 0), (0.0, 1)];
+    let views = OrganismView::all(&snapshot, &params);
+    for organism in &mut pair {
+        collision::resolve(organism, &neighbors, &views, &params, 0.1);
+    }
+
+    let momentum = |organisms: &[Organism]| -> f32 {
+        organisms
+            .iter()
+            .map(|o| physics::mass(o, &params) * o.velocity[0])
+            .sum()
+    };
+    assert!((momentum(&pair) - momentum(&snapshot)).abs() < 1e-3);
+    assert!(pair[0].velocity[0] < 0.0, "lighter body bounces back");
+    assert!(pair[1].velocity[0] > 0.0);
+    assert!(pair[1].velocity[0] < -pair[0].velocity[0]);
+}
+
+#[test]
+fn test_penalty_contact_costs_energy_without_moving() {
+    let mut params = common::small_test_params();
+    params.collision.mode = CollisionMode::Penalty;
+    params.collision.energy_penalty = 0.5;
+    let (snapshot, pair) = resolve_pair(&params);
+
+    for (before, after) in snapshot.iter().zip(&pair) {
+        assert!(after.is_alive());
+        assert_eq!(after.pos, before.pos);
+        assert!((before.energy - after.energy - 0.05).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_crowded_step_survives_without_lethal_contact() {
+    for (mode, survive) in [(CollisionMode::Lethal, false), (CollisionMode::Soft, true)] {
+        let mut params = common::small_test_params();
+        params.collision.mode = mode;
+        params.organism_spawn_rate = 0.0;
+        params.n_food = 0;
+        params.food_spawn_rate = 0.0;
+        let mut ecosystem = Ecosystem::new(&params);
+        for organism in &mut ecosystem.organisms {
+            organism.pos = Array1::from_vec(vec![250.0, 250.0]);
+            organism.energy = 1.0;
+        }
+        let count = ecosystem.organisms.len();
+
+        ecosystem.step(&params, 0.01);
+        assert_eq!(ecosystem.organisms.len() == count, survive, "{mode:?}");
+    }
+}
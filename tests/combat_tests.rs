@@ -0,0 +1,209 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::actions::{self, ActionKind};
+use evo::simulation::combat::{self, DamageSource};
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::{self, EventQueue, SimulationEvent};
+use evo::simulation::morphology;
+use evo::simulation::params::Params;
+use evo::simulation::view::OrganismView;
+use ndarray::Array1;
+
+fn combat_params() -> Params {
+    let mut params = common::small_test_params();
+    params.combat.enabled = true;
+    params.n_food = 0;
+    params
+}
+
+fn apply(ecosystem: &mut Ecosystem, params: &Params, batch: Vec<SimulationEvent>) {
+    let mut queue = EventQueue::new();
+    for event in batch {
+        queue.push(event);
+    }
+    events::apply_events(ecosystem, params, queue);
+}
+
+fn hit(target_id: usize, owner_id: usize, damage: f32) -> SimulationEvent {
+    SimulationEvent::ProjectileHit {
+        target_id,
+        damage,
+        owner_id,
+        from: Array1::from_vec(vec![1.0, 0.0]),
+    }
+}
+
+/// Ecosystem whose first two organisms touch, with full energy and health.
+fn duel(params: &Params) -> Ecosystem {
+    let mut ecosystem = Ecosystem::new(params);
+    for organism in &mut ecosystem.organisms {
+        organism.pos = Array1::from_vec(vec![400.0, 400.0]);
+        organism.energy = 1.0;
+        organism.health = params.combat.max_health;
+    }
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![100.0, 100.0]);
+    ecosystem.organisms[1].pos = Array1::from_vec(vec![100.0 + 2.0 * params.body_radius, 100.0]);
+    ecosystem
+}
+
+#[test]
+fn test_projectiles_wear_down_health_not_energy() {
+    let params = combat_params();
+    let mut ecosystem = duel(&params);
+    let (target, owner) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+
+    apply(&mut ecosystem, &params, vec![hit(target, owner, 0.4)]);
+    let organism = &ecosystem.organisms[0];
+    assert!((organism.health - 0.6).abs() < 1e-6);
+    assert!((organism.energy - 1.0).abs() < 1e-6);
+    assert!(ecosystem.kill_log.records().is_empty());
+}
+
+#[test]
+fn test_armour_reduces_damage() {
+    let params = combat_params();
+    let mut ecosystem = duel(&params);
+    ecosystem.organisms[0].morphology.armour = 1.0;
+    let (target, owner) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+
+    apply(&mut ecosystem, &params, vec![hit(target, owner, 0.4)]);
+    let taken = params.combat.max_health - ecosystem.organisms[0].health;
+    assert!((taken - 0.4 * (1.0 - params.combat.armour_strength)).abs() < 1e-6);
+}
+
+#[test]
+fn test_armour_evolves_with_combat_but_without_morphology() {
+    let mut params = combat_params();
+    params.morphology.enabled = false;
+    let mut ecosystem = Ecosystem::new(&params);
+    for organism in &mut ecosystem.organisms {
+        organism.morphology.armour = 0.5;
+    }
+
+    let mut armour = vec![0.5];
+    for generation in 1..ecosystem.organisms.len() {
+        let (parents, children) = ecosystem.organisms.split_at_mut(generation);
+        morphology::inherit_single(&mut children[0], &parents[generation - 1], &params);
+        armour.push(children[0].morphology.armour);
+    }
+    assert!(armour.windows(2).any(|pair| pair[0] != pair[1]));
+    assert!(armour.iter().all(|a| (0.0..=1.0).contains(a)));
+
+    // Armour stays put once combat is off, even with morphology evolving
+    params.combat.enabled = false;
+    params.morphology.enabled = true;
+    let (parents, children) = ecosystem.organisms.split_at_mut(1);
+    children[0].morphology.armour = 0.0;
+    morphology::inherit_single(&mut children[0], &parents[0], &params);
+    assert_eq!(children[0].morphology.armour, 0.5);
+}
+
+#[test]
+fn test_lethal_hit_is_logged_with_its_source() {
+    let params = combat_params();
+    let mut ecosystem = duel(&params);
+    let (target, owner) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+    let food_before = ecosystem.food.len();
+
+    apply(&mut ecosystem, &params, vec![hit(target, owner, 2.0)]);
+    assert!(!ecosystem.organisms[0].is_alive());
+    assert_eq!(ecosystem.food.len(), food_before + 1, "leaves a corpse");
+
+    let record = &ecosystem.kill_log.records()[0];
+    assert_eq!(record.victim_id, target);
+    assert_eq!(record.killer_id, owner);
+    assert_eq!(record.source, DamageSource::Projectile);
+}
+
+#[test]
+fn test_bite_steals_energy_from_touching_organism() {
+    let params = combat_params();
+    let mut ecosystem = duel(&params);
+    ecosystem.organisms[1].energy = 0.5;
+    let snapshot = OrganismView::all(&ecosystem.organisms, &params);
+    let neighbors: Vec<(f32, usize)> = (0..snapshot.len()).map(|i| (0.0, i)).collect();
+
+    let bite = actions::execute_bite(
+        &mut ecosystem.organisms[1],
+        1.0,
+        &neighbors,
+        &snapshot,
+        &params,
+    );
+    assert_eq!(bite.len(), 1);
+    let cost = params.combat.bite_cost;
+    apply(&mut ecosystem, &params, bite);
+
+    let (victim, attacker) = (&ecosystem.organisms[0], &ecosystem.organisms[1]);
+    assert!((victim.energy - (1.0 - params.combat.bite_energy)).abs() < 1e-6);
+    assert!((victim.health - (1.0 - params.combat.bite_damage)).abs() < 1e-6);
+    assert!((attacker.energy - (0.5 - cost + params.combat.bite_energy)).abs() < 1e-6);
+    assert!(victim.touch.damage > 0.0);
+}
+
+#[test]
+fn test_bite_needs_contact_and_combat() {
+    let mut params = combat_params();
+    let mut ecosystem = duel(&params);
+    ecosystem.organisms[0].pos[0] = 50.0;
+    let snapshot = OrganismView::all(&ecosystem.organisms, &params);
+    let neighbors: Vec<(f32, usize)> = (0..snapshot.len()).map(|i| (0.0, i)).collect();
+    let out_of_reach = actions::execute_bite(
+        &mut ecosystem.organisms[1],
+        1.0,
+        &neighbors,
+        &snapshot,
+        &params,
+    );
+    assert!(out_of_reach.is_empty());
+
+    params.combat.enabled = false;
+    let organisms = duel(&params).organisms;
+    let snapshot = OrganismView::all(&organisms, &params);
+    let mut attacker = organisms[1].clone();
+    assert!(actions::execute_bite(&mut attacker, 1.0, &neighbors, &snapshot, &params).is_empty());
+    assert_eq!(ActionKind::Bite.build().output_size(&params), 0);
+}
+
+#[test]
+fn test_fatal_bite_is_logged() {
+    let params = combat_params();
+    let mut ecosystem = duel(&params);
+    ecosystem.organisms[0].health = 0.1;
+    let (victim, attacker) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+
+    apply(
+        &mut ecosystem,
+        &params,
+        vec![SimulationEvent::Bite {
+            attacker_id: attacker,
+            target_id: victim,
+            strength: 1.0,
+            from: Array1::from_vec(vec![1.0, 0.0]),
+        }],
+    );
+    assert!(!ecosystem.organisms[0].is_alive());
+    assert_eq!(ecosystem.kill_log.count(DamageSource::Bite), 1);
+}
+
+#[test]
+fn test_health_regenerates_at_an_energy_cost() {
+    let params = combat_params();
+    let mut organism = duel(&params).organisms.remove(0);
+    organism.health = 0.5;
+
+    combat::maintain(&mut organism, &params, 1.0);
+    let healed = organism.health - 0.5;
+    assert!((healed - params.combat.regen_rate).abs() < 1e-6);
+    assert!((1.0 - organism.energy - healed * params.combat.regen_energy_cost).abs() < 1e-6);
+
+    // Armour is paid for even when unharmed
+    organism.health = params.combat.max_health;
+    organism.morphology.armour = 1.0;
+    let before = organism.energy;
+    combat::maintain(&mut organism, &params, 1.0);
+    assert!((before - organism.energy - params.combat.armour_cost).abs() < 1e-6);
+}
@@ -0,0 +1,199 @@
+//! Parameter fixtures shared by the integration tests.
+
+// Each test crate compiles this module and uses only some of the fixtures
+#![allow(dead_code)]
+
+use evo::simulation::actions::{Actuation, default_actions};
+use evo::simulation::boundary::BoundaryMode;
+use evo::simulation::brain::BrainType;
+use evo::simulation::collision::CollisionParams;
+use evo::simulation::combat::CombatParams;
+use evo::simulation::conflict::ConflictParams;
+use evo::simulation::diet::DietParams;
+use evo::simulation::energy::EnergyLedgerParams;
+use evo::simulation::environment::EnvironmentParams;
+use evo::simulation::food::default_food_kinds;
+use evo::simulation::mate_choice::MateChoiceParams;
+use evo::simulation::migration::MigrationParams;
+use evo::simulation::morphology::MorphologyParams;
+use evo::simulation::novelty::NoveltyParams;
+use evo::simulation::obstacle::ObstacleParams;
+use evo::simulation::organism::{Perception, default_senses};
+use evo::simulation::params::Params;
+use evo::simulation::pheromone::PheromoneParams;
+use evo::simulation::physics::PhysicsParams;
+use evo::simulation::projectile::ProjectileParams;
+use evo::simulation::reproduction::ReproductionMode;
+use evo::simulation::resource_field::ResourceFieldParams;
+
+/// Parameters for a 1000x1000 world with four organisms and forty food items.
+pub fn test_params() -> Params {
+    let signal_size: usize = 3;
+    let num_vision_directions: usize = 3;
+    let memory_size: usize = 3;
+
+    let vision_radius = 30.0;
+
+    let mut params = Params {
+        body_radius: 3.0,
+        vision_radius,
+        scent_radius: 50.0,
+        share_radius: 15.0,
+        dna_breeding_distance: 0.2,
+        dna_mutation_rate: 0.05,
+        brain_mutation_rate: 0.1,
+        idle_energy_rate: 0.023,
+        move_energy_rate: 0.0002,
+        move_multiplier: 60.0,
+        rot_energy_rate: 0.0003,
+        num_vision_directions,
+        fov: std::f32::consts::PI / 2.0,
+        signal_size,
+        memory_size,
+        n_organism: 4,
+        max_organism: 200,
+        n_food: 40,
+        max_food: 150,
+        box_width: 1000.0,
+        box_height: 1000.0,
+        boundary_mode: BoundaryMode::Torus,
+        boundary_contact_cost: 0.5,
+        layer_sizes: vec![0, 10, 0],
+        attack_cost_rate: 0.2,
+        attack_damage_rate: 0.4,
+        attack_cooldown: 1.0,
+        max_energy: 2.0,
+        projectile_speed: vision_radius * 2.0,
+        projectile_range: vision_radius,
+        projectile_radius: 1.0,
+        organism_spawn_rate: 1.0,
+        food_spawn_rate: 1.0,
+        food_kinds: default_food_kinds(),
+        num_genetic_pools: 1,
+        pool_interbreed_prob: 0.0,
+        brain_type: BrainType::MLP,
+        transformer_model_dim: 64,
+        transformer_num_blocks: 2,
+        transformer_num_heads: 4,
+        transformer_head_dim: 16,
+        transformer_ff_dim: 128,
+        graveyard_size: 400,
+        reproduction_energy_multiplier: 1.2,
+        reproduction_radius: 15.0,
+        reproduction_mode: ReproductionMode::Spawning,
+        reproduction_cooldown: 5.0,
+        reproduction_energy_cost: 0.1,
+        spawn_from_graveyard: true,
+        unbalanced_pool_sampling: false,
+        empty_pool_seed_count: 5,
+        novelty: NoveltyParams::default(),
+        mate_choice: MateChoiceParams::default(),
+        migration: MigrationParams::default(),
+        morphology: MorphologyParams::default(),
+        diet: DietParams::default(),
+        resource_field: ResourceFieldParams::default(),
+        obstacles: ObstacleParams::default(),
+        environment: EnvironmentParams::default(),
+        pheromones: PheromoneParams::default(),
+        physics: PhysicsParams::default(),
+        collision: CollisionParams::default(),
+        combat: CombatParams::default(),
+        projectiles: ProjectileParams::default(),
+        energy_ledger: EnergyLedgerParams::default(),
+        conflicts: ConflictParams::default(),
+        pool_overrides: Vec::new(),
+        senses: default_senses(),
+        actions: default_actions(),
+    };
+    fit_layer_sizes(&mut params);
+    params
+}
+
+/// Parameters for a small 500x500 world with ten organisms and food items.
+pub fn small_test_params() -> Params {
+    let signal_size: usize = 3;
+    let num_vision_directions: usize = 5;
+    let memory_size: usize = 8;
+
+    let mut params = Params {
+        body_radius: 3.0,
+        vision_radius: 50.0,
+        scent_radius: 20.0,
+        share_radius: 15.0,
+        dna_breeding_distance: 0.2,
+        dna_mutation_rate: 0.1,
+        brain_mutation_rate: 0.1,
+        idle_energy_rate: 0.01,
+        move_energy_rate: 0.0001,
+        move_multiplier: 50.0,
+        rot_energy_rate: 0.00001,
+        num_vision_directions,
+        fov: std::f32::consts::PI / 2.0,
+        signal_size,
+        memory_size,
+        n_organism: 10,
+        max_organism: 20,
+        n_food: 10,
+        max_food: 20,
+        box_width: 500.0,
+        box_height: 500.0,
+        boundary_mode: BoundaryMode::Torus,
+        boundary_contact_cost: 0.5,
+        layer_sizes: vec![0, 16, 0],
+        attack_cost_rate: 0.1,
+        attack_damage_rate: 0.5,
+        attack_cooldown: 1.0,
+        max_energy: 2.0,
+        projectile_speed: 100.0,
+        projectile_range: 50.0,
+        projectile_radius: 2.0,
+        organism_spawn_rate: 1.0,
+        food_spawn_rate: 1.0,
+        food_kinds: default_food_kinds(),
+        num_genetic_pools: 1,
+        pool_interbreed_prob: 0.0,
+        brain_type: BrainType::MLP,
+        transformer_model_dim: 64,
+        transformer_num_blocks: 2,
+        transformer_num_heads: 4,
+        transformer_head_dim: 16,
+        transformer_ff_dim: 128,
+        graveyard_size: 100,
+        reproduction_energy_multiplier: 1.2,
+        reproduction_radius: 15.0,
+        reproduction_mode: ReproductionMode::Spawning,
+        reproduction_cooldown: 5.0,
+        reproduction_energy_cost: 0.1,
+        spawn_from_graveyard: true,
+        unbalanced_pool_sampling: false,
+        empty_pool_seed_count: 5,
+        novelty: NoveltyParams::default(),
+        mate_choice: MateChoiceParams::default(),
+        migration: MigrationParams::default(),
+        morphology: MorphologyParams::default(),
+        diet: DietParams::default(),
+        resource_field: ResourceFieldParams::default(),
+        obstacles: ObstacleParams::default(),
+        environment: EnvironmentParams::default(),
+        pheromones: PheromoneParams::default(),
+        physics: PhysicsParams::default(),
+        collision: CollisionParams::default(),
+        combat: CombatParams::default(),
+        projectiles: ProjectileParams::default(),
+        energy_ledger: EnergyLedgerParams::default(),
+        conflicts: ConflictParams::default(),
+        pool_overrides: Vec::new(),
+        senses: default_senses(),
+        actions: default_actions(),
+    };
+    fit_layer_sizes(&mut params);
+    params
+}
+
+/// Sizes the brain's input and output layers for the senses and actions
+/// `params` enables, keeping its hidden layers.
+pub fn fit_layer_sizes(params: &mut Params) {
+    let last = params.layer_sizes.len() - 1;
+    params.layer_sizes[0] = Perception::from_params(params).total_input_size(params);
+    params.layer_sizes[last] = Actuation::from_params(params).total_output_size(params);
+}
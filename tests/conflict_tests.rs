@@ -0,0 +1,301 @@
+#![allow(missing_docs)]
+
+mod common;
+
+use evo::simulation::actions;
+use evo::simulation::conflict::{Claim, ConflictParams, ConflictPolicy, Resource};
+use evo::simulation::diet;
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::{self, EventQueue, SimulationEvent};
+use evo::simulation::params::Params;
+use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn apply(ecosystem: &mut Ecosystem, params: &Params, batch: Vec<SimulationEvent>) {
+    let mut queue = EventQueue::new();
+    for event in batch {
+        queue.push(event);
+    }
+    events::apply_events(ecosystem, params, queue);
+}
+
+fn claim(organism_id: usize, food_id: usize) -> SimulationEvent {
+    SimulationEvent::FoodConsumed {
+        organism_id,
+        food_id,
+    }
+}
+
+/// Two organisms within reach of the first food item, the second one nearer.
+fn contest(params: &Params) -> Ecosystem {
+    let mut ecosystem = Ecosystem::new(params);
+    for organism in &mut ecosystem.organisms {
+        organism.energy = 0.5;
+    }
+    ecosystem.food[0].pos = Array1::from_vec(vec![100.0, 100.0]);
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![104.0, 100.0]);
+    ecosystem.organisms[1].pos = Array1::from_vec(vec![101.0, 100.0]);
+    ecosystem
+}
+
+/// Everything event application can change, bit for bit.
+fn outcome(ecosystem: &Ecosystem) -> Vec<u64> {
+    let organisms = ecosystem.organisms.iter().flat_map(|o| {
+        [
+            o.id as u64,
+            u64::from(o.energy.to_bits()),
+            u64::from(o.health.to_bits()),
+            o.score as u64,
+            u64::from(o.touch.ate_food) << 1 | u64::from(o.touch.lost_food),
+            u64::from(o.touch.energy_received.to_bits()),
+            u64::from(o.touch.damage.to_bits()),
+        ]
+    });
+    let food = ecosystem
+        .food
+        .iter()
+        .map(|f| u64::from(f.is_consumed()) << 32 | u64::from(f.energy.to_bits()));
+    let projectiles = ecosystem.projectiles.iter().map(|p| p.owner_id as u64);
+    let kills = ecosystem
+        .kill_log
+        .records()
+        .iter()
+        .flat_map(|k| [k.victim_id as u64, k.killer_id as u64]);
+    organisms
+        .chain(food)
+        .chain(projectiles)
+        .chain(kills)
+        .collect()
+}
+
+#[test]
+fn test_allocation_follows_policy() {
+    let claims = [
+        Claim {
+            claimant: 0,
+            distance: 3.0,
+            demand: 1.0,
+        },
+        Claim {
+            claimant: 1,
+            distance: 1.0,
+            demand: 1.0,
+        },
+        Claim {
+            claimant: 2,
+            distance: 2.0,
+            demand: 0.2,
+        },
+    ];
+    let resource = Resource::Food(7);
+    let mut conflicts = ConflictParams::default();
+
+    let nearest = conflicts.allocate(1.0, &claims, 1.0, resource);
+    assert_eq!(nearest, vec![0.0, 1.0, 0.0]);
+
+    conflicts.policy = ConflictPolicy::EqualSplit;
+    let split = conflicts.allocate(1.0, &claims, 1.0, resource);
+    for (share, expected) in split.iter().zip([0.4, 0.4, 0.2]) {
+        assert!((share - expected).abs() < 1e-6);
+    }
+
+    conflicts.policy = ConflictPolicy::Random;
+    let drawn = conflicts.allocate(1.0, &claims, 1.0, resource);
+    let mut reversed = claims;
+    reversed.reverse();
+    let mut redrawn = conflicts.allocate(1.0, &reversed, 1.0, resource);
+    redrawn.reverse();
+    assert_eq!(drawn, redrawn);
+    assert!((drawn.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_nearest_claimant_wins_contested_food_in_any_order() {
+    let params = common::small_test_params();
+    let ecosystem = contest(&params);
+    let (far, near) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+    let digested = ecosystem.food[0].energy
+        * diet::efficiency(&ecosystem.organisms[1], ecosystem.food[0].kind, &params);
+
+    let mut forward = ecosystem.clone();
+    apply(&mut forward, &params, vec![claim(far, 0), claim(near, 0)]);
+    let mut backward = ecosystem.clone();
+    apply(&mut backward, &params, vec![claim(near, 0), claim(far, 0)]);
+
+    assert_eq!(outcome(&forward), outcome(&backward));
+    assert!(forward.food[0].is_consumed());
+    assert!((forward.organisms[1].energy - (0.5 + digested)).abs() < 1e-6);
+    assert!((forward.organisms[0].energy - 0.5).abs() < 1e-6);
+    assert!(forward.organisms[0].touch.lost_food);
+    assert!(forward.organisms[1].touch.ate_food);
+}
+
+#[test]
+fn test_equal_split_shares_contested_food() {
+    let mut params = common::small_test_params();
+    params.conflicts.policy = ConflictPolicy::EqualSplit;
+    let mut ecosystem = contest(&params);
+    let (first, second) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+    let energy = ecosystem.food[0].energy;
+
+    apply(
+        &mut ecosystem,
+        &params,
+        vec![claim(first, 0), claim(second, 0)],
+    );
+
+    for organism in &ecosystem.organisms[..2] {
+        let efficiency = diet::efficiency(organism, ecosystem.food[0].kind, &params);
+        assert!((organism.energy - (0.5 + energy / 2.0 * efficiency)).abs() < 1e-6);
+        assert!(organism.touch.ate_food);
+        assert!(!organism.touch.lost_food);
+        assert_eq!(organism.score, 1);
+    }
+}
+
+#[test]
+fn test_food_is_digested_once() {
+    let params = common::small_test_params();
+    let mut ecosystem = contest(&params);
+    let food = ecosystem.food.clone();
+    let digested =
+        food[0].energy * diet::efficiency(&ecosystem.organisms[1], food[0].kind, &params);
+
+    let claims =
+        actions::execute_food_consumption(&mut ecosystem.organisms[1], &[(1.0, 0)], &food, &params);
+    assert!((ecosystem.organisms[1].energy - 0.5).abs() < 1e-6);
+    apply(&mut ecosystem, &params, claims);
+
+    assert!((ecosystem.organisms[1].energy - (0.5 + digested)).abs() < 1e-6);
+    assert_eq!(ecosystem.organisms[1].score, 1);
+}
+
+#[test]
+fn test_full_receiver_turns_later_givers_away() {
+    let params = common::small_test_params();
+    let mut ecosystem = contest(&params);
+    ecosystem.organisms[2].pos = Array1::from_vec(vec![100.0, 100.0]);
+    ecosystem.organisms[2].energy = params.max_energy - 0.1;
+    let ids: Vec<usize> = ecosystem.organisms[..3].iter().map(|o| o.id).collect();
+    let gift = |giver_id| SimulationEvent::EnergyShared {
+        giver_id,
+        receiver_id: ids[2],
+        amount: 0.2,
+    };
+
+    apply(&mut ecosystem, &params, vec![gift(ids[0]), gift(ids[1])]);
+
+    // The nearer giver fills the receiver, the farther one keeps its energy
+    assert!((ecosystem.organisms[1].energy - 0.3).abs() < 1e-6);
+    assert!((ecosystem.organisms[0].energy - 0.5).abs() < 1e-6);
+    assert!((ecosystem.organisms[2].touch.energy_received - 0.2).abs() < 1e-6);
+}
+
+#[test]
+fn test_event_application_does_not_depend_on_scheduling() {
+    let mut params = common::small_test_params();
+    params.n_organism = 40;
+    params.max_organism = 80;
+    params.n_food = 10;
+    params.combat.enabled = true;
+
+    for policy in [
+        ConflictPolicy::NearestWins,
+        ConflictPolicy::EqualSplit,
+        ConflictPolicy::Random,
+    ] {
+        params.conflicts.policy = policy;
+        let ecosystem = Ecosystem::new(&params);
+        let ids: Vec<usize> = ecosystem.organisms.iter().map(|o| o.id).collect();
+        let mut rng = StdRng::seed_from_u64(7);
+        let any = |rng: &mut StdRng| ids[rng.random_range(0..ids.len())];
+        let mut batch = Vec::new();
+        for _ in 0..200 {
+            let (a, b) = (any(&mut rng), any(&mut rng));
+            batch.push(match rng.random_range(0..5) {
+                0 => claim(a, rng.random_range(0..ecosystem.food.len())),
+                1 => SimulationEvent::ProjectileHit {
+                    target_id: a,
+                    damage: rng.random_range(0.0..0.3),
+                    owner_id: b,
+                    from: Array1::from_vec(vec![1.0, 0.0]),
+                },
+                2 => SimulationEvent::Bite {
+                    attacker_id: a,
+                    target_id: b,
+                    strength: rng.random_range(0.1..1.0),
+                    from: Array1::from_vec(vec![0.0, 1.0]),
+                },
+                3 => SimulationEvent::EnergyShared {
+                    giver_id: a,
+                    receiver_id: b,
+                    amount: rng.random_range(0.0..0.5),
+                },
+                _ => SimulationEvent::ProjectileCreated {
+                    pos: Array1::from_vec(vec![100.0, 100.0]),
+                    rotation: 0.0,
+                    damage: 0.1,
+                    owner_id: a,
+                },
+            });
+        }
+
+        let run = |threads: usize, batch: Vec<SimulationEvent>| {
+            let mut state = ecosystem.clone();
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+                .install(|| apply(&mut state, &params, batch));
+            outcome(&state)
+        };
+        let mut reversed = batch.clone();
+        reversed.reverse();
+        assert_eq!(run(1, batch), run(8, reversed), "{policy:?}");
+    }
+}
+
+#[test]
+fn test_organisms_killed_this_step_stay_dead() {
+    let mut params = common::small_test_params();
+    params.combat.enabled = true;
+    let mut ecosystem = contest(&params);
+    let ids: Vec<usize> = ecosystem.organisms[..4].iter().map(|o| o.id).collect();
+    let (victim, rival, killer, biter) = (ids[1], ids[0], ids[2], ids[3]);
+    let lethal = |target_id, owner_id| SimulationEvent::ProjectileHit {
+        target_id,
+        damage: params.combat.max_health * 10.0,
+        owner_id,
+        from: Array1::from_vec(vec![1.0, 0.0]),
+    };
+
+    // The nearer claimant is shot dead, and the biting one too, as it bites
+    apply(
+        &mut ecosystem,
+        &params,
+        vec![
+            claim(victim, 0),
+            claim(rival, 0),
+            lethal(victim, killer),
+            SimulationEvent::EnergyShared {
+                giver_id: killer,
+                receiver_id: victim,
+                amount: 0.2,
+            },
+            SimulationEvent::Bite {
+                attacker_id: biter,
+                target_id: rival,
+                strength: 1.0,
+                from: Array1::from_vec(vec![0.0, 1.0]),
+            },
+            lethal(biter, killer),
+        ],
+    );
+
+    assert!(!ecosystem.organisms[1].is_alive());
+    assert!(!ecosystem.organisms[3].is_alive());
+    assert!(ecosystem.organisms[0].touch.ate_food);
+    assert!((ecosystem.organisms[2].energy - 0.5).abs() < 1e-6);
+    assert_eq!(ecosystem.kill_log.records().len(), 2);
+}
@@ -0,0 +1,120 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::actions;
+use evo::simulation::diet;
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::food::{self, Food, FoodKind, default_food_kinds};
+use evo::simulation::organism::{Scent, Sense};
+use evo::simulation::params::Params;
+use evo::simulation::spatial::Neighbourhood;
+use evo::simulation::view::WorldView;
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.diet.enabled = true;
+    params
+}
+
+fn food_at(x: f32, y: f32, kind: FoodKind) -> Food {
+    Food {
+        pos: Array1::from_vec(vec![x, y]),
+        energy: 1.0,
+        age: 0.0,
+        kind,
+    }
+}
+
+#[test]
+fn test_efficiency_follows_diet_shares() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+
+    organism.diet = Array1::from_vec(vec![1.0, 0.0]);
+    assert_eq!(diet::efficiency(organism, FoodKind::Plant, &params), 1.0);
+    assert_eq!(diet::efficiency(organism, FoodKind::Carrion, &params), 0.0);
+
+    organism.diet = Array1::from_vec(vec![0.5, 0.5]);
+    assert!((diet::efficiency(organism, FoodKind::Plant, &params) - 0.5).abs() < 1e-6);
+    assert!((diet::efficiency(organism, FoodKind::Carrion, &params) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_disabled_diet_digests_everything() {
+    let mut params = create_test_params();
+    params.diet.enabled = false;
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.diet = Array1::from_vec(vec![0.0, 1.0]);
+
+    assert_eq!(diet::efficiency(organism, FoodKind::Plant, &params), 1.0);
+}
+
+#[test]
+fn test_food_consumption_scales_with_efficiency() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.pos = Array1::from_vec(vec![100.0, 100.0]);
+    organism.energy = 1.0;
+    organism.diet = Array1::from_vec(vec![0.0, 1.0]);
+    let food = vec![food_at(101.0, 100.0, FoodKind::Plant)];
+
+    let events = actions::execute_food_consumption(organism, &[(1.0, 0)], &food, &params);
+
+    // A pure carnivore still eats the plant but gains nothing from it
+    assert_eq!(events.len(), 1);
+    assert_eq!(organism.energy, 1.0);
+}
+
+#[test]
+fn test_spawn_kind_respects_weights() {
+    let mut kinds = default_food_kinds();
+    for _ in 0..100 {
+        assert_eq!(food::sample_spawn_kind(&kinds), Some(FoodKind::Plant));
+    }
+
+    kinds[0].spawn_weight = 0.0;
+    assert_eq!(food::sample_spawn_kind(&kinds), None);
+}
+
+#[test]
+fn test_zero_lifetime_never_expires() {
+    let mut kinds = default_food_kinds();
+    let mut item = food_at(0.0, 0.0, FoodKind::Plant);
+    item.age = 1e6;
+    assert!(!item.is_expired(&kinds));
+
+    kinds[0].lifetime = 10.0;
+    assert!(item.is_expired(&kinds));
+    item.age = 5.0;
+    assert!(!item.is_expired(&kinds));
+}
+
+#[test]
+fn test_scent_uses_kind_channel() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms.truncate(1);
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![500.0, 500.0]);
+    ecosystem.food = vec![food_at(510.0, 500.0, FoodKind::Carrion)];
+
+    let scent = Scent::new().sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+
+    let carrion_channel = params.food_kinds[1].scent_channel;
+    assert!(scent[carrion_channel] > 0.0);
+    for (channel, value) in scent.iter().enumerate() {
+        if channel != carrion_channel {
+            assert_eq!(*value, 0.0);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#![allow(missing_docs)]
+
+mod common;
+
+use evo::simulation::actions::ActionKind;
+use evo::simulation::boundary::BoundaryMode;
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::energy::{self, EnergyLedger, Flow};
+use evo::simulation::events::{self, EventQueue, SimulationEvent};
+use evo::simulation::params::Params;
+
+fn strict_params() -> Params {
+    let mut params = common::small_test_params();
+    params.energy_ledger.strict = true;
+    params
+}
+
+fn run(ecosystem: &mut Ecosystem, params: &Params, steps: usize) {
+    for _ in 0..steps {
+        ecosystem.step(params, 0.1);
+        ecosystem.spawn(params, 0.1);
+    }
+}
+
+#[test]
+fn test_every_flow_is_accounted_for() {
+    let params = strict_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    run(&mut ecosystem, &params, 200);
+
+    assert_eq!(ecosystem.energy_ledger.steps(), 200);
+    assert!(ecosystem.energy_ledger.imbalance().abs() < 1e-3);
+    let total = ecosystem.energy_ledger.total();
+    assert!(total.total(Flow::FoodGrown) > 0.0);
+    assert!(total.total(Flow::Metabolism) < 0.0);
+}
+
+#[test]
+fn test_every_flow_is_accounted_for_with_combat_and_pools() {
+    let mut params = strict_params();
+    params.combat.enabled = true;
+    params.physics.enabled = true;
+    params.num_genetic_pools = 3;
+    params.migration.enabled = true;
+    params.migration.interval = 1.0;
+    params.boundary_mode = BoundaryMode::Walls;
+    params.actions.push(ActionKind::Bite);
+    common::fit_layer_sizes(&mut params);
+    let mut ecosystem = Ecosystem::new(&params);
+    run(&mut ecosystem, &params, 200);
+
+    assert!(ecosystem.energy_ledger.last_step().pools.len() <= 3);
+}
+
+#[test]
+#[should_panic(expected = "energy not conserved")]
+fn test_strict_mode_catches_unrecorded_energy() {
+    let params = strict_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    run(&mut ecosystem, &params, 5);
+
+    ecosystem.organisms[0].energy += 1.0; // appears from nowhere
+    run(&mut ecosystem, &params, 1);
+}
+
+#[test]
+fn test_sharing_moves_energy_between_pools_and_records_overflow() {
+    let mut params = common::small_test_params();
+    params.num_genetic_pools = 2;
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms[0].pool_id = 0;
+    ecosystem.organisms[0].energy = 1.0;
+    ecosystem.organisms[1].pool_id = 1;
+    ecosystem.organisms[1].energy = params.max_energy - 0.1;
+    let (giver, receiver) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+    ecosystem.energy_ledger = EnergyLedger::new(energy::stock(&ecosystem));
+
+    let mut queue = EventQueue::new();
+    queue.push(SimulationEvent::EnergyShared {
+        giver_id: giver,
+        receiver_id: receiver,
+        amount: 0.4,
+    });
+    events::apply_events(&mut ecosystem, &params, queue);
+    for organism in &mut ecosystem.organisms {
+        ecosystem.energy_ledger.collect(organism);
+    }
+    ecosystem
+        .energy_ledger
+        .close_step(energy::stock(&ecosystem), &params);
+
+    let step = ecosystem.energy_ledger.last_step();
+    assert!((step.pool(0).get(Flow::Shared) + 0.4).abs() < 1e-6);
+    assert!((step.pool(1).get(Flow::Shared) - 0.4).abs() < 1e-6);
+    assert!((step.pool(1).get(Flow::Overflow) + 0.3).abs() < 1e-6);
+    assert!(ecosystem.energy_ledger.imbalance().abs() < 1e-5);
+}
+
+#[test]
+fn test_ledger_exports_flows_by_name() {
+    let params = common::small_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    run(&mut ecosystem, &params, 20);
+
+    let path = std::env::temp_dir().join("evo_energy_ledger_test.json");
+    let path = path.to_str().unwrap();
+    ecosystem.energy_ledger.save_to_file(path).unwrap();
+    let json = std::fs::read_to_string(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert!(json.contains("\"Metabolism\""));
+    let loaded: EnergyLedger = serde_json::from_str(&json).unwrap();
+    let (before, after) = (ecosystem.energy_ledger.total(), loaded.total());
+    for flow in Flow::ALL {
+        assert!((before.total(flow) - after.total(flow)).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,174 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::environment::{self, Conditions, CycleCurve};
+use evo::simulation::food::FoodKind;
+use evo::simulation::organism::{Clock, Perception, Sense, Vision};
+use evo::simulation::params::Params;
+use evo::simulation::spatial::Neighbourhood;
+use evo::simulation::view::WorldView;
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.environment.enabled = true;
+    common::fit_layer_sizes(&mut params);
+    params
+}
+
+fn point(x: f32, y: f32) -> Array1<f32> {
+    Array1::from_vec(vec![x, y])
+}
+
+#[test]
+fn test_sine_curve_levels() {
+    let curve = CycleCurve::Sine;
+    assert!(curve.level(0.0).abs() < 1e-6);
+    assert!((curve.level(0.25) - 0.5).abs() < 1e-6);
+    assert!((curve.level(0.5) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_piecewise_curve_interpolates_and_wraps() {
+    let curve = CycleCurve::Piecewise(vec![[0.25, 0.0], [0.75, 1.0]]);
+    assert!((curve.level(0.5) - 0.5).abs() < 1e-6);
+    assert!((curve.level(0.75) - 1.0).abs() < 1e-6);
+    // Between the last point and the first, through phase 0
+    assert!((curve.level(0.0) - 0.5).abs() < 1e-6);
+    assert!((curve.level(0.125) - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_disabled_environment_is_stationary() {
+    let mut params = create_test_params();
+    params.environment.enabled = false;
+
+    for time in [0.0, 17.0, 300.0] {
+        assert_eq!(
+            environment::conditions(time, &params),
+            Conditions::default()
+        );
+    }
+    assert_eq!(Clock::new().input_size(&params), 0);
+}
+
+#[test]
+fn test_conditions_follow_day_and_season() {
+    let params = create_test_params();
+    let env = &params.environment;
+
+    let midnight_midwinter = environment::conditions(0.0, &params);
+    assert!((midnight_midwinter.vision_scale - env.night_vision_scale).abs() < 1e-5);
+    assert!((midnight_midwinter.food_rate_scale - env.winter_food_rate_scale).abs() < 1e-5);
+    assert!((midnight_midwinter.idle_energy_scale - env.winter_idle_energy_scale).abs() < 1e-5);
+
+    let noon = environment::conditions(env.day_length / 2.0, &params);
+    assert!((noon.vision_scale - 1.0).abs() < 1e-5);
+
+    let midsummer = environment::conditions(env.year_length / 2.0, &params);
+    assert!((midsummer.food_rate_scale - 1.0).abs() < 1e-5);
+    assert!((midsummer.food_energy_scale - 1.0).abs() < 1e-5);
+    assert!((midsummer.idle_energy_scale - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_clock_sense_reports_phases() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.time = params.environment.day_length / 4.0;
+
+    let clock = Clock::new();
+    assert_eq!(clock.input_size(&params), 4);
+    let outputs = clock.sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert_eq!(outputs.len(), 4);
+    // A quarter of the way through the day
+    assert!((outputs[0] - 1.0).abs() < 1e-5);
+    assert!(outputs[1].abs() < 1e-5);
+
+    let perception = Perception::default();
+    assert_eq!(perception.total_input_size(&params), params.layer_sizes[0]);
+}
+
+#[test]
+fn test_night_shortens_vision() {
+    let mut params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms.truncate(1);
+    ecosystem.food.truncate(1);
+
+    let viewer = &mut ecosystem.organisms[0];
+    viewer.pos = point(500.0, 500.0);
+    viewer.rot = 0.0;
+    viewer.vision_angles = Array1::zeros(params.num_vision_directions);
+    viewer.vision_lengths = Array1::from_elem(params.num_vision_directions, 30.0);
+    ecosystem.food[0].pos = point(520.0, 500.0);
+
+    let vision = Vision::new();
+    ecosystem.time = params.environment.day_length / 2.0;
+    let noon = vision.sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert!(noon[0] > 0.0);
+
+    ecosystem.time = 0.0;
+    let midnight = vision.sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert_eq!(midnight[0], 0.0);
+
+    // Without the schedule, time of day makes no difference
+    params.environment.enabled = false;
+    let stationary = vision.sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert_eq!(stationary, noon);
+}
+
+#[test]
+fn test_winter_slows_food_growth() {
+    let mut params = create_test_params();
+    params.food_spawn_rate = 20.0;
+    params.max_food = 10_000;
+    params.environment.winter_food_rate_scale = 0.0;
+    params.environment.winter_food_energy_scale = 0.5;
+
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.food.clear();
+    ecosystem.time = 0.0;
+    ecosystem.spawn(&params, 1.0);
+    assert!(ecosystem.food.is_empty());
+
+    // Food grown just after midwinter carries reduced energy
+    ecosystem.time = params.environment.year_length / 8.0;
+    let conditions = environment::conditions(ecosystem.time, &params);
+    for _ in 0..10 {
+        ecosystem.spawn(&params, 1.0);
+    }
+    let plant = FoodKind::Plant.spec(&params.food_kinds).unwrap().energy;
+    assert!(ecosystem.food.iter().any(|f| f.kind == FoodKind::Plant));
+    for food in ecosystem.food.iter().filter(|f| f.kind == FoodKind::Plant) {
+        assert!((food.energy - plant * conditions.food_energy_scale).abs() < 1e-5);
+    }
+
+    ecosystem.food.clear();
+    ecosystem.time = params.environment.year_length / 2.0;
+    ecosystem.spawn(&params, 1.0);
+    assert!(ecosystem.food.len() >= 20);
+}
@@ -1,4 +1,13 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+#![allow(clippy::items_after_statements)]
+
+use evo::simulation::energy::FlowTotals;
+use evo::simulation::food::FoodKind;
 use evo::simulation::locatable::Locatable;
+use evo::simulation::morphology::Morphology;
+use evo::simulation::novelty::BehaviourCharacterisation;
+use evo::simulation::organism::TouchState;
 use evo::simulation::{food::Food, organism::Organism, projectile::Projectile};
 use ndarray::Array1;
 
@@ -8,6 +17,7 @@ fn test_food_locatable() {
         pos: Array1::from_vec(vec![10.0, 20.0]),
         energy: 1.0,
         age: 0.0,
+        kind: FoodKind::Plant,
     };
 
     // Test pos accessor
@@ -56,6 +66,7 @@ fn test_locatable_trait_polymorphism() {
         pos: Array1::from_vec(vec![5.0, 5.0]),
         energy: 1.0,
         age: 0.0,
+        kind: FoodKind::Plant,
     };
 
     let projectile = Projectile::new(
@@ -94,14 +105,25 @@ fn test_organism_locatable_update() {
         memory: Array1::zeros(8),
         brain: Brain::new(&[10, 8, 6], 0.1),
         attack_cooldown: 2.0,
+        reproduction_cooldown: 0.0,
         last_brain_inputs: Array1::zeros(10),
         vision_angles: Array1::zeros(5),
         vision_lengths: Array1::ones(5),
+        morphology: Morphology::default(),
+        diet: Array1::zeros(2),
         dna: Array1::zeros(2),
+        mate_preference: Array1::zeros(5),
         pool_id: 0,
         birth_generation: 0,
         reproduction_method: 0,
         parent_avg_score: 0.0,
+        behaviour: BehaviourCharacterisation::default(),
+        novelty: 0.0,
+        touch: TouchState::default(),
+        velocity: [0.0, 0.0],
+        angular_velocity: 0.0,
+        health: 1.0,
+        energy_flows: FlowTotals::default(),
     };
 
     // Test pos accessor
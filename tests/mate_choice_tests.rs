@@ -0,0 +1,149 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::{EventQueue, SimulationEvent, apply_events};
+use evo::simulation::mate_choice;
+use evo::simulation::params::Params;
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.mate_choice.enabled = true;
+    params
+}
+
+/// Makes organism `idx` display `signal` and prefer exactly `signal` and its own DNA.
+fn set_display_and_taste(ecosystem: &mut Ecosystem, idx: usize, signal: [f32; 3]) {
+    let organism = &mut ecosystem.organisms[idx];
+    organism.signal = Array1::from_vec(signal.to_vec());
+    let mut preference = signal.to_vec();
+    preference.extend(organism.dna.iter());
+    organism.mate_preference = Array1::from_vec(preference);
+}
+
+fn mutual_intents(a: usize, b: usize) -> EventQueue {
+    let mut queue = EventQueue::new();
+    for (organism_id, partner_id) in [(a, b), (b, a)] {
+        queue.push(SimulationEvent::SexualReproductionIntent {
+            organism_id,
+            partner_id,
+            energy_contribution: 0.5,
+            pos: Array1::from_vec(vec![100.0, 100.0]),
+        });
+    }
+    queue
+}
+
+#[test]
+fn test_random_preference_has_signal_and_dna_parts() {
+    let preference = mate_choice::random_preference(3);
+    assert_eq!(preference.len(), 5);
+    assert!(preference.iter().take(3).all(|x| (-1.0..=1.0).contains(x)));
+    assert!(preference.iter().skip(3).all(|x| (0.0..=1.0).contains(x)));
+}
+
+#[test]
+fn test_acceptance_falls_with_mismatch() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    let dna = ecosystem.organisms[0].dna.clone();
+    ecosystem.organisms[1].dna.clone_from(&dna);
+    ecosystem.organisms[2].dna.clone_from(&dna);
+    set_display_and_taste(&mut ecosystem, 0, [1.0, 1.0, 1.0]);
+    set_display_and_taste(&mut ecosystem, 1, [1.0, 1.0, 1.0]);
+    set_display_and_taste(&mut ecosystem, 2, [-1.0, -1.0, -1.0]);
+
+    let chooser = &ecosystem.organisms[0];
+    let perfect = mate_choice::acceptance_probability(chooser, &ecosystem.organisms[1], &params);
+    let opposite = mate_choice::acceptance_probability(chooser, &ecosystem.organisms[2], &params);
+
+    assert!((perfect - 1.0).abs() < 1e-6);
+    assert!(opposite < perfect);
+}
+
+#[test]
+fn test_disabled_mate_choice_accepts_everyone() {
+    let mut params = create_test_params();
+    params.mate_choice.enabled = false;
+    let mut ecosystem = Ecosystem::new(&params);
+    set_display_and_taste(&mut ecosystem, 0, [1.0, 1.0, 1.0]);
+    set_display_and_taste(&mut ecosystem, 1, [-1.0, -1.0, -1.0]);
+
+    let probability = mate_choice::acceptance_probability(
+        &ecosystem.organisms[0],
+        &ecosystem.organisms[1],
+        &params,
+    );
+    assert_eq!(probability, 1.0);
+}
+
+#[test]
+fn test_mutual_rejection_prevents_offspring() {
+    let mut params = create_test_params();
+    params.mate_choice.selectivity = 1000.0;
+    params.mate_choice.dna_weight = 0.0;
+    let mut ecosystem = Ecosystem::new(&params);
+    for organism in &mut ecosystem.organisms {
+        organism.energy = 2.0;
+    }
+    set_display_and_taste(&mut ecosystem, 0, [1.0, 1.0, 1.0]);
+    set_display_and_taste(&mut ecosystem, 1, [-1.0, -1.0, -1.0]);
+    let (a, b) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+
+    apply_events(&mut ecosystem, &params, mutual_intents(a, b));
+
+    assert_eq!(ecosystem.organisms.len(), params.n_organism);
+    assert!(ecosystem.organisms[0].can_reproduce());
+}
+
+#[test]
+fn test_matching_preferences_produce_offspring_with_inherited_preference() {
+    let mut params = create_test_params();
+    params.mate_choice.selectivity = 1000.0;
+    params.mate_choice.dna_weight = 0.0;
+    let mut ecosystem = Ecosystem::new(&params);
+    for organism in &mut ecosystem.organisms {
+        organism.energy = 2.0;
+    }
+    set_display_and_taste(&mut ecosystem, 0, [0.5, -0.5, 0.0]);
+    set_display_and_taste(&mut ecosystem, 1, [0.5, -0.5, 0.0]);
+    let (a, b) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+
+    apply_events(&mut ecosystem, &params, mutual_intents(a, b));
+
+    assert_eq!(ecosystem.organisms.len(), params.n_organism + 1);
+    let offspring = ecosystem.organisms.last().unwrap();
+    assert_eq!(offspring.mate_preference.len(), params.signal_size + 2);
+    let tolerance = params.mate_choice.preference_mutation_rate + 1e-5;
+    for (inherited, parent) in offspring.mate_preference.iter().zip([0.5, -0.5, 0.0]) {
+        assert!((inherited - parent).abs() <= tolerance);
+    }
+}
+
+#[test]
+fn test_preference_display_correlation() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    let signals = [
+        [-1.0, 0.2, 0.9],
+        [-0.3, -0.4, 0.1],
+        [0.4, 0.8, -0.6],
+        [0.9, -0.9, -0.2],
+    ];
+    for (idx, signal) in signals.into_iter().enumerate() {
+        set_display_and_taste(&mut ecosystem, idx, signal);
+    }
+
+    let correlation =
+        mate_choice::preference_display_correlation(&ecosystem.organisms, params.signal_size)
+            .unwrap();
+    assert!((correlation - 1.0).abs() < 1e-5);
+
+    assert!(
+        mate_choice::preference_display_correlation(&ecosystem.organisms[..1], params.signal_size)
+            .is_none()
+    );
+}
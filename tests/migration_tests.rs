@@ -0,0 +1,161 @@
+#![allow(missing_docs)]
+
+mod common;
+
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::migration::{
+    self, MigrantPolicy, MigrationKind, MigrationParams, MigrationTopology,
+};
+use evo::simulation::params::{Params, PoolOverrides};
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.n_organism = 12;
+    params.num_genetic_pools = 3;
+    params.migration = MigrationParams {
+        enabled: true,
+        topology: MigrationTopology::Custom,
+        adjacency: vec![
+            vec![false, true, false],
+            vec![false, false, false],
+            vec![false, false, false],
+        ],
+        rate: 0.5,
+        interval: 1.0,
+        policy: MigrantPolicy::Random,
+        kind: MigrationKind::Move,
+    };
+    params
+}
+
+fn pool_size(ecosystem: &Ecosystem, pool_id: usize) -> usize {
+    ecosystem
+        .organisms
+        .iter()
+        .filter(|org| org.pool_id == pool_id)
+        .count()
+}
+
+#[test]
+fn test_topologies() {
+    let mut params = MigrationParams {
+        topology: MigrationTopology::Ring,
+        ..Default::default()
+    };
+    assert_eq!(params.destinations(0, 4), vec![1, 3]);
+    assert_eq!(params.destinations(2, 4), vec![1, 3]);
+
+    params.topology = MigrationTopology::Star;
+    assert_eq!(params.destinations(0, 4), vec![1, 2, 3]);
+    assert_eq!(params.destinations(2, 4), vec![0]);
+
+    params.topology = MigrationTopology::FullyConnected;
+    assert_eq!(params.destinations(1, 3), vec![0, 2]);
+
+    params.topology = MigrationTopology::Custom;
+    params.adjacency = vec![vec![false, false, true], vec![true, false, false]];
+    assert_eq!(params.destinations(0, 3), vec![2]);
+    assert_eq!(params.destinations(1, 3), vec![0]);
+    // Rows missing from the matrix are unconnected
+    assert!(params.destinations(2, 3).is_empty());
+}
+
+#[test]
+fn test_move_migration_follows_adjacency_and_records_flows() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+
+    migration::migrate(&mut ecosystem, &params, 1.0);
+
+    assert_eq!(ecosystem.organisms.len(), params.n_organism);
+    assert_eq!(pool_size(&ecosystem, 0), 2);
+    assert_eq!(pool_size(&ecosystem, 1), 6);
+    assert_eq!(pool_size(&ecosystem, 2), 4);
+    assert_eq!(ecosystem.migration.outflow(0), 2);
+    assert_eq!(ecosystem.migration.inflow(1), 2);
+    assert_eq!(ecosystem.migration.inflow(0), 0);
+    assert!(
+        ecosystem
+            .event_log
+            .events()
+            .iter()
+            .any(|event| event.description.contains("from pool 0 to pool 1"))
+    );
+}
+
+#[test]
+fn test_migration_refuses_brains_that_dont_fit_the_destination() {
+    let mut params = create_test_params();
+    params.pool_overrides = vec![
+        PoolOverrides::default(),
+        PoolOverrides {
+            hidden_layers: Some(vec![4]),
+            ..Default::default()
+        },
+    ];
+    let mut ecosystem = Ecosystem::new(&params);
+
+    for kind in [MigrationKind::Move, MigrationKind::Copy] {
+        params.migration.kind = kind;
+        migration::migrate(&mut ecosystem, &params, 1.0);
+
+        assert_eq!(ecosystem.organisms.len(), params.n_organism);
+        assert_eq!(pool_size(&ecosystem, 0), 4);
+        assert_eq!(pool_size(&ecosystem, 1), 4);
+        assert_eq!(ecosystem.migration.inflow(1), 0);
+    }
+    assert!(ecosystem.event_log.events().iter().any(|event| {
+        event
+            .description
+            .contains("could not migrate from pool 0 to pool 1")
+    }));
+}
+
+#[test]
+fn test_migration_waits_for_interval() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+
+    migration::migrate(&mut ecosystem, &params, 0.5);
+
+    assert_eq!(pool_size(&ecosystem, 0), 4);
+    assert_eq!(ecosystem.migration.outflow(0), 0);
+}
+
+#[test]
+fn test_copy_migration_of_best_organisms() {
+    let mut params = create_test_params();
+    params.migration.kind = MigrationKind::Copy;
+    params.migration.policy = MigrantPolicy::Best;
+    params.migration.rate = 0.25;
+    let mut ecosystem = Ecosystem::new(&params);
+
+    let best_idx = ecosystem
+        .organisms
+        .iter()
+        .position(|org| org.pool_id == 0)
+        .unwrap();
+    ecosystem.organisms[best_idx].score = 100;
+    let best_dna = ecosystem.organisms[best_idx].dna.clone();
+
+    migration::migrate(&mut ecosystem, &params, 1.0);
+
+    assert_eq!(ecosystem.organisms.len(), params.n_organism + 1);
+    assert_eq!(pool_size(&ecosystem, 0), 4);
+    let copy = ecosystem.organisms.last().unwrap();
+    assert_eq!(copy.pool_id, 1);
+    assert_eq!(copy.dna, best_dna);
+    assert_eq!(copy.score, 0);
+}
+
+#[test]
+fn test_disabled_migration_does_nothing() {
+    let mut params = create_test_params();
+    params.migration.enabled = false;
+    let mut ecosystem = Ecosystem::new(&params);
+
+    migration::migrate(&mut ecosystem, &params, 10.0);
+
+    assert_eq!(pool_size(&ecosystem, 0), 4);
+    assert_eq!(ecosystem.migration.outflow(0), 0);
+}
@@ -0,0 +1,140 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::actions;
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::food::{Food, FoodKind};
+use evo::simulation::morphology;
+use evo::simulation::params::Params;
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.morphology.enabled = true;
+    params
+}
+
+#[test]
+fn test_inherit_blends_parents_without_mutation() {
+    let mut params = create_test_params();
+    params.morphology.mutation_rate = 0.0;
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms[0].morphology.body_scale = 2.0;
+    ecosystem.organisms[0].vision_lengths.fill(40.0);
+    ecosystem.organisms[1].morphology.body_scale = 1.0;
+    ecosystem.organisms[1].vision_lengths.fill(20.0);
+
+    let (parents, children) = ecosystem.organisms.split_at_mut(2);
+    let child = &mut children[0];
+    morphology::inherit(child, &parents[0], &parents[1], 0.5, &params);
+
+    assert!((child.morphology.body_scale - 1.5).abs() < 1e-6);
+    assert!(
+        child
+            .vision_lengths
+            .iter()
+            .all(|&l| (l - 30.0).abs() < 1e-4)
+    );
+}
+
+#[test]
+fn test_disabled_morphology_is_not_inherited() {
+    let mut params = create_test_params();
+    params.morphology.enabled = false;
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms[0].morphology.body_scale = 2.0;
+
+    let (parents, children) = ecosystem.organisms.split_at_mut(1);
+    morphology::inherit_single(&mut children[0], &parents[0], &params);
+
+    assert_eq!(children[0].morphology.body_scale, 1.0);
+    assert_eq!(morphology::metabolic_rate(&children[0], &params), 0.0);
+}
+
+#[test]
+fn test_mutation_stays_within_bounds() {
+    let mut params = create_test_params();
+    params.morphology.mutation_rate = 1.0;
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+
+    for _ in 0..200 {
+        morphology::mutate(organism, &params);
+    }
+
+    let (min, max) = (params.morphology.min_scale, params.morphology.max_scale);
+    for scale in [
+        organism.morphology.body_scale,
+        organism.morphology.speed_scale,
+        organism.morphology.scent_scale,
+    ] {
+        assert!((min..=max).contains(&scale));
+    }
+    assert!(
+        organism
+            .vision_lengths
+            .iter()
+            .all(|l| (min * params.vision_radius..=max * params.vision_radius).contains(l))
+    );
+    assert!(
+        organism
+            .vision_angles
+            .iter()
+            .all(|a| a.abs() <= std::f32::consts::PI)
+    );
+}
+
+#[test]
+fn test_metabolic_cost_grows_with_traits() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    let baseline = morphology::metabolic_rate(&ecosystem.organisms[0], &params);
+
+    ecosystem.organisms[0].morphology.body_scale = 2.0;
+    let bigger = morphology::metabolic_rate(&ecosystem.organisms[0], &params);
+    ecosystem.organisms[0].morphology.speed_scale = 2.0;
+    let faster = morphology::metabolic_rate(&ecosystem.organisms[0], &params);
+
+    assert!(baseline > 0.0);
+    assert!(bigger > baseline);
+    assert!(faster > bigger);
+}
+
+#[test]
+fn test_body_size_extends_food_reach() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.pos = Array1::from_vec(vec![100.0, 100.0]);
+    let food = vec![Food {
+        pos: Array1::from_vec(vec![107.0, 100.0]),
+        energy: 1.0,
+        age: 0.0,
+        kind: FoodKind::Plant,
+    }];
+    let neighbors = [(49.0, 0)];
+
+    let events = actions::execute_food_consumption(organism, &neighbors, &food, &params);
+    assert!(events.is_empty());
+
+    organism.morphology.body_scale = 2.0;
+    let events = actions::execute_food_consumption(organism, &neighbors, &food, &params);
+    assert_eq!(events.len(), 1);
+}
+
+#[test]
+fn test_speed_scale_multiplies_movement() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.pos = Array1::from_vec(vec![100.0, 100.0]);
+    organism.rot = 0.0;
+    organism.morphology.speed_scale = 2.0;
+
+    actions::execute_movement(organism, 1.0, &params, 0.1);
+
+    let expected = 100.0 + params.move_multiplier * 2.0 * 0.1;
+    assert!((organism.pos[0] - expected).abs() < 1e-4);
+}
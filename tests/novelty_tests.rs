@@ -0,0 +1,66 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+use evo::simulation::novelty::{
+    ACTION_BINS, BehaviourCharacterisation, NoveltyArchive, behaviour_distance,
+};
+
+#[test]
+fn test_behaviour_vector_has_fixed_length() {
+    let bc = BehaviourCharacterisation::default();
+    assert_eq!(bc.to_vector(8).len(), 8 * 2 + ACTION_BINS);
+
+    let bc = BehaviourCharacterisation {
+        trajectory: vec![[0.1, 0.2], [0.3, 0.4]],
+        action_counts: [1, 0, 2, 0, 0, 1],
+        steps: 2,
+        ..Default::default()
+    };
+    let features = bc.to_vector(4);
+    assert_eq!(features.len(), 4 * 2 + ACTION_BINS);
+
+    // Trajectory is padded with the last sample
+    assert_eq!(&features[4..8], &[0.3, 0.4, 0.3, 0.4]);
+    // Action counts are normalized by the number of steps
+    assert!((features[8 + 2] - 1.0).abs() < 1e-6);
+    assert!((features[8] - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_novelty_is_mean_distance_to_k_nearest() {
+    let mut archive = NoveltyArchive::default();
+    archive.add(vec![0.0, 0.0], 10);
+    archive.add(vec![1.0, 0.0], 10);
+    archive.add(vec![0.0, 3.0], 10);
+
+    // Nearest two are at distances 0.0 and 1.0
+    let novelty = archive.novelty(&[0.0, 0.0], std::iter::empty(), 2);
+    assert!((novelty - 0.5).abs() < 1e-6);
+
+    // Population members count as neighbours too
+    let population = [vec![0.0, 0.5]];
+    let novelty = archive.novelty(&[0.0, 0.0], population.iter().map(Vec::as_slice), 2);
+    assert!((novelty - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_novelty_of_empty_archive_is_zero() {
+    let archive = NoveltyArchive::default();
+    assert_eq!(archive.novelty(&[1.0, 2.0], std::iter::empty(), 5), 0.0);
+}
+
+#[test]
+fn test_archive_evicts_oldest() {
+    let mut archive = NoveltyArchive::default();
+    for i in 0..5 {
+        archive.add(vec![i as f32], 3);
+    }
+    assert_eq!(archive.len(), 3);
+    let first = archive.behaviours().next().unwrap();
+    assert_eq!(first, &[2.0]);
+}
+
+#[test]
+fn test_behaviour_distance() {
+    assert!((behaviour_distance(&[0.0, 0.0], &[3.0, 4.0]) - 5.0).abs() < 1e-6);
+}
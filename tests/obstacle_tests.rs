@@ -0,0 +1,159 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::obstacle::{self, Obstacle, ObstacleParams};
+use evo::simulation::organism::{Sense, Vision};
+use evo::simulation::params::Params;
+use evo::simulation::projectile::Projectile;
+use evo::simulation::spatial::Neighbourhood;
+use evo::simulation::view::WorldView;
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.obstacles = ObstacleParams {
+        enabled: true,
+        obstacles: vec![Obstacle::rectangle(400.0, 400.0, 100.0, 100.0)],
+    };
+    common::fit_layer_sizes(&mut params);
+    params
+}
+
+fn point(x: f32, y: f32) -> Array1<f32> {
+    Array1::from_vec(vec![x, y])
+}
+
+#[test]
+fn test_rectangle_contains_and_ray_hits() {
+    let rock = Obstacle::rectangle(400.0, 400.0, 100.0, 100.0);
+
+    assert!(rock.contains(&point(450.0, 450.0)));
+    assert!(!rock.contains(&point(350.0, 450.0)));
+
+    let hit = rock.segment_hit(&point(350.0, 450.0), &point(450.0, 450.0));
+    assert!((hit.unwrap() - 50.0).abs() < 1e-4);
+    assert!(
+        rock.segment_hit(&point(350.0, 450.0), &point(390.0, 450.0))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_bodies_are_pushed_out_of_obstacles() {
+    let obstacles = vec![Obstacle::rectangle(400.0, 400.0, 100.0, 100.0)];
+
+    // Overlapping from outside: pushed one radius clear of the edge
+    let mut pos = point(398.0, 450.0);
+    assert!(obstacle::resolve_collision(&mut pos, 3.0, &obstacles));
+    assert!((pos[0] - 397.0).abs() < 1e-4);
+
+    // Inside: leaves through the nearest edge
+    let mut pos = point(405.0, 450.0);
+    assert!(obstacle::resolve_collision(&mut pos, 3.0, &obstacles));
+    assert!((pos[0] - 397.0).abs() < 1e-4);
+    assert!(!obstacle::is_blocked(&obstacles, &pos));
+
+    // Clear of the obstacle: untouched
+    let mut pos = point(300.0, 300.0);
+    assert!(!obstacle::resolve_collision(&mut pos, 3.0, &obstacles));
+}
+
+#[test]
+fn test_vision_reports_walls_and_hides_what_is_behind() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.food.clear();
+    ecosystem.organisms.truncate(2);
+
+    let viewer = &mut ecosystem.organisms[0];
+    viewer.pos = point(380.0, 450.0);
+    viewer.rot = 0.0;
+    viewer.vision_angles = Array1::zeros(params.num_vision_directions);
+    viewer.vision_lengths = Array1::from_elem(params.num_vision_directions, 30.0);
+    // Another organism inside the wall's shadow
+    ecosystem.organisms[1].pos = point(405.0, 450.0);
+
+    let vision = Vision::new();
+    assert_eq!(vision.input_size(&params), params.num_vision_directions * 4);
+    let outputs = vision.sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+
+    // Wall 20 units away on every ray, organism behind it not seen
+    assert!((outputs[0] - (1.0 - 20.0 / params.vision_radius)).abs() < 1e-4);
+    assert_eq!(outputs[2], 0.0);
+    assert_eq!(outputs[3], 1.0);
+}
+
+#[test]
+fn test_vision_has_no_wall_channel_without_obstacles() {
+    let mut params = create_test_params();
+    params.obstacles.enabled = false;
+
+    assert_eq!(
+        Vision::new().input_size(&params),
+        params.num_vision_directions * 3
+    );
+}
+
+#[test]
+fn test_food_never_spawns_inside_obstacles() {
+    let mut params = create_test_params();
+    params.obstacles.obstacles = vec![Obstacle::rectangle(0.0, 0.0, 1000.0, 800.0)];
+    params.max_food = 500;
+    let mut ecosystem = Ecosystem::new(&params);
+    for _ in 0..20 {
+        ecosystem.spawn(&params, 1.0);
+    }
+
+    assert!(!ecosystem.food.is_empty());
+    assert!(
+        ecosystem
+            .food
+            .iter()
+            .all(|f| !obstacle::is_blocked(&ecosystem.obstacles, &f.pos))
+    );
+}
+
+#[test]
+fn test_projectiles_stop_at_obstacles() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms.clear();
+    ecosystem.projectiles.push(Projectile::new(
+        point(395.0, 450.0),
+        0.0,
+        100.0,
+        1.0,
+        999,
+        1000.0,
+    ));
+
+    ecosystem.step(&params, 0.1);
+
+    assert!(ecosystem.projectiles.is_empty());
+}
+
+#[test]
+fn test_obstacles_survive_save_and_load() {
+    let params = create_test_params();
+    let ecosystem = Ecosystem::new(&params);
+    let path = std::env::temp_dir().join("evo_obstacle_roundtrip.json");
+    let path = path.to_str().unwrap();
+
+    ecosystem.save_to_file(path).unwrap();
+    let loaded = Ecosystem::load_from_file(path).unwrap();
+    std::fs::remove_file(path).ok();
+
+    assert_eq!(loaded.obstacles.len(), 1);
+    assert_eq!(
+        loaded.obstacles[0].vertices(),
+        ecosystem.obstacles[0].vertices()
+    );
+}
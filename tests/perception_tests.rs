@@ -1,74 +1,19 @@
 #![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
 
-use evo::simulation::ecosystem::Ecosystem;
-use evo::simulation::organism::{Perception, Proprioception, Scent, Sense, Vision};
-use evo::simulation::params::Params;
-
-fn create_test_params() -> Params {
-    let signal_size: usize = 3;
-    let num_vision_directions: usize = 5;
-    let memory_size: usize = 8;
-
-    let layer_sizes = vec![
-        3 * num_vision_directions + signal_size + memory_size + 7,
-        16,
-        signal_size + memory_size + 6,
-    ];
+mod common;
 
-    Params {
-        body_radius: 3.0,
-        vision_radius: 50.0,
-        scent_radius: 20.0,
-        share_radius: 15.0,
-        dna_breeding_distance: 0.2,
-        dna_mutation_rate: 0.1,
-        idle_energy_rate: 0.01,
-        move_energy_rate: 0.0001,
-        move_multiplier: 50.0,
-        rot_energy_rate: 0.00001,
-        num_vision_directions,
-        fov: std::f32::consts::PI / 2.0,
-        signal_size,
-        memory_size,
-        n_organism: 10,
-        max_organism: 20,
-        n_food: 10,
-        max_food: 20,
-        box_width: 500.0,
-        box_height: 500.0,
-        layer_sizes,
-        attack_cost_rate: 0.1,
-        attack_damage_rate: 0.5,
-        attack_cooldown: 1.0,
-        corpse_energy_ratio: 0.5,
-        max_energy: 2.0,
-        food_energy: 1.0,
-        projectile_speed: 100.0,
-        projectile_range: 50.0,
-        projectile_radius: 2.0,
-        organism_spawn_rate: 1.0,
-        food_spawn_rate: 1.0,
-        food_lifetime: 0.0,
-        num_genetic_pools: 1,
-        pool_interbreed_prob: 0.0,
-        brain_type: evo::simulation::brain::BrainType::MLP,
-        transformer_model_dim: 64,
-        transformer_num_blocks: 2,
-        transformer_num_heads: 4,
-        transformer_head_dim: 16,
-        transformer_ff_dim: 128,
-        graveyard_size: 100,
-        reproduction_energy_multiplier: 1.2,
-        reproduction_radius: 15.0,
-        spawn_from_graveyard: true,
-        unbalanced_pool_sampling: false,
-        empty_pool_seed_count: 5,
-    }
-}
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::organism::{
+    Perception, Proprioception, Scent, Sense, SenseKind, StereoScent, Vision,
+};
+use evo::simulation::spatial::Neighbourhood;
+use evo::simulation::view::WorldView;
+use ndarray::{Array1, s};
 
 #[test]
 fn test_vision_sense_size() {
-    let params = create_test_params();
+    let params = common::small_test_params();
     let vision = Vision::new();
 
     let expected_size = params.num_vision_directions * 3; // distance, pool_match, is_organism
@@ -78,7 +23,7 @@ fn test_vision_sense_size() {
 
 #[test]
 fn test_scent_sense_size() {
-    let params = create_test_params();
+    let params = common::small_test_params();
     let scent = Scent::new();
 
     let expected_size = params.signal_size; // signal channels
@@ -88,7 +33,7 @@ fn test_scent_sense_size() {
 
 #[test]
 fn test_proprioception_sense_size() {
-    let params = create_test_params();
+    let params = common::small_test_params();
     let proprio = Proprioception::new();
 
     let expected_size = params.memory_size + 7; // memory + energy + rotation(2) + position(4)
@@ -98,7 +43,7 @@ fn test_proprioception_sense_size() {
 
 #[test]
 fn test_perception_combines_senses() {
-    let params = create_test_params();
+    let params = common::small_test_params();
     let ecosystem = Ecosystem::new(&params);
 
     let perception = Perception::default();
@@ -112,14 +57,19 @@ fn test_perception_combines_senses() {
 
     // Test perception on first organism
     if let Some(organism) = ecosystem.organisms.first() {
-        let inputs = perception.perceive(organism, &ecosystem, &params, None);
+        let inputs = perception.perceive(
+            organism,
+            &WorldView::of(&ecosystem, &params),
+            &params,
+            &Neighbourhood::around(organism, &ecosystem, &params),
+        );
         assert_eq!(inputs.len(), expected_size);
     }
 }
 
 #[test]
 fn test_custom_perception() {
-    let params = create_test_params();
+    let params = common::small_test_params();
 
     // Create perception with only vision and proprioception
     let perception = Perception::new(vec![
@@ -133,12 +83,17 @@ fn test_custom_perception() {
 
 #[test]
 fn test_proprioception_reads_organism_state() {
-    let params = create_test_params();
+    let params = common::small_test_params();
     let ecosystem = Ecosystem::new(&params);
     let proprio = Proprioception::new();
 
     if let Some(organism) = ecosystem.organisms.first() {
-        let outputs = proprio.sense(organism, &ecosystem, &params, None);
+        let outputs = proprio.sense(
+            organism,
+            &WorldView::of(&ecosystem, &params),
+            &params,
+            &Neighbourhood::around(organism, &ecosystem, &params),
+        );
 
         // Should have memory + energy + rotation(2) + position(4)
         assert_eq!(outputs.len(), params.memory_size + 7);
@@ -148,3 +103,122 @@ fn test_proprioception_reads_organism_state() {
         assert_eq!(outputs[energy_idx], organism.energy);
     }
 }
+
+#[test]
+fn test_stereo_scent_sense_size() {
+    let params = common::small_test_params();
+    let stereo = StereoScent::new(3);
+    assert_eq!(stereo.input_size(&params), 3 * (params.signal_size + 1));
+    assert_eq!(stereo.name(), "StereoScent");
+}
+
+#[test]
+fn test_stereo_scent_separates_left_and_right() {
+    let params = common::small_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms.truncate(2);
+    ecosystem.food.truncate(1);
+
+    // Facing +x; food at +y (first sector), a signalling neighbour at -y (second)
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![500.0, 500.0]);
+    ecosystem.organisms[0].rot = 0.0;
+    ecosystem.food[0].pos = Array1::from_vec(vec![500.0, 510.0]);
+    ecosystem.organisms[1].pos = Array1::from_vec(vec![500.0, 490.0]);
+    ecosystem.organisms[1].signal = Array1::ones(params.signal_size);
+
+    let outputs = StereoScent::default().sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    let stride = params.signal_size + 1;
+    let factor = 1.0 - 10.0 / params.scent_radius;
+
+    assert!((outputs[params.signal_size] - factor).abs() < 1e-5);
+    assert!(
+        outputs
+            .slice(s![..params.signal_size])
+            .iter()
+            .all(|&x| x == 0.0)
+    );
+    assert!(
+        outputs
+            .slice(s![stride..stride + params.signal_size])
+            .iter()
+            .all(|&x| (x - factor).abs() < 1e-5)
+    );
+    assert_eq!(outputs[stride + params.signal_size], 0.0);
+
+    // Turning around swaps the sides
+    ecosystem.organisms[0].rot = std::f32::consts::PI;
+    let turned = StereoScent::default().sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert!((turned[stride + params.signal_size] - factor).abs() < 1e-5);
+    assert_eq!(turned[params.signal_size], 0.0);
+}
+
+#[test]
+fn test_perception_follows_params_senses() {
+    let mut params = common::small_test_params();
+    params.senses = vec![
+        SenseKind::Proprioception,
+        SenseKind::StereoScent { sectors: 4 },
+        SenseKind::Vision,
+    ];
+    let perception = Perception::from_params(&params);
+
+    let names: Vec<&str> = perception.senses().iter().map(|s| s.name()).collect();
+    assert_eq!(names, ["Proprioception", "StereoScent", "Vision"]);
+    assert_eq!(
+        perception.total_input_size(&params),
+        (params.memory_size + 7) + 4 * (params.signal_size + 1) + params.num_vision_directions * 3
+    );
+
+    let labels = perception.input_labels(&params);
+    assert_eq!(labels[0], "Mem 0");
+    assert_eq!(labels[params.memory_size], "Energy");
+    assert_eq!(labels[params.memory_size + 7], "SS0 S0");
+    assert_eq!(labels.last().unwrap(), "V4 T");
+}
+
+#[test]
+fn test_every_sense_labels_each_input() {
+    let mut params = common::small_test_params();
+    params.environment.enabled = true;
+    params.pheromones.enabled = true;
+    params.obstacles.enabled = true;
+
+    for kind in SenseKind::ALL {
+        let sense = kind.build();
+        assert_eq!(sense.name(), kind.name());
+        assert_eq!(
+            sense.input_labels(&params).len(),
+            sense.input_size(&params),
+            "{}",
+            kind.name()
+        );
+    }
+}
+
+#[test]
+fn test_world_view_mirrors_organisms() {
+    let params = common::small_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.step(&params, 0.1);
+
+    let world = WorldView::of(&ecosystem, &params);
+    assert_eq!(world.organisms.len(), ecosystem.organisms.len());
+    assert_eq!(world.food.len(), ecosystem.food.len());
+    for (view, organism) in world.organisms.iter().zip(&ecosystem.organisms) {
+        assert_eq!(view.id, organism.id);
+        assert_eq!(view.pos, organism.pos);
+        assert_eq!(view.signal, organism.signal);
+        assert_eq!(view.can_reproduce, organism.can_reproduce());
+        assert!((view.body_radius - organism.morphology.body_radius(&params)).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,178 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::actions;
+use evo::simulation::boundary::BoundaryMode;
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::{self, EventQueue};
+use evo::simulation::organism::{Perception, Pheromones, Sense};
+use evo::simulation::params::Params;
+use evo::simulation::pheromone::{self, PheromoneField, PheromoneParams};
+use evo::simulation::spatial::Neighbourhood;
+use evo::simulation::view::WorldView;
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.pheromones = PheromoneParams {
+        enabled: true,
+        channels: 2,
+        cell_size: 10.0,
+        evaporation_rate: 0.0,
+        diffusion_rate: 0.0,
+        ..Default::default()
+    };
+    common::fit_layer_sizes(&mut params);
+    params
+}
+
+fn point(x: f32, y: f32) -> Array1<f32> {
+    Array1::from_vec(vec![x, y])
+}
+
+#[test]
+fn test_deposits_stay_in_their_channel() {
+    let params = create_test_params();
+    let mut field = PheromoneField::new(&params);
+    assert_eq!(field.cols, 100);
+    assert_eq!(field.channels, 2);
+
+    field.deposit(&point(55.0, 55.0), 1, 2.0);
+    assert_eq!(field.concentration_at(&point(51.0, 59.0), 1), 2.0);
+    assert_eq!(field.concentration_at(&point(55.0, 55.0), 0), 0.0);
+    assert_eq!(field.total(1), 2.0);
+    assert_eq!(field.total(0), 0.0);
+
+    // Unknown channels are ignored
+    field.deposit(&point(55.0, 55.0), 5, 1.0);
+    assert_eq!(field.total(0) + field.total(1), 2.0);
+}
+
+#[test]
+fn test_field_evaporates() {
+    let mut params = create_test_params();
+    params.pheromones.evaporation_rate = 0.5;
+    let mut field = PheromoneField::new(&params);
+    field.deposit(&point(500.0, 500.0), 0, 1.0);
+
+    field.update(&params, 2.0);
+
+    assert!((field.total(0) - (-1.0f32).exp()).abs() < 1e-5);
+}
+
+#[test]
+fn test_diffusion_spreads_and_conserves() {
+    let mut params = create_test_params();
+    params.pheromones.diffusion_rate = 0.1;
+    let mut field = PheromoneField::new(&params);
+    // Source on the seam of the torus
+    field.deposit(&point(0.0, 500.0), 0, 1.0);
+
+    for _ in 0..10 {
+        field.update(&params, 0.5);
+    }
+
+    assert!((field.total(0) - 1.0).abs() < 1e-4);
+    assert!(field.concentration_at(&point(995.0, 500.0), 0) > 0.0);
+    assert!(field.concentration_at(&point(15.0, 500.0), 0) > 0.0);
+    assert_eq!(field.total(1), 0.0);
+}
+
+#[test]
+fn test_gradient_points_up_the_trail() {
+    let mut params = create_test_params();
+    let mut field = PheromoneField::new(&params);
+    field.deposit(&point(515.0, 505.0), 0, 1.0);
+
+    let [dx, dy] = field.gradient(&point(505.0, 505.0), 0, &params);
+    assert!(dx > 0.0);
+    assert_eq!(dy, 0.0);
+
+    // Across the seam only on a torus
+    field.deposit(&point(995.0, 305.0), 0, 1.0);
+    let [dx, _] = field.gradient(&point(5.0, 305.0), 0, &params);
+    assert!(dx < 0.0);
+    params.boundary_mode = BoundaryMode::Walls;
+    let [dx, _] = field.gradient(&point(5.0, 305.0), 0, &params);
+    assert_eq!(dx, 0.0);
+}
+
+#[test]
+fn test_sense_reads_concentration_and_relative_gradient() {
+    let mut params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.pheromones.deposit(&point(505.0, 505.0), 1, 1.0);
+    ecosystem.pheromones.deposit(&point(505.0, 515.0), 1, 3.0);
+
+    let organism = &mut ecosystem.organisms[0];
+    organism.pos = point(505.0, 505.0);
+    organism.rot = std::f32::consts::FRAC_PI_2;
+
+    let sense = Pheromones::new();
+    assert_eq!(sense.input_size(&params), 6);
+    let outputs = sense.sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert_eq!(outputs.len(), 6);
+    assert!((outputs[3] - 0.5).abs() < 1e-5);
+    // Facing +y, the trail is straight ahead
+    assert!((outputs[4] - 1.0).abs() < 1e-5);
+    assert!(outputs[5].abs() < 1e-5);
+    assert_eq!(outputs[0], 0.0);
+
+    assert_eq!(
+        Perception::default().total_input_size(&params),
+        params.layer_sizes[0]
+    );
+
+    params.pheromones.enabled = false;
+    assert_eq!(sense.input_size(&params), 0);
+    assert_eq!(pheromone::output_size(&params), 0);
+}
+
+#[test]
+fn test_deposit_outputs_cost_energy_and_mark_the_field() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms.truncate(1);
+    ecosystem.food.clear();
+
+    let mut organism = ecosystem.organisms[0].clone();
+    organism.pos = point(300.0, 300.0);
+    organism.energy = 1.0;
+    let mut outputs = Array1::zeros(params.layer_sizes[2]);
+    let deposit_start = params.signal_size + params.memory_size + 6;
+    outputs[deposit_start] = 1.0;
+
+    let events = actions::execute_pheromone_deposit(
+        &mut organism,
+        &outputs.as_slice().unwrap()[deposit_start..],
+        &params,
+        0.5,
+    );
+    assert_eq!(events.len(), 1);
+    let deposited = params.pheromones.deposit_rate * 0.5;
+    assert!(
+        (organism.energy - (1.0 - deposited * params.pheromones.deposit_energy_cost)).abs() < 1e-6
+    );
+
+    let mut queue = EventQueue::new();
+    for event in events {
+        queue.push(event);
+    }
+    events::apply_events(&mut ecosystem, &params, queue);
+    assert!(
+        (ecosystem
+            .pheromones
+            .concentration_at(&point(300.0, 300.0), 0)
+            - deposited)
+            .abs()
+            < 1e-6
+    );
+    assert_eq!(ecosystem.pheromones.total(1), 0.0);
+}
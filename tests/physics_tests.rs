@@ -0,0 +1,148 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::actions;
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::organism::{Organism, Proprioception, Sense};
+use evo::simulation::params::Params;
+use evo::simulation::physics::{self, MassMode};
+use evo::simulation::spatial::Neighbourhood;
+use evo::simulation::view::WorldView;
+use ndarray::Array1;
+
+fn physics_params() -> Params {
+    let mut params = common::small_test_params();
+    params.physics.enabled = true;
+    params
+}
+
+/// A default-bodied organism at rest, facing +x.
+fn resting_organism(params: &Params) -> Organism {
+    let mut organism = Ecosystem::new(params).organisms.remove(0);
+    organism.pos = Array1::from_vec(vec![100.0, 100.0]);
+    organism.rot = 0.0;
+    organism.energy = 1.0;
+    organism.velocity = [0.0, 0.0];
+    organism.angular_velocity = 0.0;
+    organism
+}
+
+#[test]
+fn test_thrust_builds_momentum() {
+    let params = physics_params();
+    let mut organism = resting_organism(&params);
+    let dt = 0.1;
+
+    actions::execute_movement(&mut organism, 1.0, &params, dt);
+    assert!((organism.velocity[0] - params.physics.thrust * dt).abs() < 1e-4);
+    assert_eq!(
+        organism.pos[0], 100.0,
+        "thrust alone does not move the body"
+    );
+
+    // Coasting: no more thrust, the body keeps moving but slows down
+    physics::integrate(&mut organism, &params, dt);
+    let coasting_speed = organism.velocity[0];
+    let moved = organism.pos[0] - 100.0;
+    assert!(moved > 0.0);
+    physics::integrate(&mut organism, &params, dt);
+    assert!(organism.pos[0] - 100.0 > moved);
+    assert!(organism.velocity[0] < coasting_speed);
+}
+
+#[test]
+fn test_terminal_speed_balances_thrust_and_drag() {
+    let params = physics_params();
+    let mut organism = resting_organism(&params);
+    for _ in 0..2000 {
+        actions::execute_movement(&mut organism, 1.0, &params, 0.01);
+        physics::integrate(&mut organism, &params, 0.01);
+    }
+    // Exponential drag over a step slightly undershoots thrust / drag
+    let terminal = params.physics.thrust / params.physics.linear_drag;
+    assert!((organism.velocity[0] - terminal).abs() / terminal < 0.02);
+}
+
+#[test]
+fn test_heavier_bodies_accelerate_less() {
+    let mut params = physics_params();
+    let mut light = resting_organism(&params);
+    let mut heavy = resting_organism(&params);
+    heavy.morphology.body_scale = 2.0;
+    assert!((physics::mass(&heavy, &params) - 4.0).abs() < 1e-6);
+
+    actions::execute_movement(&mut light, 1.0, &params, 0.1);
+    actions::execute_movement(&mut heavy, 1.0, &params, 0.1);
+    assert!((light.velocity[0] - 4.0 * heavy.velocity[0]).abs() < 1e-4);
+
+    params.physics.mass_mode = MassMode::BodyAndEnergy;
+    params.physics.energy_mass = 0.5;
+    light.energy = 1.0;
+    assert!((physics::mass(&light, &params) - 1.5).abs() < 1e-6);
+    params.physics.mass_mode = MassMode::Fixed;
+    assert!((physics::mass(&heavy, &params) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_energy_cost_scales_with_force() {
+    let params = physics_params();
+    let mut gentle = resting_organism(&params);
+    let mut hard = resting_organism(&params);
+
+    actions::execute_movement(&mut gentle, 0.25, &params, 0.1);
+    actions::execute_movement(&mut hard, 0.5, &params, 0.1);
+    let gentle_cost = 1.0 - gentle.energy;
+    let hard_cost = 1.0 - hard.energy;
+    assert!(gentle_cost > 0.0);
+    assert!((hard_cost - 2.0 * gentle_cost).abs() < 1e-7);
+}
+
+#[test]
+fn test_torque_spins_up_and_drag_stops_it() {
+    let params = physics_params();
+    let mut organism = resting_organism(&params);
+
+    actions::execute_rotation(&mut organism, 1.0, &params, 0.1);
+    assert!(organism.angular_velocity > 0.0);
+    assert_eq!(organism.rot, 0.0);
+
+    for _ in 0..500 {
+        physics::integrate(&mut organism, &params, 0.1);
+    }
+    assert!(organism.rot > 0.0);
+    assert!(organism.angular_velocity.abs() < 1e-6);
+}
+
+#[test]
+fn test_proprioception_reports_own_velocity() {
+    let mut params = common::small_test_params();
+    let sense = Proprioception::new();
+    assert_eq!(sense.input_size(&params), params.memory_size + 7);
+
+    params.physics.enabled = true;
+    assert_eq!(sense.input_size(&params), params.memory_size + 10);
+    assert_eq!(sense.input_labels(&params).len(), sense.input_size(&params));
+
+    let mut ecosystem = Ecosystem::new(&params);
+    let organism = &mut ecosystem.organisms[0];
+    organism.rot = std::f32::consts::FRAC_PI_2;
+    organism.velocity = [0.0, params.move_multiplier / 2.0];
+    organism.angular_velocity = -5.0;
+    let organism = ecosystem.organisms[0].clone();
+
+    let outputs = sense.sense(
+        &organism,
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&organism, &ecosystem, &params),
+    );
+    let motion = params.memory_size + 7;
+    assert!(
+        (outputs[motion] - 0.5).abs() < 1e-5,
+        "moving straight ahead"
+    );
+    assert!(outputs[motion + 1].abs() < 1e-5);
+    assert!((outputs[motion + 2] + 0.5).abs() < 1e-5);
+}
@@ -0,0 +1,144 @@
+#![allow(missing_docs)]
+
+mod common;
+
+use evo::simulation::brain::{Brain, BrainType};
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::{EventQueue, SimulationEvent, apply_events};
+use evo::simulation::params::{Params, PoolOverrides};
+use ndarray::Array1;
+
+/// Two pools: pool 1 are fast, far-sighted predators with a smaller brain.
+fn predator_prey_params() -> Params {
+    let mut params = common::small_test_params();
+    params.num_genetic_pools = 2;
+    params.pool_overrides = vec![
+        PoolOverrides::default(),
+        PoolOverrides {
+            hidden_layers: Some(vec![8, 8]),
+            move_multiplier: Some(120.0),
+            vision_radius: Some(90.0),
+            attack_damage_rate: Some(1.5),
+            ..PoolOverrides::default()
+        },
+    ];
+    params
+}
+
+fn hidden_layer_count(brain: &Brain) -> usize {
+    match brain {
+        Brain::MLP { layers } => layers.len() - 1,
+        Brain::Transformer { .. } => 0,
+    }
+}
+
+#[test]
+fn test_for_pool_applies_only_that_pools_overrides() {
+    let params = predator_prey_params();
+
+    let prey = params.for_pool(0);
+    assert!((prey.move_multiplier - params.move_multiplier).abs() < f32::EPSILON);
+    assert_eq!(prey.layer_sizes, params.layer_sizes);
+
+    let predators = params.for_pool(1);
+    assert!((predators.move_multiplier - 120.0).abs() < f32::EPSILON);
+    assert!((predators.vision_radius - 90.0).abs() < f32::EPSILON);
+    assert!((predators.attack_cost_rate - params.attack_cost_rate).abs() < f32::EPSILON);
+    assert_eq!(
+        predators.layer_sizes,
+        vec![params.layer_sizes[0], 8, 8, params.layer_sizes[2]]
+    );
+
+    // Pools without an entry use the global parameters
+    assert_eq!(params.for_pool(5).layer_sizes, params.layer_sizes);
+}
+
+#[test]
+fn test_pools_mutate_brains_at_their_own_rate() {
+    let mut params = predator_prey_params();
+    params.pool_overrides[1].brain_mutation_rate = Some(0.0);
+    assert!((params.for_pool(0).brain_mutation_rate - params.brain_mutation_rate).abs() < 1e-6);
+    assert!(params.for_pool(1).brain_mutation_rate.abs() < f32::EPSILON);
+
+    let mut ecosystem = Ecosystem::new(&params);
+    let parent = ecosystem
+        .organisms
+        .iter_mut()
+        .find(|organism| organism.pool_id == 1)
+        .unwrap();
+    parent.energy = params.max_energy;
+    parent.reproduction_cooldown = 0.0;
+    let (parent_id, parent_pos) = (parent.id, parent.pos.clone());
+    let parent_brain = parent.brain.clone();
+
+    let mut queue = EventQueue::new();
+    queue.push(SimulationEvent::AsexualReproduction {
+        parent_id,
+        parent_pos,
+        energy_contribution: 0.5,
+    });
+    apply_events(&mut ecosystem, &params, queue);
+
+    // Pool 1 clones its brains without mutation
+    let offspring = ecosystem.organisms.last().unwrap();
+    assert_eq!(offspring.pool_id, 1);
+    let inputs = Array1::linspace(-1.0, 1.0, params.layer_sizes[0]);
+    assert_eq!(offspring.brain.think(&inputs), parent_brain.think(&inputs));
+}
+
+#[test]
+fn test_organisms_are_built_from_their_pools_parameters() {
+    let params = predator_prey_params();
+    let ecosystem = Ecosystem::new(&params);
+
+    for organism in &ecosystem.organisms {
+        let (hidden, vision) = if organism.pool_id == 1 {
+            (2, 90.0)
+        } else {
+            (1, params.vision_radius)
+        };
+        assert_eq!(hidden_layer_count(&organism.brain), hidden);
+        assert!((organism.vision_lengths[0] - vision).abs() < f32::EPSILON);
+    }
+}
+
+#[test]
+fn test_spawned_organisms_follow_pool_brain_type() {
+    let mut params = predator_prey_params();
+    params.pool_overrides[1].brain_type = Some(BrainType::Transformer);
+    params.pool_interbreed_prob = 1.0;
+    params.organism_spawn_rate = 50.0;
+    let mut ecosystem = Ecosystem::new(&params);
+
+    // Fill the graveyard from both pools, then let evolution spawn replacements
+    for _ in 0..100 {
+        ecosystem.step(&params, 0.1);
+        ecosystem.spawn(&params, 0.1);
+    }
+    for organism in &mut ecosystem.organisms {
+        organism.kill();
+    }
+    ecosystem.step(&params, 0.1);
+    ecosystem.spawn(&params, 0.1);
+
+    assert!(!ecosystem.organisms.is_empty());
+    for organism in &ecosystem.organisms {
+        let expected = if organism.pool_id == 1 {
+            BrainType::Transformer
+        } else {
+            BrainType::MLP
+        };
+        assert_eq!(organism.brain.brain_type(), expected);
+    }
+}
+
+#[test]
+fn test_same_architecture_compares_layer_shapes() {
+    let small = Brain::new(&[10, 8, 4], 0.1);
+    let large = Brain::new(&[10, 16, 4], 0.1);
+    assert!(small.same_architecture(&small.clone()));
+    assert!(!small.same_architecture(&large));
+
+    // Mismatched parents are refused instead of panicking
+    assert!(Brain::crossover(&small, &large).is_err());
+}
@@ -0,0 +1,158 @@
+#![allow(missing_docs)]
+
+mod common;
+
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::SimulationEvent;
+use evo::simulation::food::{Food, FoodKind};
+use evo::simulation::params::Params;
+use evo::simulation::projectile::{self, Projectile};
+use evo::simulation::spatial::SpatialIndex;
+use ndarray::Array1;
+
+/// Ecosystem with no food and every organism parked in a corner, except
+/// organism 0 at (200, 200).
+fn range(params: &Params) -> Ecosystem {
+    let mut ecosystem = Ecosystem::new(params);
+    ecosystem.food.clear();
+    for organism in &mut ecosystem.organisms {
+        organism.pos = Array1::from_vec(vec![450.0, 450.0]);
+    }
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![200.0, 200.0]);
+    ecosystem
+}
+
+fn fire(ecosystem: &mut Ecosystem, x: f32, y: f32, rotation: f32, speed: f32) {
+    let owner_id = ecosystem.organisms[1].id;
+    ecosystem.projectiles.push(Projectile::new(
+        Array1::from_vec(vec![x, y]),
+        rotation,
+        speed,
+        0.3,
+        owner_id,
+        1000.0,
+    ));
+}
+
+fn update(ecosystem: &mut Ecosystem, params: &Params, dt: f32) -> Vec<SimulationEvent> {
+    let spatial_index = SpatialIndex::build(ecosystem, params);
+    projectile::update_all(ecosystem, &spatial_index, params, dt)
+}
+
+fn hit_targets(events: &[SimulationEvent]) -> Vec<usize> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            SimulationEvent::ProjectileHit { target_id, .. } => Some(*target_id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_fast_projectile_cannot_tunnel_through_target() {
+    let mut params = common::small_test_params();
+    params.n_food = 0;
+    let mut ecosystem = range(&params);
+    let target = ecosystem.organisms[0].id;
+    // Starts 100 before the target and ends 100 past it
+    fire(&mut ecosystem, 100.0, 200.0, 0.0, 1000.0);
+
+    let events = update(&mut ecosystem, &params, 0.2);
+    assert_eq!(hit_targets(&events), vec![target]);
+    assert!(
+        ecosystem.projectiles.is_empty(),
+        "projectile stops at its target"
+    );
+}
+
+#[test]
+fn test_earliest_hit_along_path_wins() {
+    let mut params = common::small_test_params();
+    params.n_food = 0;
+    let mut ecosystem = range(&params);
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![250.0, 200.0]);
+    ecosystem.organisms[2].pos = Array1::from_vec(vec![150.0, 200.0]);
+    let nearer = ecosystem.organisms[2].id;
+    fire(&mut ecosystem, 100.0, 200.0, 0.0, 1000.0);
+
+    let events = update(&mut ecosystem, &params, 0.2);
+    assert_eq!(hit_targets(&events), vec![nearer]);
+}
+
+#[test]
+fn test_owner_and_missed_targets_are_not_hit() {
+    let mut params = common::small_test_params();
+    params.n_food = 0;
+    let mut ecosystem = range(&params);
+    ecosystem.organisms[1].pos = Array1::from_vec(vec![150.0, 200.0]);
+    // Runs through its owner and stops 50 short of organism 0
+    fire(&mut ecosystem, 100.0, 200.0, 0.0, 1000.0);
+    let events = update(&mut ecosystem, &params, 0.05);
+    assert!(hit_targets(&events).is_empty());
+    assert_eq!(ecosystem.projectiles.len(), 1);
+}
+
+#[test]
+fn test_crossing_projectiles_destroy_each_other_when_enabled() {
+    let mut params = common::small_test_params();
+    params.n_food = 0;
+
+    // Head-on: they pass each other halfway through the step
+    let mut ecosystem = range(&params);
+    fire(&mut ecosystem, 100.0, 300.0, 0.0, 400.0);
+    fire(&mut ecosystem, 300.0, 300.0, std::f32::consts::PI, 400.0);
+    update(&mut ecosystem, &params, 0.5);
+    assert_eq!(ecosystem.projectiles.len(), 2, "disabled by default");
+
+    params.projectiles.hit_projectiles = true;
+    let mut ecosystem = range(&params);
+    fire(&mut ecosystem, 100.0, 300.0, 0.0, 400.0);
+    fire(&mut ecosystem, 300.0, 300.0, std::f32::consts::PI, 400.0);
+    update(&mut ecosystem, &params, 0.5);
+    assert!(ecosystem.projectiles.is_empty());
+}
+
+#[test]
+fn test_projectiles_destroy_food_when_enabled() {
+    let mut params = common::small_test_params();
+    params.n_food = 0;
+    let food = Food {
+        pos: Array1::from_vec(vec![200.0, 100.0]),
+        energy: 1.0,
+        age: 0.0,
+        kind: FoodKind::Plant,
+    };
+
+    let mut ecosystem = range(&params);
+    ecosystem.food.push(food.clone());
+    fire(&mut ecosystem, 100.0, 100.0, 0.0, 1000.0);
+    update(&mut ecosystem, &params, 0.2);
+    assert!(!ecosystem.food[0].is_consumed(), "disabled by default");
+    assert_eq!(ecosystem.projectiles.len(), 1);
+
+    params.projectiles.hit_food = true;
+    let mut ecosystem = range(&params);
+    ecosystem.food.push(food);
+    fire(&mut ecosystem, 100.0, 100.0, 0.0, 1000.0);
+    update(&mut ecosystem, &params, 0.2);
+    assert!(ecosystem.food[0].is_consumed());
+    assert!(ecosystem.projectiles.is_empty());
+}
+
+#[test]
+fn test_organism_moved_into_path_is_hit_after_grid_rebuild() {
+    let mut params = common::small_test_params();
+    params.n_food = 0;
+    let mut ecosystem = range(&params);
+    let target = ecosystem.organisms[0].id;
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![200.0, 400.0]);
+    let mut spatial_index = SpatialIndex::build(&ecosystem, &params);
+    // Moves into the path after the index was built, far outside the query
+    ecosystem.organisms[0].pos = Array1::from_vec(vec![200.0, 200.0]);
+    fire(&mut ecosystem, 100.0, 200.0, 0.0, 1000.0);
+
+    spatial_index.rebuild_organisms(&ecosystem, &params);
+    let events = projectile::update_all(&mut ecosystem, &spatial_index, &params, 0.2);
+    assert_eq!(hit_targets(&events), vec![target]);
+}
@@ -0,0 +1,159 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::brain;
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::{EventQueue, SimulationEvent, apply_events};
+use evo::simulation::params::Params;
+use evo::simulation::reproduction::ReproductionMode;
+use ndarray::Array1;
+
+fn intent(organism_id: usize, partner_id: usize) -> SimulationEvent {
+    SimulationEvent::SexualReproductionIntent {
+        organism_id,
+        partner_id,
+        energy_contribution: 0.5,
+        pos: Array1::from_vec(vec![100.0, 100.0]),
+    }
+}
+
+fn ready_ecosystem(params: &Params) -> Ecosystem {
+    let mut ecosystem = Ecosystem::new(params);
+    for organism in &mut ecosystem.organisms {
+        organism.energy = 2.0;
+    }
+    ecosystem
+}
+
+#[test]
+fn test_mutual_intents_produce_one_offspring() {
+    let params = common::test_params();
+    let mut ecosystem = ready_ecosystem(&params);
+    let (a, b) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+
+    let mut queue = EventQueue::new();
+    queue.push(intent(a, b));
+    queue.push(intent(b, a));
+    apply_events(&mut ecosystem, &params, queue);
+
+    assert_eq!(ecosystem.organisms.len(), params.n_organism + 1);
+
+    let expected_energy = 2.0 - 0.5 - params.reproduction_energy_cost;
+    for parent in &ecosystem.organisms[..2] {
+        assert!((parent.energy - expected_energy).abs() < 1e-5);
+        assert!(!parent.can_reproduce());
+    }
+
+    let offspring = ecosystem.organisms.last().unwrap();
+    assert_eq!(offspring.reproduction_method, 2);
+    assert!((offspring.energy - 1.0 * params.reproduction_energy_multiplier).abs() < 1e-5);
+}
+
+#[test]
+fn test_one_sided_intent_is_refused() {
+    let params = common::test_params();
+    let mut ecosystem = ready_ecosystem(&params);
+    let (a, b, c) = (
+        ecosystem.organisms[0].id,
+        ecosystem.organisms[1].id,
+        ecosystem.organisms[2].id,
+    );
+
+    // a wants b, but b wants c
+    let mut queue = EventQueue::new();
+    queue.push(intent(a, b));
+    queue.push(intent(b, c));
+    apply_events(&mut ecosystem, &params, queue);
+
+    assert_eq!(ecosystem.organisms.len(), params.n_organism);
+    assert!(
+        ecosystem
+            .organisms
+            .iter()
+            .all(|o| (o.energy - 2.0).abs() < 1e-6)
+    );
+}
+
+#[test]
+fn test_reproduction_respects_cooldown() {
+    let params = common::test_params();
+    let mut ecosystem = ready_ecosystem(&params);
+    let (a, b) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+    ecosystem.organisms[1].reset_reproduction_cooldown(params.reproduction_cooldown);
+
+    let mut queue = EventQueue::new();
+    queue.push(intent(a, b));
+    queue.push(intent(b, a));
+    apply_events(&mut ecosystem, &params, queue);
+
+    assert_eq!(ecosystem.organisms.len(), params.n_organism);
+}
+
+#[test]
+fn test_reproduction_respects_population_cap() {
+    let mut params = common::test_params();
+    params.max_organism = params.n_organism;
+    let mut ecosystem = ready_ecosystem(&params);
+    let parent_id = ecosystem.organisms[0].id;
+
+    let mut queue = EventQueue::new();
+    queue.push(SimulationEvent::AsexualReproduction {
+        parent_id,
+        parent_pos: ecosystem.organisms[0].pos.clone(),
+        energy_contribution: 0.5,
+    });
+    apply_events(&mut ecosystem, &params, queue);
+
+    assert_eq!(ecosystem.organisms.len(), params.max_organism);
+}
+
+#[test]
+fn test_organism_initiated_mode_disables_spawning() {
+    let mut params = common::test_params();
+    params.reproduction_mode = ReproductionMode::OrganismInitiated;
+    params.organism_spawn_rate = 10.0;
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms.truncate(2);
+
+    ecosystem.spawn(&params, 1.0);
+
+    assert_eq!(ecosystem.organisms.len(), 2);
+}
+
+#[test]
+fn test_mismatched_architectures_are_refused_and_logged() {
+    let params = common::test_params();
+    let mut ecosystem = ready_ecosystem(&params);
+    let (a, b) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+    ecosystem.organisms[1].brain = brain::Brain::new_transformer(
+        params.layer_sizes[0],
+        *params.layer_sizes.last().unwrap(),
+        16,
+        1,
+        2,
+        8,
+        32,
+        0.1,
+    );
+
+    let mut queue = EventQueue::new();
+    queue.push(intent(a, b));
+    queue.push(intent(b, a));
+    apply_events(&mut ecosystem, &params, queue);
+
+    // No offspring and no energy spent
+    assert_eq!(ecosystem.organisms.len(), params.n_organism);
+    assert!(
+        ecosystem.organisms[..2]
+            .iter()
+            .all(|o| (o.energy - 2.0).abs() < 1e-6)
+    );
+    let logged = ecosystem.event_log.events().front().unwrap();
+    assert!(
+        logged.description.contains("could not reproduce"),
+        "{}",
+        logged.description
+    );
+}
@@ -0,0 +1,132 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::params::Params;
+use evo::simulation::resource_field::{
+    FertilityPatch, FertilitySource, ResourceField, ResourceFieldParams,
+};
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::test_params();
+    params.resource_field = ResourceFieldParams {
+        enabled: true,
+        source: FertilitySource::Patches,
+        patches: vec![FertilityPatch {
+            x: 250.0,
+            y: 250.0,
+            radius: 200.0,
+            fertility: 1.0,
+        }],
+        ..Default::default()
+    };
+    params
+}
+
+#[test]
+fn test_patch_fertility_falls_off_with_distance() {
+    let params = create_test_params();
+    let field = ResourceField::new(&params);
+
+    assert_eq!(field.cols, 20);
+    assert_eq!(field.rows, 20);
+    let centre = field.cell_index(&Array1::from_vec(vec![250.0, 250.0]));
+    let edge = field.cell_index(&Array1::from_vec(vec![400.0, 250.0]));
+    let far = field.cell_index(&Array1::from_vec(vec![900.0, 900.0]));
+    assert!(field.fertility[centre] > 0.8);
+    assert!(field.fertility[edge] > 0.0 && field.fertility[edge] < field.fertility[centre]);
+    assert_eq!(field.fertility[far], 0.0);
+    assert_eq!(field.nutrients[centre], field.fertility[centre] * 5.0);
+}
+
+#[test]
+fn test_noise_fertility_is_seeded_and_bounded() {
+    let mut params = create_test_params();
+    params.resource_field.source = FertilitySource::Noise;
+    let a = ResourceField::new(&params);
+    let b = ResourceField::new(&params);
+
+    assert_eq!(a.fertility, b.fertility);
+    assert!(a.fertility.iter().all(|f| (0.0..=1.0).contains(f)));
+}
+
+#[test]
+fn test_depleted_cell_regrows_to_capacity() {
+    let mut params = create_test_params();
+    params.resource_field.diffusion_rate = 0.0;
+    let mut field = ResourceField::new(&params);
+    let centre = field.cell_index(&Array1::from_vec(vec![250.0, 250.0]));
+    let capacity = field.nutrients[centre];
+    field.nutrients[centre] = 0.0;
+
+    field.update(&params, 1.0);
+    let after_one = field.nutrients[centre];
+    assert!(after_one > 0.0);
+
+    for _ in 0..500 {
+        field.update(&params, 1.0);
+    }
+    assert!(field.nutrients[centre] > after_one);
+    assert!(field.nutrients[centre] <= capacity + 1e-4);
+    assert!((field.nutrients[centre] - capacity).abs() < 0.01);
+}
+
+#[test]
+fn test_diffusion_conserves_nutrients() {
+    let mut params = create_test_params();
+    params.resource_field.regrowth_rate = 0.0;
+    let mut field = ResourceField::new(&params);
+    let before = field.total_nutrients();
+
+    for _ in 0..50 {
+        field.update(&params, 1.0);
+    }
+
+    assert!((field.total_nutrients() - before).abs() / before < 1e-3);
+}
+
+#[test]
+fn test_food_takes_energy_from_fertile_cells() {
+    let params = create_test_params();
+    let mut field = ResourceField::new(&params);
+    let before = field.total_nutrients();
+
+    for _ in 0..20 {
+        let pos = field.take_food_position(1.0).expect("fertile cells left");
+        let index = field.cell_index(&pos);
+        assert!(field.fertility[index] > 0.0);
+    }
+    assert!((before - field.total_nutrients() - 20.0).abs() < 1e-3);
+
+    field.nutrients.iter_mut().for_each(|n| *n = 0.5);
+    assert!(field.take_food_position(1.0).is_none());
+}
+
+#[test]
+fn test_dead_organisms_return_nutrients() {
+    let params = create_test_params();
+    let mut field = ResourceField::new(&params);
+    let pos = Array1::from_vec(vec![900.0, 900.0]);
+    let index = field.cell_index(&pos);
+
+    field.decompose(&pos, &params);
+
+    assert_eq!(
+        field.nutrients[index],
+        params.resource_field.decomposition_energy
+    );
+}
+
+#[test]
+fn test_barren_world_spawns_no_food() {
+    let mut params = create_test_params();
+    params.resource_field.patches.clear();
+    let mut ecosystem = Ecosystem::new(&params);
+    assert!(ecosystem.food.is_empty());
+
+    ecosystem.spawn(&params, 10.0);
+    assert!(ecosystem.food.is_empty());
+}
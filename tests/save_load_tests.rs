@@ -1,73 +1,24 @@
 #![allow(missing_docs)]
 #![allow(clippy::float_cmp)]
 
-use evo::simulation::brain;
+mod common;
+
 use evo::simulation::ecosystem::Ecosystem;
 use evo::simulation::params::Params;
 use std::fs;
 
 fn create_test_params() -> Params {
-    let signal_size: usize = 3;
-    let num_vision_directions: usize = 3;
-    let memory_size: usize = 3;
-
-    let layer_sizes = vec![
-        3 * num_vision_directions + signal_size + memory_size + 7,
-        10,
-        signal_size + memory_size + 6,
-    ];
-
-    let vision_radius = 30.0;
-
-    Params {
-        body_radius: 3.0,
-        vision_radius,
-        scent_radius: 50.0,
-        share_radius: 15.0,
-        dna_breeding_distance: 0.2,
-        dna_mutation_rate: 0.05,
-        idle_energy_rate: 0.023,
-        move_energy_rate: 0.0002,
-        move_multiplier: 60.0,
-        rot_energy_rate: 0.0003,
-        num_vision_directions,
-        fov: std::f32::consts::PI / 2.0,
-        signal_size,
-        memory_size,
-        n_organism: 20,
-        max_organism: 100,
-        n_food: 15,
-        max_food: 80,
-        box_width: 1000.0,
-        box_height: 1000.0,
-        layer_sizes,
-        attack_cost_rate: 0.2,
-        attack_damage_rate: 0.4,
-        attack_cooldown: 1.0,
-        corpse_energy_ratio: 0.8,
-        max_energy: 2.0,
-        food_energy: 1.0,
-        projectile_speed: vision_radius * 2.0,
-        projectile_range: vision_radius,
-        projectile_radius: 1.0,
-        organism_spawn_rate: 1.0,
-        food_spawn_rate: 1.0,
-        food_lifetime: 0.0,
-        num_genetic_pools: 1,
-        pool_interbreed_prob: 0.0,
-        brain_type: brain::BrainType::MLP,
-        transformer_model_dim: 64,
-        transformer_num_blocks: 2,
-        transformer_num_heads: 4,
-        transformer_head_dim: 16,
-        transformer_ff_dim: 128,
-        graveyard_size: 400,
-        reproduction_energy_multiplier: 1.2,
-        reproduction_radius: 15.0,
-        spawn_from_graveyard: true,
-        unbalanced_pool_sampling: false,
-        empty_pool_seed_count: 5,
+    let mut params = common::test_params();
+    params.n_organism = 20;
+    params.max_organism = 100;
+    params.n_food = 15;
+    params.max_food = 80;
+    // Food never rots (0 = unlimited); carrion is worth 0.8 energy
+    for kind in &mut params.food_kinds {
+        kind.lifetime = 0.0;
     }
+    params.food_kinds[1].energy = 0.8;
+    params
 }
 
 #[test]
@@ -230,3 +181,29 @@ fn test_load_and_continue_simulation() {
     // Clean up
     fs::remove_file(save_path).ok();
 }
+
+#[test]
+fn test_save_and_load_preserves_novelty_archive() {
+    let mut params = create_test_params();
+    params.novelty.archive_add_prob = 1.0;
+    let mut ecosystem = Ecosystem::new(&params);
+
+    // Kill a few old-enough organisms so their behaviours get archived
+    for organism in ecosystem.organisms.iter_mut().take(5) {
+        organism.age = 1.0;
+        organism.energy = 0.0;
+    }
+    ecosystem.step(&params, 0.05);
+    assert_eq!(ecosystem.novelty_archive().len(), 5);
+
+    let save_path = "test_novelty_archive.json";
+    ecosystem.save_to_file(save_path).expect("Failed to save");
+    let loaded_ecosystem = Ecosystem::load_from_file(save_path).expect("Failed to load");
+
+    let original: Vec<&[f32]> = ecosystem.novelty_archive().behaviours().collect();
+    let loaded: Vec<&[f32]> = loaded_ecosystem.novelty_archive().behaviours().collect();
+    assert_eq!(original, loaded);
+
+    // Clean up
+    fs::remove_file(save_path).ok();
+}
@@ -0,0 +1,180 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::boundary::{self, BoundaryMode};
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::params::Params;
+use evo::simulation::spatial::{CellGrid, Neighbourhood, SpatialIndex};
+use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn point(x: f32, y: f32) -> Array1<f32> {
+    Array1::from_vec(vec![x, y])
+}
+
+/// Random points, a third of them crowded near the edges and corners.
+fn scatter(rng: &mut StdRng, count: usize, params: &Params, overshoot: f32) -> Vec<[f32; 2]> {
+    let (w, h) = (params.box_width, params.box_height);
+    (0..count)
+        .map(|i| {
+            if i % 3 == 0 {
+                let near_edge = |size: f32, rng: &mut StdRng| {
+                    if rng.random::<bool>() {
+                        rng.random_range(-overshoot..10.0)
+                    } else {
+                        rng.random_range(size - 10.0..size + overshoot)
+                    }
+                };
+                [near_edge(w, rng), near_edge(h, rng)]
+            } else {
+                [rng.random_range(0.0..w), rng.random_range(0.0..h)]
+            }
+        })
+        .collect()
+}
+
+/// Everything within `radius` of `pos`, found by checking every point.
+fn brute_force(points: &[[f32; 2]], pos: [f32; 2], radius: f32, params: &Params) -> Vec<usize> {
+    let from = point(pos[0], pos[1]);
+    points
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| boundary::distance(&from, &point(p[0], p[1]), params) <= radius)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn assert_grid_matches_brute_force(params: &Params, overshoot: f32, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let points = scatter(&mut rng, 400, params, overshoot);
+    let mut grid = CellGrid::default();
+    grid.rebuild(points.iter().copied(), params.vision_radius, params);
+    assert_eq!(grid.len(), points.len());
+
+    let queries = scatter(&mut rng, 200, params, overshoot);
+    for radius in [
+        0.0,
+        4.0,
+        params.vision_radius,
+        3.5 * params.vision_radius,
+        2000.0,
+    ] {
+        for &pos in &queries {
+            let mut found: Vec<usize> = Vec::new();
+            grid.for_each_within(pos, radius, |dist_sq, index| {
+                let [x, y] = points[index];
+                let expected = boundary::distance(&point(pos[0], pos[1]), &point(x, y), params);
+                assert!((dist_sq.sqrt() - expected).abs() < 1e-2);
+                found.push(index);
+            });
+            found.sort_unstable();
+            let expected = brute_force(&points, pos, radius, params);
+            // Points right on the query circle may go either way with rounding
+            let borderline = |index: &usize| {
+                let [x, y] = points[*index];
+                let d = boundary::distance(&point(pos[0], pos[1]), &point(x, y), params);
+                (d - radius).abs() < 1e-3
+            };
+            let found_clear: Vec<usize> =
+                found.iter().copied().filter(|i| !borderline(i)).collect();
+            let expected_clear: Vec<usize> = expected
+                .iter()
+                .copied()
+                .filter(|i| !borderline(i))
+                .collect();
+            assert_eq!(
+                found_clear, expected_clear,
+                "{:?} query at {pos:?} with radius {radius}",
+                params.boundary_mode
+            );
+            // No point is reported twice
+            assert!(found.windows(2).all(|pair| pair[0] != pair[1]));
+        }
+    }
+}
+
+#[test]
+fn test_grid_matches_brute_force_on_a_torus() {
+    let params = common::test_params();
+    assert_grid_matches_brute_force(&params, 0.0, 1);
+
+    // Positions that left the world are wrapped into it
+    assert_grid_matches_brute_force(&params, 25.0, 2);
+}
+
+#[test]
+fn test_grid_matches_brute_force_with_walls() {
+    let mut params = common::test_params();
+    params.boundary_mode = BoundaryMode::Walls;
+    // Positions outside the world land in the edge cells
+    assert_grid_matches_brute_force(&params, 25.0, 3);
+}
+
+#[test]
+fn test_grid_matches_brute_force_in_a_world_smaller_than_a_cell() {
+    let mut params = common::test_params();
+    params.box_width = 40.0;
+    params.box_height = 25.0;
+    assert_grid_matches_brute_force(&params, 0.0, 4);
+}
+
+#[test]
+fn test_neighbourhood_is_nearest_first_and_nests_by_radius() {
+    let params = common::test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms[0].pos = point(3.0, 997.0);
+    let index = SpatialIndex::build(&ecosystem, &params);
+
+    let mut neighbourhood = Neighbourhood::default();
+    let centre = ecosystem.organisms[0].pos.clone();
+    neighbourhood.gather(&index, &centre, 400.0);
+    let food = neighbourhood.food(400.0);
+    assert!(food.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+    let points: Vec<[f32; 2]> = ecosystem
+        .food
+        .iter()
+        .map(|f| [f.pos[0], f.pos[1]])
+        .collect();
+    for radius in [10.0, 100.0, 400.0] {
+        let mut found: Vec<usize> = neighbourhood.food(radius).iter().map(|&(_, i)| i).collect();
+        found.sort_unstable();
+        assert_eq!(
+            found,
+            brute_force(&points, [centre[0], centre[1]], radius, &params)
+        );
+    }
+    assert_eq!(
+        neighbourhood.organisms(400.0)[0].1,
+        0,
+        "itself, at distance 0"
+    );
+}
+
+#[test]
+fn test_sensing_radius_covers_every_action() {
+    let mut params = common::test_params();
+    params.vision_radius = 5.0;
+    params.share_radius = 40.0;
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms[0].vision_lengths.fill(5.0);
+    ecosystem.organisms[0].pos = point(500.0, 500.0);
+    ecosystem.organisms[1].pos = point(530.0, 500.0);
+
+    let neighbourhood = Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params);
+    assert!(neighbourhood.radius() >= params.share_radius);
+    assert!(
+        neighbourhood
+            .organisms(params.share_radius)
+            .iter()
+            .any(|&(_, i)| i == 1),
+        "a share partner beyond sight is still in reach"
+    );
+
+    params.share_radius = 1.0;
+    params.reproduction_radius = 60.0;
+    assert!(Neighbourhood::sensing_radius(&ecosystem.organisms[0], &params, 1.0) >= 60.0);
+}
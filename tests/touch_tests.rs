@@ -0,0 +1,146 @@
+#![allow(missing_docs)]
+#![allow(clippy::float_cmp)]
+
+mod common;
+
+use evo::simulation::ecosystem::Ecosystem;
+use evo::simulation::events::{self, EventQueue, SimulationEvent};
+use evo::simulation::organism::{Perception, Sense, SenseKind, Touch};
+use evo::simulation::params::Params;
+use evo::simulation::spatial::Neighbourhood;
+use evo::simulation::view::WorldView;
+use ndarray::Array1;
+
+fn create_test_params() -> Params {
+    let mut params = common::small_test_params();
+    params.senses.push(SenseKind::Touch);
+    common::fit_layer_sizes(&mut params);
+    params
+}
+
+fn apply(ecosystem: &mut Ecosystem, params: &Params, batch: Vec<SimulationEvent>) {
+    let mut queue = EventQueue::new();
+    for event in batch {
+        queue.push(event);
+    }
+    events::apply_events(ecosystem, params, queue);
+}
+
+#[test]
+fn test_touch_sense_size() {
+    let params = create_test_params();
+    let touch = Touch::new();
+    assert_eq!(touch.input_size(&params), 7);
+    assert_eq!(touch.input_labels(&params).len(), 7);
+    assert_eq!(touch.name(), "Touch");
+    assert_eq!(
+        Perception::from_params(&params).total_input_size(&params),
+        params.layer_sizes[0]
+    );
+    assert_eq!(
+        Perception::default().total_input_size(&params) + 7,
+        params.layer_sizes[0]
+    );
+}
+
+#[test]
+fn test_touch_reports_damage_and_its_direction() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms[0].rot = 0.0;
+    ecosystem.organisms[0].energy = 1.0;
+    let target_id = ecosystem.organisms[0].id;
+
+    // Shot travelling in -y, so it came from +y: the organism's left
+    apply(
+        &mut ecosystem,
+        &params,
+        vec![SimulationEvent::ProjectileHit {
+            target_id,
+            damage: 0.5,
+            owner_id: usize::MAX,
+            from: Array1::from_vec(vec![0.0, 3.0]),
+        }],
+    );
+
+    let outputs = Touch::new().sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert!((outputs[0] - 0.5 / params.max_energy).abs() < 1e-6);
+    assert!(outputs[1].abs() < 1e-6);
+    assert!((outputs[2] - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_touch_reports_feeding_and_gifts() {
+    let params = create_test_params();
+    let mut ecosystem = Ecosystem::new(&params);
+    for organism in &mut ecosystem.organisms {
+        organism.energy = 1.0;
+    }
+    // The first organism is nearer the contested food
+    ecosystem.organisms[0].pos = ecosystem.food[0].pos.clone();
+    ecosystem.organisms[1].pos = &ecosystem.food[0].pos + 5.0;
+    let (first, second) = (ecosystem.organisms[0].id, ecosystem.organisms[1].id);
+
+    apply(
+        &mut ecosystem,
+        &params,
+        vec![
+            SimulationEvent::FoodConsumed {
+                organism_id: first,
+                food_id: 0,
+            },
+            SimulationEvent::FoodConsumed {
+                organism_id: second,
+                food_id: 0,
+            },
+            SimulationEvent::EnergyShared {
+                giver_id: first,
+                receiver_id: second,
+                amount: 0.2,
+            },
+        ],
+    );
+
+    let winner = Touch::new().sense(
+        &ecosystem.organisms[0],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[0], &ecosystem, &params),
+    );
+    assert_eq!(winner[4], 1.0);
+    assert_eq!(winner[5], 0.0);
+    assert_eq!(winner[3], 0.0);
+
+    let loser = Touch::new().sense(
+        &ecosystem.organisms[1],
+        &WorldView::of(&ecosystem, &params),
+        &params,
+        &Neighbourhood::around(&ecosystem.organisms[1], &ecosystem, &params),
+    );
+    assert_eq!(loser[4], 0.0);
+    assert_eq!(loser[5], 1.0);
+    assert!((loser[3] - 0.2 / params.max_energy).abs() < 1e-6);
+}
+
+#[test]
+fn test_touch_is_felt_once() {
+    let mut params = create_test_params();
+    params.n_organism = 1;
+    params.n_food = 0;
+    params.food_spawn_rate = 0.0;
+    params.organism_spawn_rate = 0.0;
+    let mut ecosystem = Ecosystem::new(&params);
+    ecosystem.organisms[0].energy = 1.0;
+    ecosystem.organisms[0].touch.ate_food = true;
+
+    ecosystem.step(&params, 0.01);
+
+    let inputs = &ecosystem.organisms[0].last_brain_inputs;
+    assert_eq!(inputs[inputs.len() - 3], 1.0);
+    assert!(!ecosystem.organisms[0].touch.ate_food);
+}